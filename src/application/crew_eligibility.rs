@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::eligibility_rules::EligibilityEvaluator,
+    config::config_loader,
+    domain::{
+        entities::quests::QuestEntity,
+        repositories::{
+            crew_switchboard::CrewSwitchboardRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            eligibility_model::IneligibilityModel, level_model::LevelModel,
+            quest_dependency_model::pending_prerequisites, waitlist_model::WaitlistCandidatesModel,
+        },
+    },
+};
+
+pub struct CrewEligibility<T1, T2>
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    crew_switchboard_repository: Arc<T1>,
+    quest_viewing_repository: Arc<T2>,
+    eligibility_evaluator: EligibilityEvaluator,
+}
+
+impl<T1, T2> CrewEligibility<T1, T2>
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    pub fn new(crew_switchboard_repository: Arc<T1>, quest_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            crew_switchboard_repository,
+            quest_viewing_repository,
+            eligibility_evaluator: EligibilityEvaluator::default(),
+        }
+    }
+
+    pub fn with_eligibility_evaluator(
+        mut self,
+        eligibility_evaluator: EligibilityEvaluator,
+    ) -> Self {
+        self.eligibility_evaluator = eligibility_evaluator;
+        self
+    }
+
+    pub fn join_deadline_passed(quest: &QuestEntity) -> bool {
+        quest
+            .join_deadline
+            .is_some_and(|join_deadline| join_deadline < chrono::Utc::now().naive_utc())
+    }
+
+    pub async fn ensure_prerequisites_completed(&self, quest_id: i32) -> Result<()> {
+        let pending_prerequisites = self.pending_prerequisites(quest_id).await?;
+
+        if !pending_prerequisites.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Quest prerequisites must be completed first: {:?}",
+                pending_prerequisites
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub async fn ensure_eligible(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        let Some(requirements) = self
+            .quest_viewing_repository
+            .requirements_by_quest_id(quest_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        let requirements = requirements.to_model()?;
+
+        let leveling = config_loader::get_leveling_env()?;
+
+        let standing = self
+            .crew_switchboard_repository
+            .adventurer_standing(adventurer_id)
+            .await?;
+
+        let level = LevelModel::from_xp(standing.xp, leveling.base_xp, leveling.exponent);
+
+        let failed_rules = self
+            .eligibility_evaluator
+            .evaluate(&requirements, &standing.to_candidate(level.level));
+
+        if !failed_rules.is_empty() {
+            return Err(IneligibilityModel::new(failed_rules).into());
+        }
+
+        Ok(())
+    }
+
+    pub async fn waitlist_candidates(
+        &self,
+        quest: &QuestEntity,
+    ) -> Result<WaitlistCandidatesModel> {
+        let mut waitlist_candidates = WaitlistCandidatesModel::default();
+
+        if Self::join_deadline_passed(quest)
+            || !self.pending_prerequisites(quest.id).await?.is_empty()
+        {
+            return Ok(waitlist_candidates);
+        }
+
+        let waitlist_adventurer_ids = self
+            .crew_switchboard_repository
+            .waitlist_adventurer_ids(quest.id)
+            .await?;
+
+        for adventurer_id in waitlist_adventurer_ids {
+            match self.ensure_eligible(quest.id, adventurer_id).await {
+                Ok(()) => waitlist_candidates
+                    .eligible_adventurer_ids
+                    .push(adventurer_id),
+                Err(e) if e.is::<IneligibilityModel>() => waitlist_candidates
+                    .ineligible_adventurer_ids
+                    .push(adventurer_id),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(waitlist_candidates)
+    }
+
+    async fn pending_prerequisites(&self, quest_id: i32) -> Result<Vec<i32>> {
+        let prerequisites = self
+            .quest_viewing_repository
+            .prerequisites_by_quest_id(quest_id)
+            .await?;
+
+        Ok(pending_prerequisites(&prerequisites))
+    }
+}
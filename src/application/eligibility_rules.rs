@@ -0,0 +1,134 @@
+use crate::domain::value_objects::{
+    eligibility_model::EligibilityCandidateModel, quest_requirements_model::QuestRequirementsModel,
+};
+
+pub trait EligibilityRule: Send + Sync {
+    fn evaluate(
+        &self,
+        requirements: &QuestRequirementsModel,
+        candidate: &EligibilityCandidateModel,
+    ) -> Option<String>;
+}
+
+pub struct MinLevelRule;
+
+impl EligibilityRule for MinLevelRule {
+    fn evaluate(
+        &self,
+        requirements: &QuestRequirementsModel,
+        candidate: &EligibilityCandidateModel,
+    ) -> Option<String> {
+        match requirements.min_level {
+            Some(min_level) if candidate.level < min_level => Some(format!(
+                "Minimum level {} is required, current level is {}",
+                min_level, candidate.level
+            )),
+            _ => None,
+        }
+    }
+}
+
+pub struct MinCompletedQuestsRule;
+
+impl EligibilityRule for MinCompletedQuestsRule {
+    fn evaluate(
+        &self,
+        requirements: &QuestRequirementsModel,
+        candidate: &EligibilityCandidateModel,
+    ) -> Option<String> {
+        match requirements.min_completed_quests {
+            Some(min_completed_quests)
+                if candidate.completed_quests < min_completed_quests as i64 =>
+            {
+                Some(format!(
+                    "At least {} completed quests are required, current count is {}",
+                    min_completed_quests, candidate.completed_quests
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+pub struct RequiredSkillsRule;
+
+impl EligibilityRule for RequiredSkillsRule {
+    fn evaluate(
+        &self,
+        requirements: &QuestRequirementsModel,
+        candidate: &EligibilityCandidateModel,
+    ) -> Option<String> {
+        let missing_skills = requirements
+            .required_skills
+            .iter()
+            .filter(|skill| !candidate.skills.contains(skill))
+            .cloned()
+            .collect::<Vec<String>>();
+
+        if missing_skills.is_empty() {
+            return None;
+        }
+
+        Some(format!(
+            "Missing required skills: {}",
+            missing_skills.join(", ")
+        ))
+    }
+}
+
+pub struct AllowlistRule;
+
+impl EligibilityRule for AllowlistRule {
+    fn evaluate(
+        &self,
+        requirements: &QuestRequirementsModel,
+        candidate: &EligibilityCandidateModel,
+    ) -> Option<String> {
+        if requirements.allowed_adventurer_ids.is_empty()
+            || requirements
+                .allowed_adventurer_ids
+                .contains(&candidate.adventurer_id)
+        {
+            return None;
+        }
+
+        Some("Adventurer is not on the quest allowlist".to_string())
+    }
+}
+
+pub struct EligibilityEvaluator {
+    rules: Vec<Box<dyn EligibilityRule>>,
+}
+
+impl Default for EligibilityEvaluator {
+    fn default() -> Self {
+        Self::new(vec![
+            Box::new(MinLevelRule),
+            Box::new(MinCompletedQuestsRule),
+            Box::new(RequiredSkillsRule),
+            Box::new(AllowlistRule),
+        ])
+    }
+}
+
+impl EligibilityEvaluator {
+    pub fn new(rules: Vec<Box<dyn EligibilityRule>>) -> Self {
+        Self { rules }
+    }
+
+    pub fn with_rule(mut self, rule: Box<dyn EligibilityRule>) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn evaluate(
+        &self,
+        requirements: &QuestRequirementsModel,
+        candidate: &EligibilityCandidateModel,
+    ) -> Vec<String> {
+        self.rules
+            .iter()
+            .filter_map(|rule| rule.evaluate(requirements, candidate))
+            .collect()
+    }
+}
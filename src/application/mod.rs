@@ -1 +1,4 @@
-pub mod use_cases;
\ No newline at end of file
+pub mod crew_eligibility;
+pub mod eligibility_rules;
+pub mod quest_events;
+pub mod use_cases;
@@ -0,0 +1,52 @@
+use anyhow::Result;
+use axum::async_trait;
+use tokio::sync::broadcast;
+
+use crate::domain::{
+    repositories::quest_event_handler::QuestEventHandler,
+    value_objects::{
+        outbox_event_model::OutboxEventModel, quest_event_model::PublicQuestEventModel,
+    },
+};
+
+pub const QUEST_EVENTS_CAPACITY: usize = 256;
+
+#[derive(Clone)]
+pub struct QuestEventBus {
+    sender: broadcast::Sender<PublicQuestEventModel>,
+}
+
+impl Default for QuestEventBus {
+    fn default() -> Self {
+        Self::new(QUEST_EVENTS_CAPACITY)
+    }
+}
+
+impl QuestEventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<PublicQuestEventModel> {
+        self.sender.subscribe()
+    }
+
+    pub fn publish(&self, event: PublicQuestEventModel) {
+        let _ = self.sender.send(event);
+    }
+}
+
+#[async_trait]
+impl QuestEventHandler for QuestEventBus {
+    fn name(&self) -> &'static str {
+        "events"
+    }
+
+    async fn handle(&self, outbox_event: &OutboxEventModel) -> Result<()> {
+        self.publish(outbox_event.event.to_public());
+
+        Ok(())
+    }
+}
@@ -3,13 +3,22 @@ use std::sync::Arc;
 use anyhow::{Ok, Result};
 
 use crate::{
+    config::config_loader,
     domain::{
         repositories::adventurers::AdventurersRepository,
-        value_objects::adventurer_model::RegisterAdventurerModel,
+        value_objects::{
+            adventurer_model::{
+                AdventurerProfileModel, AdventurerSkillsModel, RegisterAdventurerModel,
+            },
+            labels::normalize_labels,
+            level_model::LevelModel,
+        },
     },
     infrastructure::argon2_hashing,
 };
 
+pub const MAX_SKILLS_PER_ADVENTURER: usize = 20;
+
 pub struct AdventurersUseCase<T>
 where
     T: AdventurersRepository + Send + Sync,
@@ -43,4 +52,48 @@ where
 
         Ok(adventurer_id)
     }
+
+    pub async fn profile(&self, adventurer_id: i32) -> Result<AdventurerProfileModel> {
+        let leveling = config_loader::get_leveling_env()?;
+
+        let adventurer = self
+            .adventurers_repository
+            .find_by_id(adventurer_id)
+            .await?;
+
+        let level = LevelModel::from_xp(adventurer.xp, leveling.base_xp, leveling.exponent);
+
+        let skills = self.adventurers_repository.skills(adventurer_id).await?;
+
+        Ok(AdventurerProfileModel {
+            id: adventurer.id,
+            username: adventurer.username,
+            gold: adventurer.gold,
+            xp: adventurer.xp,
+            level: level.level,
+            xp_to_next_level: level.xp_to_next_level,
+            skills,
+        })
+    }
+
+    pub async fn set_skills(
+        &self,
+        adventurer_id: i32,
+        adventurer_skills_model: AdventurerSkillsModel,
+    ) -> Result<Vec<String>> {
+        let skills = normalize_labels(adventurer_skills_model.skills);
+
+        if skills.len() > MAX_SKILLS_PER_ADVENTURER {
+            return Err(anyhow::anyhow!(
+                "Adventurer must not have more than {} skills",
+                MAX_SKILLS_PER_ADVENTURER
+            ));
+        }
+
+        self.adventurers_repository
+            .replace_skills(adventurer_id, skills.clone())
+            .await?;
+
+        Ok(skills)
+    }
 }
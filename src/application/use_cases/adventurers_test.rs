@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::use_cases::adventurers::AdventurersUseCase,
+        domain::{
+            entities::adventurers::AdventurerEntity,
+            repositories::adventurers::MockAdventurersRepository,
+        },
+    };
+
+    #[tokio::test]
+    async fn test_profile_derives_level_from_xp() {
+        let mut mock_adventurers_repo = MockAdventurersRepository::new();
+
+        mock_adventurers_repo.expect_find_by_id().returning(|_| {
+            Box::pin(async {
+                Ok(AdventurerEntity {
+                    id: 1,
+                    username: "test".to_string(),
+                    password: "test".to_string(),
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                    gold: 30,
+                    xp: 150,
+                })
+            })
+        });
+
+        mock_adventurers_repo
+            .expect_skills()
+            .returning(|_| Box::pin(async { Ok(vec!["archery".to_string()]) }));
+
+        let use_case = AdventurersUseCase::new(Arc::new(mock_adventurers_repo));
+
+        let result = use_case.profile(1).await.unwrap();
+        assert_eq!(result.gold, 30);
+        assert_eq!(result.xp, 150);
+        assert_eq!(result.level, 2);
+        assert_eq!(result.xp_to_next_level, 233);
+        assert_eq!(result.skills, vec!["archery".to_string()]);
+    }
+}
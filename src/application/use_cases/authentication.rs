@@ -75,8 +75,8 @@ where
             jwt_authentication::generate_token(secret_env.refresh_secret, &refresh_token_claims)?;
 
         Ok(Passport {
-            access_token: access_token,
-            refresh_token: refresh_token,
+            access_token,
+            refresh_token,
         })
     }
 
@@ -107,8 +107,8 @@ where
             jwt_authentication::generate_token(secret_env.refresh_secret, &refresh_token_claims)?;
 
         Ok(Passport {
-            access_token: access_token,
-            refresh_token: refresh_token,
+            access_token,
+            refresh_token,
         })
     }
 
@@ -148,8 +148,8 @@ where
             jwt_authentication::generate_token(secret_env.refresh_secret, &refresh_token_claims)?;
 
         Ok(Passport {
-            access_token: access_token,
-            refresh_token: refresh_token,
+            access_token,
+            refresh_token,
         })
     }
 
@@ -180,8 +180,8 @@ where
             jwt_authentication::generate_token(secret_env.refresh_secret, &refresh_token_claims)?;
 
         Ok(Passport {
-            access_token: access_token,
-            refresh_token: refresh_token,
+            access_token,
+            refresh_token,
         })
     }
 }
@@ -0,0 +1,235 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::crew_eligibility::CrewEligibility,
+    domain::{
+        entities::quests::QuestEntity,
+        repositories::{
+            crew_switchboard::CrewSwitchboardRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            crew_member_model::CrewMemberModel, crew_membership_statuses::CrewMembershipStatuses,
+            quest_adventurer_junction::QuestAdventurerJunction, quest_statuses::QuestStatuses,
+            waitlist_model::WaitlistCandidatesModel,
+        },
+    },
+};
+
+pub struct CrewManagementUseCase<T1, T2>
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    crew_switchboard_repository: Arc<T1>,
+    quest_viewing_repository: Arc<T2>,
+    crew_eligibility: CrewEligibility<T1, T2>,
+}
+
+impl<T1, T2> CrewManagementUseCase<T1, T2>
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    pub fn new(crew_switchboard_repository: Arc<T1>, quest_viewing_repository: Arc<T2>) -> Self {
+        let crew_eligibility = CrewEligibility::new(
+            Arc::clone(&crew_switchboard_repository),
+            Arc::clone(&quest_viewing_repository),
+        );
+
+        Self {
+            crew_switchboard_repository,
+            quest_viewing_repository,
+            crew_eligibility,
+        }
+    }
+
+    pub async fn invite(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+    ) -> Result<()> {
+        let quest = self
+            .owned_quest_details(quest_id, guild_commander_id)
+            .await?;
+
+        if !Self::crew_changeable(&quest) {
+            return Err(anyhow::anyhow!("Quest crew is not changeable"));
+        }
+
+        let junction_body = QuestAdventurerJunction {
+            quest_id,
+            adventurer_id,
+        };
+
+        let membership_status = self
+            .crew_switchboard_repository
+            .membership_status(junction_body.clone())
+            .await?;
+
+        match membership_status {
+            Some(CrewMembershipStatuses::Pending) => {
+                self.approve(quest_id, guild_commander_id, adventurer_id)
+                    .await
+            }
+            Some(_) => Err(anyhow::anyhow!(
+                "Adventurer has already been invited or joined this quest"
+            )),
+            None => self.crew_switchboard_repository.invite(junction_body).await,
+        }
+    }
+
+    pub async fn approve(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+    ) -> Result<()> {
+        let quest = self
+            .owned_quest_details(quest_id, guild_commander_id)
+            .await?;
+
+        if !Self::crew_changeable(&quest) {
+            return Err(anyhow::anyhow!("Quest crew is not changeable"));
+        }
+
+        let adventurers_count = self
+            .quest_viewing_repository
+            .adventurers_counting_by_quest_id(quest_id)
+            .await?;
+
+        if adventurers_count >= quest.max_adventurers as i64 {
+            return Err(anyhow::anyhow!("Quest is full"));
+        }
+
+        let junction_body = QuestAdventurerJunction {
+            quest_id,
+            adventurer_id,
+        };
+
+        let membership_status = self
+            .crew_switchboard_repository
+            .membership_status(junction_body.clone())
+            .await?;
+
+        if membership_status != Some(CrewMembershipStatuses::Pending) {
+            return Err(anyhow::anyhow!("Join request not found"));
+        }
+
+        self.crew_switchboard_repository
+            .approve(junction_body)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn reject(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+    ) -> Result<()> {
+        self.owned_quest_details(quest_id, guild_commander_id)
+            .await?;
+
+        let junction_body = QuestAdventurerJunction {
+            quest_id,
+            adventurer_id,
+        };
+
+        let membership_status = self
+            .crew_switchboard_repository
+            .membership_status(junction_body.clone())
+            .await?;
+
+        if membership_status != Some(CrewMembershipStatuses::Pending) {
+            return Err(anyhow::anyhow!("Join request not found"));
+        }
+
+        self.crew_switchboard_repository
+            .leave(junction_body, WaitlistCandidatesModel::default())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn kick(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+    ) -> Result<()> {
+        let quest = self
+            .owned_quest_details(quest_id, guild_commander_id)
+            .await?;
+
+        if !Self::crew_changeable(&quest) {
+            return Err(anyhow::anyhow!("Quest crew is not changeable"));
+        }
+
+        let junction_body = QuestAdventurerJunction {
+            quest_id,
+            adventurer_id,
+        };
+
+        let membership_status = self
+            .crew_switchboard_repository
+            .membership_status(junction_body.clone())
+            .await?;
+
+        match membership_status {
+            Some(CrewMembershipStatuses::Joined) => {
+                let waitlist_candidates = self.crew_eligibility.waitlist_candidates(&quest).await?;
+
+                self.crew_switchboard_repository
+                    .leave(junction_body, waitlist_candidates)
+                    .await
+            }
+            Some(CrewMembershipStatuses::Invited) => {
+                self.crew_switchboard_repository
+                    .leave(junction_body, WaitlistCandidatesModel::default())
+                    .await
+            }
+            _ => Err(anyhow::anyhow!("Adventurer is not in this quest crew")),
+        }
+    }
+
+    pub async fn pending_requests(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<Vec<CrewMemberModel>> {
+        self.owned_quest_details(quest_id, guild_commander_id)
+            .await?;
+
+        let pending = self
+            .quest_viewing_repository
+            .crew_by_quest_id(quest_id, CrewMembershipStatuses::Pending)
+            .await?;
+
+        Ok(pending)
+    }
+
+    async fn owned_quest_details(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<QuestEntity> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        if quest.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest is not owned by this guild commander"
+            ));
+        }
+
+        Ok(quest)
+    }
+
+    fn crew_changeable(quest: &QuestEntity) -> bool {
+        quest.status == QuestStatuses::Open.to_string()
+            || quest.status == QuestStatuses::Failed.to_string()
+    }
+}
@@ -0,0 +1,245 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::use_cases::crew_management::CrewManagementUseCase,
+        domain::{
+            entities::quests::QuestEntity,
+            repositories::{
+                crew_switchboard::MockCrewSwitchboardRepository,
+                quest_viewing::MockQuestViewingRepository,
+            },
+            value_objects::{
+                crew_membership_statuses::CrewMembershipStatuses,
+                quest_adventurer_junction::DEFAULT_CREW_SIZE,
+                quest_difficulties::QuestDifficulties, quest_statuses::QuestStatuses,
+            },
+        },
+    };
+
+    #[tokio::test]
+    async fn test_approve_success() {
+        let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: true,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(2) }));
+
+        mock_crew_repo
+            .expect_membership_status()
+            .returning(|_| Box::pin(async { Ok(Some(CrewMembershipStatuses::Pending)) }));
+
+        mock_crew_repo
+            .expect_approve()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case =
+            CrewManagementUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case.approve(1, 1, 1).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_approve_fails_when_quest_is_full() {
+        let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: true,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(DEFAULT_CREW_SIZE as i64) }));
+
+        mock_crew_repo.expect_approve().never();
+
+        let use_case =
+            CrewManagementUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case.approve(1, 1, 1).await;
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "Quest is full");
+    }
+
+    #[tokio::test]
+    async fn test_kick_fails_when_quest_is_not_owned() {
+        let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 2,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_crew_repo.expect_leave().never();
+
+        let use_case =
+            CrewManagementUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case.kick(1, 1, 1).await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quest is not owned by this guild commander"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_kick_success() {
+        let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_crew_repo
+            .expect_membership_status()
+            .returning(|_| Box::pin(async { Ok(Some(CrewMembershipStatuses::Joined)) }));
+
+        mock_quest_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_crew_repo
+            .expect_waitlist_adventurer_ids()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_crew_repo
+            .expect_leave()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let use_case =
+            CrewManagementUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case.kick(1, 1, 1).await;
+        assert!(result.is_ok());
+    }
+}
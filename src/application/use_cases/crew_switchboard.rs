@@ -2,13 +2,20 @@ use std::sync::Arc;
 
 use anyhow::Result;
 
-use crate::domain::{
-    repositories::{
-        crew_switchboard::CrewSwitchboardRepository, quest_viewing::QuestViewingRepository,
-    },
-    value_objects::{
-        quest_adventurer_junction::{QuestAdventurerJunction, MAX_ADVENTURERS_PER_QUEST},
-        quest_statuses::QuestStatuses,
+use crate::{
+    application::{crew_eligibility::CrewEligibility, eligibility_rules::EligibilityEvaluator},
+    domain::{
+        repositories::{
+            crew_switchboard::CrewSwitchboardRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            crew_membership_statuses::CrewMembershipStatuses,
+            quest_adventurer_junction::QuestAdventurerJunction,
+            quest_model::QuestsByStatusModel,
+            quest_objective_model::{objectives_progress, QuestObjectiveModel},
+            quest_statuses::QuestStatuses,
+            waitlist_model::WaitlistPositionModel,
+        },
     },
 };
 
@@ -19,6 +26,7 @@ where
 {
     crew_switchboard_repository: Arc<T1>,
     quest_viewing_repository: Arc<T2>,
+    crew_eligibility: CrewEligibility<T1, T2>,
 }
 
 impl<T1, T2> CrewSwitchboardUseCase<T1, T2>
@@ -27,13 +35,29 @@ where
     T2: QuestViewingRepository + Send + Sync,
 {
     pub fn new(crew_switchboard_repository: Arc<T1>, quest_viewing_repository: Arc<T2>) -> Self {
+        let crew_eligibility = CrewEligibility::new(
+            Arc::clone(&crew_switchboard_repository),
+            Arc::clone(&quest_viewing_repository),
+        );
+
         Self {
             crew_switchboard_repository,
             quest_viewing_repository,
+            crew_eligibility,
         }
     }
 
-    pub async fn join(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+    pub fn with_eligibility_evaluator(
+        mut self,
+        eligibility_evaluator: EligibilityEvaluator,
+    ) -> Self {
+        self.crew_eligibility = self
+            .crew_eligibility
+            .with_eligibility_evaluator(eligibility_evaluator);
+        self
+    }
+
+    pub async fn join(&self, quest_id: i32, adventurer_id: i32) -> Result<CrewMembershipStatuses> {
         let quest = self.quest_viewing_repository.view_details(quest_id).await?;
 
         let adventurers_count = self
@@ -44,7 +68,7 @@ where
         let quest_status_condition = quest.status == QuestStatuses::Open.to_string()
             || quest.status == QuestStatuses::Failed.to_string();
 
-        let adventurers_count_condition = adventurers_count < MAX_ADVENTURERS_PER_QUEST;
+        let adventurers_count_condition = adventurers_count < quest.max_adventurers as i64;
 
         if !adventurers_count_condition {
             return Err(anyhow::anyhow!("Quest is full"));
@@ -54,14 +78,52 @@ where
             return Err(anyhow::anyhow!("Quest is not joinable"));
         }
 
-        self.crew_switchboard_repository
-            .join(QuestAdventurerJunction {
-                quest_id,
-                adventurer_id,
-            })
+        if CrewEligibility::<T1, T2>::join_deadline_passed(&quest) {
+            return Err(anyhow::anyhow!("Quest join deadline has passed"));
+        }
+
+        self.crew_eligibility
+            .ensure_prerequisites_completed(quest_id)
             .await?;
 
-        Ok(())
+        let junction_body = QuestAdventurerJunction {
+            quest_id,
+            adventurer_id,
+        };
+
+        let membership_status = self
+            .crew_switchboard_repository
+            .membership_status(junction_body.clone())
+            .await?;
+
+        if membership_status.is_none() {
+            self.crew_eligibility
+                .ensure_eligible(quest_id, adventurer_id)
+                .await?;
+        }
+
+        match membership_status {
+            Some(CrewMembershipStatuses::Invited) => {
+                self.crew_switchboard_repository
+                    .approve(junction_body)
+                    .await?;
+
+                Ok(CrewMembershipStatuses::Joined)
+            }
+            Some(_) => Err(anyhow::anyhow!("Adventurer has already joined this quest")),
+            None if quest.approval_required => {
+                self.crew_switchboard_repository
+                    .request_to_join(junction_body)
+                    .await?;
+
+                Ok(CrewMembershipStatuses::Pending)
+            }
+            None => {
+                self.crew_switchboard_repository.join(junction_body).await?;
+
+                Ok(CrewMembershipStatuses::Joined)
+            }
+        }
     }
 
     pub async fn leave(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
@@ -74,13 +136,217 @@ where
             return Err(anyhow::anyhow!("Quest is not leavable"));
         }
 
+        let waitlist_candidates = self.crew_eligibility.waitlist_candidates(&quest).await?;
+
+        self.crew_switchboard_repository
+            .leave(
+                QuestAdventurerJunction {
+                    quest_id,
+                    adventurer_id,
+                },
+                waitlist_candidates,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn my_quests(&self, adventurer_id: i32) -> Result<QuestsByStatusModel> {
+        let results = self
+            .quest_viewing_repository
+            .joined_quests_by_adventurer_id(adventurer_id)
+            .await?;
+
+        let mut quests_by_status = QuestsByStatusModel::default();
+        for quest in results.into_iter() {
+            let adventurers_count = self
+                .quest_viewing_repository
+                .adventurers_counting_by_quest_id(quest.id)
+                .await?;
+
+            let tags = self
+                .quest_viewing_repository
+                .tags_by_quest_id(quest.id)
+                .await?;
+
+            let objectives = self
+                .quest_viewing_repository
+                .objectives_by_quest_id(quest.id)
+                .await?;
+
+            quests_by_status.push(quest.to_model(
+                adventurers_count,
+                tags,
+                objectives_progress(&objectives),
+            ))?;
+        }
+
+        Ok(quests_by_status)
+    }
+
+    pub async fn join_waitlist(
+        &self,
+        quest_id: i32,
+        adventurer_id: i32,
+    ) -> Result<WaitlistPositionModel> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        let adventurers_count = self
+            .quest_viewing_repository
+            .adventurers_counting_by_quest_id(quest_id)
+            .await?;
+
+        let quest_status_condition = quest.status == QuestStatuses::Open.to_string()
+            || quest.status == QuestStatuses::Failed.to_string();
+
+        if !quest_status_condition {
+            return Err(anyhow::anyhow!("Quest is not joinable"));
+        }
+
+        if CrewEligibility::<T1, T2>::join_deadline_passed(&quest) {
+            return Err(anyhow::anyhow!("Quest join deadline has passed"));
+        }
+
+        if adventurers_count < quest.max_adventurers as i64 {
+            return Err(anyhow::anyhow!("Quest is not full"));
+        }
+
+        let junction_body = QuestAdventurerJunction {
+            quest_id,
+            adventurer_id,
+        };
+
+        let membership_status = self
+            .crew_switchboard_repository
+            .membership_status(junction_body.clone())
+            .await?;
+
+        if membership_status.is_some() {
+            return Err(anyhow::anyhow!("Adventurer has already joined this quest"));
+        }
+
+        let position = self
+            .crew_switchboard_repository
+            .waitlist_position(junction_body.clone())
+            .await?;
+
+        if position.is_some() {
+            return Err(anyhow::anyhow!("Adventurer is already on the waitlist"));
+        }
+
+        self.crew_eligibility
+            .ensure_eligible(quest_id, adventurer_id)
+            .await?;
+
         self.crew_switchboard_repository
-            .leave(QuestAdventurerJunction {
+            .join_waitlist(junction_body.clone())
+            .await?;
+
+        self.waitlist_position(quest_id, adventurer_id).await
+    }
+
+    pub async fn leave_waitlist(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        self.crew_switchboard_repository
+            .leave_waitlist(QuestAdventurerJunction {
+                quest_id,
+                adventurer_id,
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn waitlist_position(
+        &self,
+        quest_id: i32,
+        adventurer_id: i32,
+    ) -> Result<WaitlistPositionModel> {
+        let position = self
+            .crew_switchboard_repository
+            .waitlist_position(QuestAdventurerJunction {
+                quest_id,
+                adventurer_id,
+            })
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Adventurer is not on the waitlist"))?;
+
+        Ok(WaitlistPositionModel { quest_id, position })
+    }
+
+    pub async fn complete_objective(
+        &self,
+        quest_id: i32,
+        objective_id: i32,
+        adventurer_id: i32,
+    ) -> Result<QuestObjectiveModel> {
+        self.ensure_objective_tickable(quest_id, objective_id, adventurer_id)
+            .await?;
+
+        let quest_objective = self
+            .crew_switchboard_repository
+            .complete_objective(objective_id, adventurer_id)
+            .await?;
+
+        Ok(quest_objective.to_model())
+    }
+
+    pub async fn reopen_objective(
+        &self,
+        quest_id: i32,
+        objective_id: i32,
+        adventurer_id: i32,
+    ) -> Result<QuestObjectiveModel> {
+        self.ensure_objective_tickable(quest_id, objective_id, adventurer_id)
+            .await?;
+
+        let quest_objective = self
+            .crew_switchboard_repository
+            .reopen_objective(objective_id)
+            .await?;
+
+        Ok(quest_objective.to_model())
+    }
+
+    async fn ensure_objective_tickable(
+        &self,
+        quest_id: i32,
+        objective_id: i32,
+        adventurer_id: i32,
+    ) -> Result<()> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        if quest.status != QuestStatuses::InJourney.to_string() {
+            return Err(anyhow::anyhow!(
+                "Quest objectives can only be ticked off while the quest is in journey"
+            ));
+        }
+
+        let membership_status = self
+            .crew_switchboard_repository
+            .membership_status(QuestAdventurerJunction {
                 quest_id,
                 adventurer_id,
             })
             .await?;
 
+        if membership_status != Some(CrewMembershipStatuses::Joined) {
+            return Err(anyhow::anyhow!(
+                "Adventurer is not a crew member of this quest"
+            ));
+        }
+
+        let objectives = self
+            .quest_viewing_repository
+            .objectives_by_quest_id(quest_id)
+            .await?;
+
+        if !objectives
+            .iter()
+            .any(|objective| objective.id == objective_id)
+        {
+            return Err(anyhow::anyhow!("Quest objective not found"));
+        }
+
         Ok(())
     }
 }
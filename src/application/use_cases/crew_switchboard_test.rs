@@ -7,13 +7,18 @@ mod tests {
     use crate::{
         application::use_cases::crew_switchboard::CrewSwitchboardUseCase,
         domain::{
-            entities::quests::QuestEntity,
+            entities::{quest_requirements::QuestRequirementEntity, quests::QuestEntity},
             repositories::{
                 crew_switchboard::MockCrewSwitchboardRepository,
                 quest_viewing::MockQuestViewingRepository,
             },
             value_objects::{
-                quest_adventurer_junction::MAX_ADVENTURERS_PER_QUEST, quest_statuses::QuestStatuses,
+                crew_membership_statuses::CrewMembershipStatuses,
+                eligibility_model::{AdventurerStandingModel, IneligibilityModel},
+                quest_adventurer_junction::DEFAULT_CREW_SIZE,
+                quest_difficulties::QuestDifficulties,
+                quest_statuses::QuestStatuses,
+                waitlist_model::WaitlistCandidatesModel,
             },
         },
     };
@@ -35,6 +40,18 @@ mod tests {
                     description: Some("test".to_string()),
                     status: QuestStatuses::Open.to_string(),
                     guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
                     created_at: Utc
                         .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
                         .unwrap()
@@ -48,10 +65,22 @@ mod tests {
             })
         });
 
+        mock_crew_repo
+            .expect_membership_status()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
         mock_crew_repo
             .expect_join()
             .returning(|_| Box::pin(async { Ok(()) }));
 
+        mock_quest_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_quest_repo
+            .expect_requirements_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
         let use_case =
             CrewSwitchboardUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
 
@@ -60,6 +89,194 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_join_fails_when_adventurer_is_not_eligible() {
+        let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(2) }));
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_quest_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_quest_repo
+            .expect_requirements_by_quest_id()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(Some(QuestRequirementEntity {
+                        quest_id: 1,
+                        min_level: Some(5),
+                        min_completed_quests: None,
+                        required_skills: serde_json::json!(["archery", "stealth"]),
+                        allowed_adventurer_ids: serde_json::json!([]),
+                        created_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+
+                        updated_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    }))
+                })
+            });
+
+        mock_crew_repo
+            .expect_membership_status()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        mock_crew_repo.expect_adventurer_standing().returning(|_| {
+            Box::pin(async {
+                Ok(AdventurerStandingModel {
+                    adventurer_id: 1,
+                    xp: 0,
+                    completed_quests: 0,
+                    skills: vec!["archery".to_string()],
+                })
+            })
+        });
+
+        mock_crew_repo.expect_join().never();
+
+        let use_case =
+            CrewSwitchboardUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case.join(1, 1).await;
+        let ineligibility = result
+            .unwrap_err()
+            .downcast::<IneligibilityModel>()
+            .unwrap();
+
+        assert_eq!(
+            ineligibility.failed_rules,
+            vec![
+                "Minimum level 5 is required, current level is 1".to_string(),
+                "Missing required skills: stealth".to_string(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_join_fails_when_requirements_cannot_be_decoded() {
+        let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(2) }));
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_quest_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_quest_repo
+            .expect_requirements_by_quest_id()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(Some(QuestRequirementEntity {
+                        quest_id: 1,
+                        min_level: None,
+                        min_completed_quests: None,
+                        required_skills: serde_json::json!("archery"),
+                        allowed_adventurer_ids: serde_json::json!([]),
+                        created_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+
+                        updated_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    }))
+                })
+            });
+
+        mock_crew_repo
+            .expect_membership_status()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        mock_crew_repo.expect_adventurer_standing().never();
+        mock_crew_repo.expect_join().never();
+
+        let use_case =
+            CrewSwitchboardUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let error = use_case.join(1, 1).await.unwrap_err();
+
+        assert!(error.downcast_ref::<IneligibilityModel>().is_none());
+    }
+
     #[tokio::test]
     async fn test_join_fails_when_quest_is_not_open() {
         let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
@@ -77,6 +294,18 @@ mod tests {
                     description: Some("test".to_string()),
                     status: QuestStatuses::InJourney.to_string(),
                     guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
                     created_at: Utc
                         .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
                         .unwrap()
@@ -90,6 +319,10 @@ mod tests {
             })
         });
 
+        mock_crew_repo
+            .expect_membership_status()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
         mock_crew_repo
             .expect_join()
             .returning(|_| Box::pin(async { Ok(()) }));
@@ -109,7 +342,7 @@ mod tests {
 
         mock_quest_repo
             .expect_adventurers_counting_by_quest_id()
-            .returning(|_| Box::pin(async { Ok(MAX_ADVENTURERS_PER_QUEST) }));
+            .returning(|_| Box::pin(async { Ok(DEFAULT_CREW_SIZE as i64) }));
 
         mock_quest_repo.expect_view_details().returning(|_| {
             Box::pin(async {
@@ -119,6 +352,18 @@ mod tests {
                     description: Some("test".to_string()),
                     status: QuestStatuses::Open.to_string(),
                     guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
                     created_at: Utc
                         .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
                         .unwrap()
@@ -132,6 +377,10 @@ mod tests {
             })
         });
 
+        mock_crew_repo
+            .expect_membership_status()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
         mock_crew_repo
             .expect_join()
             .returning(|_| Box::pin(async { Ok(()) }));
@@ -144,6 +393,195 @@ mod tests {
         assert_eq!(result.unwrap_err().to_string(), "Quest is full");
     }
 
+    #[tokio::test]
+    async fn test_join_requests_approval_when_required() {
+        let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(2) }));
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: true,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_crew_repo
+            .expect_membership_status()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        mock_crew_repo
+            .expect_request_to_join()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        mock_crew_repo.expect_join().never();
+
+        mock_quest_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_quest_repo
+            .expect_requirements_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        let use_case =
+            CrewSwitchboardUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case.join(1, 1).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_join_accepts_invitation() {
+        let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(2) }));
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: true,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_quest_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_crew_repo
+            .expect_membership_status()
+            .returning(|_| Box::pin(async { Ok(Some(CrewMembershipStatuses::Invited)) }));
+
+        mock_crew_repo
+            .expect_approve()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case =
+            CrewSwitchboardUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case.join(1, 1).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_join_fails_when_join_deadline_has_passed() {
+        let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(2) }));
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: Some(
+                        Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    ),
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_crew_repo.expect_join().never();
+
+        let use_case =
+            CrewSwitchboardUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case.join(1, 1).await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quest join deadline has passed"
+        );
+    }
+
     #[tokio::test]
     async fn test_leave_success() {
         let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
@@ -161,6 +599,18 @@ mod tests {
                     description: Some("test".to_string()),
                     status: QuestStatuses::Open.to_string(),
                     guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
                     created_at: Utc
                         .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
                         .unwrap()
@@ -174,9 +624,17 @@ mod tests {
             })
         });
 
+        mock_quest_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_crew_repo
+            .expect_waitlist_adventurer_ids()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
         mock_crew_repo
             .expect_leave()
-            .returning(|_| Box::pin(async { Ok(()) }));
+            .returning(|_, _| Box::pin(async { Ok(()) }));
 
         let use_case =
             CrewSwitchboardUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
@@ -198,6 +656,67 @@ mod tests {
                     description: Some("test".to_string()),
                     status: QuestStatuses::InJourney.to_string(),
                     guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_crew_repo.expect_leave().never();
+
+        let use_case =
+            CrewSwitchboardUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case.leave(1, 1).await;
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "Quest is not leavable");
+    }
+
+    #[tokio::test]
+    async fn test_leave_skips_ineligible_head_of_waitlist() {
+        let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
                     created_at: Utc
                         .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
                         .unwrap()
@@ -211,16 +730,349 @@ mod tests {
             })
         });
 
+        mock_quest_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_quest_repo
+            .expect_requirements_by_quest_id()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(Some(QuestRequirementEntity {
+                        quest_id: 1,
+                        min_level: Some(5),
+                        min_completed_quests: None,
+                        required_skills: serde_json::json!([]),
+                        allowed_adventurer_ids: serde_json::json!([]),
+                        created_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+
+                        updated_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    }))
+                })
+            });
+
+        mock_crew_repo
+            .expect_waitlist_adventurer_ids()
+            .returning(|_| Box::pin(async { Ok(vec![10, 20]) }));
+
+        mock_crew_repo
+            .expect_adventurer_standing()
+            .returning(|adventurer_id| {
+                Box::pin(async move {
+                    Ok(AdventurerStandingModel {
+                        adventurer_id,
+                        xp: if adventurer_id == 10 { 0 } else { 100_000 },
+                        completed_quests: 0,
+                        skills: vec![],
+                    })
+                })
+            });
+
         mock_crew_repo
             .expect_leave()
-            .returning(|_| Box::pin(async { Ok(()) }));
+            .withf(|_, waitlist_candidates| {
+                *waitlist_candidates
+                    == WaitlistCandidatesModel {
+                        eligible_adventurer_ids: vec![20],
+                        ineligible_adventurer_ids: vec![10],
+                    }
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
 
         let use_case =
             CrewSwitchboardUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
 
         let result = use_case.leave(1, 1).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_my_quests_groups_by_status() {
+        let mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_joined_quests_by_adventurer_id()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![
+                        QuestEntity {
+                            id: 1,
+                            name: "test".to_string(),
+                            description: Some("test".to_string()),
+                            status: QuestStatuses::Open.to_string(),
+                            guild_commander_id: 1,
+                            approval_required: false,
+                            starts_at: None,
+                            due_at: None,
+                            join_deadline: None,
+                            reward_gold: 0,
+                            reward_xp: 0,
+                            difficulty: QuestDifficulties::Normal.to_string(),
+                            category_id: None,
+                            max_adventurers: DEFAULT_CREW_SIZE,
+                            series_id: None,
+                            objectives_required: false,
+                            version: 1,
+                            created_at: Utc
+                                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                                .unwrap()
+                                .naive_utc(),
+
+                            updated_at: Utc
+                                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                                .unwrap()
+                                .naive_utc(),
+                        },
+                        QuestEntity {
+                            id: 2,
+                            name: "test".to_string(),
+                            description: Some("test".to_string()),
+                            status: QuestStatuses::Completed.to_string(),
+                            guild_commander_id: 1,
+                            approval_required: false,
+                            starts_at: None,
+                            due_at: None,
+                            join_deadline: None,
+                            reward_gold: 0,
+                            reward_xp: 0,
+                            difficulty: QuestDifficulties::Normal.to_string(),
+                            category_id: None,
+                            max_adventurers: DEFAULT_CREW_SIZE,
+                            series_id: None,
+                            objectives_required: false,
+                            version: 1,
+                            created_at: Utc
+                                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                                .unwrap()
+                                .naive_utc(),
 
+                            updated_at: Utc
+                                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                                .unwrap()
+                                .naive_utc(),
+                        },
+                    ])
+                })
+            });
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(3) }));
+
+        mock_quest_repo
+            .expect_tags_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec!["dragons".to_string()]) }));
+
+        mock_quest_repo
+            .expect_objectives_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        let use_case =
+            CrewSwitchboardUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case.my_quests(1).await.unwrap();
+
+        assert_eq!(result.open.len(), 1);
+        assert_eq!(result.open[0].adventurers_count, 3);
+        assert_eq!(result.completed.len(), 1);
+        assert!(result.in_journey.is_empty());
+        assert!(result.failed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_join_waitlist_fails_when_quest_is_not_full() {
+        let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(2) }));
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_crew_repo.expect_join_waitlist().never();
+
+        let use_case =
+            CrewSwitchboardUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case.join_waitlist(1, 1).await;
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err().to_string(), "Quest is not leavable");
+        assert_eq!(result.unwrap_err().to_string(), "Quest is not full");
+    }
+
+    #[tokio::test]
+    async fn test_join_waitlist_success() {
+        let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(DEFAULT_CREW_SIZE as i64) }));
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_crew_repo
+            .expect_membership_status()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        let mut sequence = mockall::Sequence::new();
+
+        mock_crew_repo
+            .expect_waitlist_position()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        mock_crew_repo
+            .expect_join_waitlist()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        mock_crew_repo
+            .expect_waitlist_position()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Box::pin(async { Ok(Some(3)) }));
+
+        mock_quest_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_quest_repo
+            .expect_requirements_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        let use_case =
+            CrewSwitchboardUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case.join_waitlist(1, 1).await.unwrap();
+        assert_eq!(result.position, 3);
+    }
+
+    #[tokio::test]
+    async fn test_complete_objective_fails_when_adventurer_is_not_crew_member() {
+        let mut mock_crew_repo = MockCrewSwitchboardRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::InJourney.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_crew_repo
+            .expect_membership_status()
+            .returning(|_| Box::pin(async { Ok(Some(CrewMembershipStatuses::Pending)) }));
+
+        mock_crew_repo.expect_complete_objective().never();
+
+        let use_case =
+            CrewSwitchboardUseCase::new(Arc::new(mock_crew_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case.complete_objective(1, 1, 1).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Adventurer is not a crew member of this quest"
+        );
     }
 }
@@ -1,13 +1,25 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use tracing::error;
 
-use crate::domain::{
-    repositories::{
-        journey_ledger::JourneyLedgerRepository, quest_viewing::QuestViewingRepository,
-    },
-    value_objects::{
-        quest_adventurer_junction::MAX_ADVENTURERS_PER_QUEST, quest_statuses::QuestStatuses,
+use crate::{
+    config::config_loader,
+    domain::{
+        entities::quests::QuestEntity,
+        repositories::{
+            journey_ledger::JourneyLedgerRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_bulk_model::{
+                BulkPlan, BulkResultModel, BulkTransitionQuestsModel, QuestTransitionModel,
+            },
+            quest_dependency_model::pending_prerequisites,
+            quest_model::QuestsByStatusModel,
+            quest_objective_model::objectives_progress,
+            quest_reward_model::QuestRewardModel,
+            quest_statuses::QuestStatuses,
+        },
     },
 };
 
@@ -33,22 +45,9 @@ where
     }
 
     pub async fn in_journey(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
-        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
-
-        let adventurers_count = self
-            .quest_viewing_repository
-            .adventurers_counting_by_quest_id(quest_id)
+        self.prepare_transition(quest_id, guild_commander_id, QuestStatuses::InJourney)
             .await?;
 
-        let conditions_to_update = (quest.status == QuestStatuses::Open.to_string()
-            || quest.status == QuestStatuses::Failed.to_string())
-            && adventurers_count > 0
-            && adventurers_count <= MAX_ADVENTURERS_PER_QUEST;
-
-        if !conditions_to_update {
-            return Err(anyhow::anyhow!("Invalid condition to change status"));
-        }
-
         let result = self
             .journey_ledger_repository
             .in_journey(quest_id, guild_commander_id)
@@ -58,36 +57,246 @@ where
     }
 
     pub async fn to_completed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
-        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+        let transition = self
+            .prepare_transition(quest_id, guild_commander_id, QuestStatuses::Completed)
+            .await?;
 
-        let condition_to_update = quest.status == QuestStatuses::InJourney.to_string();
+        let result = self
+            .journey_ledger_repository
+            .to_completed(quest_id, guild_commander_id, transition.reward)
+            .await?;
 
-        if !condition_to_update {
-            return Err(anyhow::anyhow!("Invalid condition to change status"));
-        }
+        Ok(result)
+    }
+
+    pub async fn to_failed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+        let transition = self
+            .prepare_transition(quest_id, guild_commander_id, QuestStatuses::Failed)
+            .await?;
 
         let result = self
             .journey_ledger_repository
-            .to_completed(quest_id, guild_commander_id)
+            .to_failed(quest_id, guild_commander_id, transition.reward)
             .await?;
 
         Ok(result)
     }
 
-    pub async fn to_failed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
-        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+    pub async fn transition_many(
+        &self,
+        guild_commander_id: i32,
+        bulk_transition_quests_model: BulkTransitionQuestsModel,
+    ) -> Result<BulkResultModel> {
+        if bulk_transition_quests_model.status == QuestStatuses::Open {
+            return Err(anyhow::anyhow!("Quests cannot be moved back to Open"));
+        }
 
-        let condition_to_update = quest.status == QuestStatuses::InJourney.to_string();
+        let quest_ids = match (
+            bulk_transition_quests_model.quest_ids,
+            bulk_transition_quests_model.overdue.unwrap_or(false),
+        ) {
+            (Some(quest_ids), false) => quest_ids,
+            (None, true) => self
+                .quest_viewing_repository
+                .overdue_quests(chrono::Utc::now().naive_utc())
+                .await?
+                .into_iter()
+                .filter(|quest| quest.guild_commander_id == guild_commander_id)
+                .map(|quest| quest.id)
+                .collect(),
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Either quest ids or the overdue selector must be given"
+                ))
+            }
+        };
 
-        if !condition_to_update {
-            return Err(anyhow::anyhow!("Invalid condition to change status"));
+        let mut prepared = Vec::with_capacity(quest_ids.len());
+        for quest_id in quest_ids.iter() {
+            prepared.push(
+                self.prepare_transition(
+                    *quest_id,
+                    guild_commander_id,
+                    bulk_transition_quests_model.status.clone(),
+                )
+                .await,
+            );
         }
 
-        let result = self
-            .journey_ledger_repository
-            .to_failed(quest_id, guild_commander_id)
+        let mut bulk_plan = BulkPlan::new(
+            prepared,
+            bulk_transition_quests_model.all_or_nothing.unwrap_or(false),
+        );
+
+        let executed = match bulk_plan.take_executable() {
+            Some(transitions) => {
+                self.journey_ledger_repository
+                    .transition_many(transitions, guild_commander_id, bulk_plan.all_or_nothing())
+                    .await?
+            }
+            None => Vec::new(),
+        };
+
+        let requested_quest_ids = quest_ids.into_iter().map(Some).collect();
+
+        Ok(bulk_plan.finish(requested_quest_ids, executed))
+    }
+
+    pub async fn my_quests(&self, guild_commander_id: i32) -> Result<QuestsByStatusModel> {
+        let results = self
+            .quest_viewing_repository
+            .owned_quests_by_guild_commander_id(guild_commander_id)
             .await?;
 
-        Ok(result)
+        let mut quests_by_status = QuestsByStatusModel::default();
+        for quest in results.into_iter() {
+            let adventurers_count = self
+                .quest_viewing_repository
+                .adventurers_counting_by_quest_id(quest.id)
+                .await?;
+
+            let tags = self
+                .quest_viewing_repository
+                .tags_by_quest_id(quest.id)
+                .await?;
+
+            let objectives = self
+                .quest_viewing_repository
+                .objectives_by_quest_id(quest.id)
+                .await?;
+
+            quests_by_status.push(quest.to_model(
+                adventurers_count,
+                tags,
+                objectives_progress(&objectives),
+            ))?;
+        }
+
+        Ok(quests_by_status)
+    }
+
+    pub async fn fail_overdue_quests(&self) -> Result<Vec<i32>> {
+        let overdue_quests = self
+            .quest_viewing_repository
+            .overdue_quests(chrono::Utc::now().naive_utc())
+            .await?;
+
+        let mut failed_quest_ids = Vec::new();
+        for quest in overdue_quests.into_iter() {
+            let result = match Self::failed_reward(&quest) {
+                Ok(reward) => {
+                    self.journey_ledger_repository
+                        .to_failed_by_system(quest.id, reward)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(quest_id) => failed_quest_ids.push(quest_id),
+                Err(e) => error!("Failed to fail overdue quest {}: {}", quest.id, e),
+            }
+        }
+
+        Ok(failed_quest_ids)
+    }
+
+    async fn prepare_transition(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        status: QuestStatuses,
+    ) -> Result<QuestTransitionModel> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        if quest.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest is not owned by this guild commander"
+            ));
+        }
+
+        let reward = match status {
+            QuestStatuses::InJourney => {
+                let adventurers_count = self
+                    .quest_viewing_repository
+                    .adventurers_counting_by_quest_id(quest_id)
+                    .await?;
+
+                let conditions_to_update = (quest.status == QuestStatuses::Open.to_string()
+                    || quest.status == QuestStatuses::Failed.to_string())
+                    && adventurers_count > 0
+                    && adventurers_count <= quest.max_adventurers as i64;
+
+                if !conditions_to_update {
+                    return Err(anyhow::anyhow!("Invalid condition to change status"));
+                }
+
+                self.ensure_prerequisites_completed(quest_id).await?;
+
+                QuestRewardModel::default()
+            }
+            QuestStatuses::Completed => {
+                if quest.status != QuestStatuses::InJourney.to_string() {
+                    return Err(anyhow::anyhow!("Invalid condition to change status"));
+                }
+
+                if quest.objectives_required {
+                    let objectives = self
+                        .quest_viewing_repository
+                        .objectives_by_quest_id(quest_id)
+                        .await?;
+
+                    if objectives.iter().any(|objective| !objective.done) {
+                        return Err(anyhow::anyhow!(
+                            "Quest objectives must all be done before completion"
+                        ));
+                    }
+                }
+
+                quest.reward()
+            }
+            QuestStatuses::Failed => {
+                if quest.status != QuestStatuses::InJourney.to_string() {
+                    return Err(anyhow::anyhow!("Invalid condition to change status"));
+                }
+
+                Self::failed_reward(&quest)?
+            }
+            QuestStatuses::Open => {
+                return Err(anyhow::anyhow!("Invalid condition to change status"));
+            }
+        };
+
+        Ok(QuestTransitionModel {
+            quest_id,
+            status,
+            reward,
+        })
+    }
+
+    async fn ensure_prerequisites_completed(&self, quest_id: i32) -> Result<()> {
+        let prerequisites = self
+            .quest_viewing_repository
+            .prerequisites_by_quest_id(quest_id)
+            .await?;
+
+        let pending_prerequisites = pending_prerequisites(&prerequisites);
+
+        if !pending_prerequisites.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Quest prerequisites must be completed first: {:?}",
+                pending_prerequisites
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn failed_reward(quest: &QuestEntity) -> Result<QuestRewardModel> {
+        let rewards = config_loader::get_rewards_env()?;
+
+        Ok(quest
+            .reward()
+            .partial(rewards.failed_gold_percent, rewards.failed_xp_percent))
     }
 }
@@ -0,0 +1,512 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::use_cases::journey_ledger::JourneyLedgerUseCase,
+        domain::{
+            entities::{quest_objectives::QuestObjectiveEntity, quests::QuestEntity},
+            repositories::{
+                journey_ledger::MockJourneyLedgerRepository,
+                quest_viewing::MockQuestViewingRepository,
+            },
+            value_objects::{
+                quest_adventurer_junction::DEFAULT_CREW_SIZE,
+                quest_bulk_model::{BulkItemStatuses, BulkTransitionQuestsModel},
+                quest_difficulties::QuestDifficulties,
+                quest_reward_model::QuestRewardModel,
+                quest_statuses::QuestStatuses,
+            },
+        },
+    };
+
+    #[tokio::test]
+    async fn test_fail_overdue_quests_success() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_overdue_quests().returning(|_| {
+            Box::pin(async {
+                Ok(vec![QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::InJourney.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: Some(
+                        Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    ),
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                }])
+            })
+        });
+
+        mock_journey_ledger_repo
+            .expect_to_failed_by_system()
+            .times(1)
+            .returning(|quest_id, _| Box::pin(async move { Ok(quest_id) }));
+
+        mock_journey_ledger_repo.expect_to_failed().never();
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_repo),
+        );
+
+        let result = use_case.fail_overdue_quests().await;
+        assert_eq!(result.unwrap(), vec![1]);
+    }
+
+    #[tokio::test]
+    async fn test_fail_overdue_quests_continues_after_a_failure() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_overdue_quests().returning(|_| {
+            Box::pin(async { Ok(vec![in_journey_quest(1, 1), in_journey_quest(2, 1)]) })
+        });
+
+        mock_journey_ledger_repo
+            .expect_to_failed_by_system()
+            .times(2)
+            .returning(|quest_id, _| {
+                Box::pin(async move {
+                    if quest_id == 1 {
+                        Err(anyhow::anyhow!("Invalid condition to change status"))
+                    } else {
+                        Ok(quest_id)
+                    }
+                })
+            });
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_repo),
+        );
+
+        let result = use_case.fail_overdue_quests().await;
+        assert_eq!(result.unwrap(), vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_fail_overdue_quests_without_overdue_quests() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_overdue_quests()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        mock_journey_ledger_repo
+            .expect_to_failed_by_system()
+            .never();
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_repo),
+        );
+
+        let result = use_case.fail_overdue_quests().await;
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_to_completed_pays_full_reward() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::InJourney.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 50,
+                    reward_xp: 120,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_journey_ledger_repo
+            .expect_to_completed()
+            .withf(|_, _, reward| *reward == QuestRewardModel { gold: 50, xp: 120 })
+            .times(1)
+            .returning(|quest_id, _, _| Box::pin(async move { Ok(quest_id) }));
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_repo),
+        );
+
+        let result = use_case.to_completed(1, 1).await;
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_to_completed_fails_when_objectives_are_pending() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::InJourney.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 50,
+                    reward_xp: 120,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: true,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_quest_repo
+            .expect_objectives_by_quest_id()
+            .returning(|quest_id| {
+                Box::pin(async move {
+                    Ok([true, false]
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, done)| QuestObjectiveEntity {
+                            id: index as i32 + 1,
+                            quest_id,
+                            position: index as i32 + 1,
+                            title: format!("objective {}", index + 1),
+                            description: None,
+                            done,
+                            completed_by: None,
+                            completed_at: None,
+                            created_at: Utc
+                                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                                .unwrap()
+                                .naive_utc(),
+
+                            updated_at: Utc
+                                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                                .unwrap()
+                                .naive_utc(),
+                        })
+                        .collect())
+                })
+            });
+
+        mock_journey_ledger_repo.expect_to_completed().never();
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_repo),
+        );
+
+        let result = use_case.to_completed(1, 1).await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quest objectives must all be done before completion"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_in_journey_fails_when_prerequisites_are_pending() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_view_details().returning(|_| {
+            Box::pin(async {
+                Ok(QuestEntity {
+                    id: 1,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 1,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(1) }));
+
+        mock_quest_repo
+            .expect_prerequisites_by_quest_id()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![QuestEntity {
+                        id: 2,
+                        name: "test".to_string(),
+                        description: Some("test".to_string()),
+                        status: QuestStatuses::InJourney.to_string(),
+                        guild_commander_id: 1,
+                        approval_required: false,
+                        starts_at: None,
+                        due_at: None,
+                        join_deadline: None,
+                        reward_gold: 0,
+                        reward_xp: 0,
+                        difficulty: QuestDifficulties::Normal.to_string(),
+                        category_id: None,
+                        max_adventurers: DEFAULT_CREW_SIZE,
+                        series_id: None,
+                        objectives_required: false,
+                        version: 1,
+                        created_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+
+                        updated_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    }])
+                })
+            });
+
+        mock_journey_ledger_repo.expect_in_journey().never();
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_repo),
+        );
+
+        let result = use_case.in_journey(1, 1).await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quest prerequisites must be completed first: [2]"
+        );
+    }
+
+    fn in_journey_quest(id: i32, guild_commander_id: i32) -> QuestEntity {
+        QuestEntity {
+            id,
+            name: "test".to_string(),
+            description: Some("test".to_string()),
+            status: QuestStatuses::InJourney.to_string(),
+            guild_commander_id,
+            approval_required: false,
+            starts_at: None,
+            due_at: Some(
+                Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+            ),
+            join_deadline: None,
+            reward_gold: 0,
+            reward_xp: 0,
+            difficulty: QuestDifficulties::Normal.to_string(),
+            category_id: None,
+            max_adventurers: DEFAULT_CREW_SIZE,
+            series_id: None,
+            objectives_required: false,
+            version: 1,
+            created_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transition_many_fails_own_overdue_quests() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_overdue_quests().returning(|_| {
+            Box::pin(async { Ok(vec![in_journey_quest(1, 1), in_journey_quest(2, 2)]) })
+        });
+
+        mock_quest_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(in_journey_quest(quest_id, 1)) }));
+
+        mock_journey_ledger_repo
+            .expect_transition_many()
+            .withf(|transitions, guild_commander_id, all_or_nothing| {
+                transitions.len() == 1
+                    && transitions[0].quest_id == 1
+                    && transitions[0].status == QuestStatuses::Failed
+                    && *guild_commander_id == 1
+                    && !*all_or_nothing
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(vec![Ok(1)]) }));
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_repo),
+        );
+
+        let result = use_case
+            .transition_many(
+                1,
+                BulkTransitionQuestsModel {
+                    status: QuestStatuses::Failed,
+                    quest_ids: None,
+                    overdue: Some(true),
+                    all_or_nothing: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(result.committed);
+        assert_eq!(result.succeeded, 1);
+        assert_eq!(result.results[0].quest_id, Some(1));
+        assert_eq!(result.results[0].status, BulkItemStatuses::Succeeded);
+    }
+
+    #[tokio::test]
+    async fn test_transition_many_rejects_open_status() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_journey_ledger_repo.expect_transition_many().never();
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_repo),
+        );
+
+        let result = use_case
+            .transition_many(
+                1,
+                BulkTransitionQuestsModel {
+                    status: QuestStatuses::Open,
+                    quest_ids: Some(vec![1]),
+                    overdue: None,
+                    all_or_nothing: None,
+                },
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quests cannot be moved back to Open"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transition_many_rejects_quests_owned_by_another_commander() {
+        let mut mock_journey_ledger_repo = MockJourneyLedgerRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(in_journey_quest(quest_id, 2)) }));
+
+        mock_journey_ledger_repo.expect_transition_many().never();
+
+        let use_case = JourneyLedgerUseCase::new(
+            Arc::new(mock_journey_ledger_repo),
+            Arc::new(mock_quest_repo),
+        );
+
+        let result = use_case
+            .transition_many(
+                1,
+                BulkTransitionQuestsModel {
+                    status: QuestStatuses::Completed,
+                    quest_ids: Some(vec![1]),
+                    overdue: None,
+                    all_or_nothing: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.results[0].status, BulkItemStatuses::Failed);
+        assert_eq!(
+            result.results[0].error.as_deref(),
+            Some("Quest is not owned by this guild commander")
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use std::{cmp::Ordering, sync::Arc};
+
+use anyhow::Result;
+
+use crate::domain::{
+    repositories::leaderboards::LeaderboardsRepository,
+    value_objects::leaderboard_model::{
+        LeaderboardEntryModel, LeaderboardFilter, LeaderboardSorts, LeaderboardStatsModel,
+    },
+};
+
+pub const DEFAULT_LEADERBOARD_LIMIT: i64 = 10;
+pub const MAX_LEADERBOARD_LIMIT: i64 = 100;
+
+pub struct LeaderboardsUseCase<T>
+where
+    T: LeaderboardsRepository + Send + Sync,
+{
+    leaderboards_repository: Arc<T>,
+}
+
+impl<T> LeaderboardsUseCase<T>
+where
+    T: LeaderboardsRepository + Send + Sync,
+{
+    pub fn new(leaderboards_repository: Arc<T>) -> Self {
+        Self {
+            leaderboards_repository,
+        }
+    }
+
+    pub async fn adventurers(
+        &self,
+        filter: &LeaderboardFilter,
+    ) -> Result<Vec<LeaderboardEntryModel>> {
+        let stats = self
+            .leaderboards_repository
+            .adventurers_stats(filter.window.unwrap_or_default())
+            .await?;
+
+        Ok(Self::rank(stats, filter))
+    }
+
+    pub async fn guild_commanders(
+        &self,
+        filter: &LeaderboardFilter,
+    ) -> Result<Vec<LeaderboardEntryModel>> {
+        let stats = self
+            .leaderboards_repository
+            .guild_commanders_stats(filter.window.unwrap_or_default())
+            .await?;
+
+        Ok(Self::rank(stats, filter))
+    }
+
+    fn rank(
+        mut stats: Vec<LeaderboardStatsModel>,
+        filter: &LeaderboardFilter,
+    ) -> Vec<LeaderboardEntryModel> {
+        let sort_by = filter.sort_by.unwrap_or_default();
+        let limit = filter
+            .limit
+            .unwrap_or(DEFAULT_LEADERBOARD_LIMIT)
+            .clamp(1, MAX_LEADERBOARD_LIMIT);
+
+        stats.sort_by(|a, b| {
+            let ordering = match sort_by {
+                LeaderboardSorts::Completed => b.completed_quests.cmp(&a.completed_quests),
+                LeaderboardSorts::SuccessRate => b
+                    .success_rate()
+                    .partial_cmp(&a.success_rate())
+                    .unwrap_or(Ordering::Equal),
+                LeaderboardSorts::Xp => b.xp.cmp(&a.xp),
+            };
+
+            ordering
+                .then_with(|| b.completed_quests.cmp(&a.completed_quests))
+                .then_with(|| b.xp.cmp(&a.xp))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        stats
+            .iter()
+            .take(limit as usize)
+            .enumerate()
+            .map(|(index, stats)| stats.to_entry(index as i64 + 1))
+            .collect()
+    }
+}
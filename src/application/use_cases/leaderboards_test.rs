@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        application::use_cases::leaderboards::LeaderboardsUseCase,
+        domain::{
+            repositories::leaderboards::MockLeaderboardsRepository,
+            value_objects::{
+                leaderboard_model::{LeaderboardFilter, LeaderboardSorts, LeaderboardStatsModel},
+                leaderboard_windows::LeaderboardWindows,
+            },
+        },
+    };
+
+    fn stats() -> Vec<LeaderboardStatsModel> {
+        vec![
+            LeaderboardStatsModel {
+                id: 1,
+                username: "veteran".to_string(),
+                completed_quests: 6,
+                failed_quests: 4,
+                xp: 900,
+            },
+            LeaderboardStatsModel {
+                id: 2,
+                username: "rookie".to_string(),
+                completed_quests: 2,
+                failed_quests: 0,
+                xp: 200,
+            },
+            LeaderboardStatsModel {
+                id: 3,
+                username: "unlucky".to_string(),
+                completed_quests: 0,
+                failed_quests: 3,
+                xp: 75,
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_adventurers_ranked_by_success_rate() {
+        let mut mock_leaderboards_repo = MockLeaderboardsRepository::new();
+
+        mock_leaderboards_repo
+            .expect_adventurers_stats()
+            .withf(|window| *window == LeaderboardWindows::Week)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(stats()) }));
+
+        let use_case = LeaderboardsUseCase::new(Arc::new(mock_leaderboards_repo));
+
+        let filter = LeaderboardFilter {
+            window: Some(LeaderboardWindows::Week),
+            sort_by: Some(LeaderboardSorts::SuccessRate),
+            limit: Some(2),
+        };
+
+        let result = use_case.adventurers(&filter).await.unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!((result[0].rank, result[0].id), (1, 2));
+        assert_eq!((result[1].rank, result[1].id), (2, 1));
+        assert_eq!(result[1].success_rate, 0.6);
+    }
+
+    #[tokio::test]
+    async fn test_guild_commanders_ranked_by_completed_quests_by_default() {
+        let mut mock_leaderboards_repo = MockLeaderboardsRepository::new();
+
+        mock_leaderboards_repo
+            .expect_guild_commanders_stats()
+            .withf(|window| *window == LeaderboardWindows::AllTime)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(stats()) }));
+
+        let use_case = LeaderboardsUseCase::new(Arc::new(mock_leaderboards_repo));
+
+        let result = use_case
+            .guild_commanders(&LeaderboardFilter::default())
+            .await
+            .unwrap();
+        let ids: Vec<i32> = result.iter().map(|entry| entry.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+}
@@ -1,8 +1,31 @@
 pub mod adventurers;
+pub mod adventurers_test;
 pub mod authentication;
+pub mod crew_management;
+pub mod crew_management_test;
 pub mod crew_switchboard;
 pub mod crew_switchboard_test;
 pub mod guild_commanders;
 pub mod journey_ledger;
+pub mod journey_ledger_test;
+pub mod leaderboards;
+pub mod leaderboards_test;
+pub mod notifications;
+pub mod notifications_test;
+pub mod outbox_relay;
+pub mod outbox_relay_test;
+pub mod quest_categories;
+pub mod quest_categories_test;
+pub mod quest_comments;
+pub mod quest_comments_test;
 pub mod quest_ops;
+pub mod quest_ops_test;
+pub mod quest_series;
+pub mod quest_series_test;
+pub mod quest_templates;
+pub mod quest_templates_test;
 pub mod quest_viewing;
+pub mod webhook_deliveries;
+pub mod webhook_deliveries_test;
+pub mod webhooks;
+pub mod webhooks_test;
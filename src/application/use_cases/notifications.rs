@@ -0,0 +1,214 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::async_trait;
+
+use crate::domain::{
+    repositories::{
+        notifications::NotificationsRepository, quest_event_handler::QuestEventHandler,
+        quest_viewing::QuestViewingRepository,
+    },
+    value_objects::{
+        authenticated_roles::{AuthenticatedIdentity, AuthenticatedRoles},
+        notification_model::{
+            NotificationFilter, NotificationMessageModel, NotificationModel,
+            UnreadNotificationsModel,
+        },
+        outbox_event_model::OutboxEventModel,
+        quest_event_model::{QuestEventKinds, QuestEventModel},
+    },
+};
+
+pub const DEFAULT_NOTIFICATIONS_LIMIT: i64 = 50;
+pub const MAX_NOTIFICATIONS_LIMIT: i64 = 200;
+
+pub struct NotificationsUseCase<T1, T2>
+where
+    T1: NotificationsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    notifications_repository: Arc<T1>,
+    quest_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> NotificationsUseCase<T1, T2>
+where
+    T1: NotificationsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    pub fn new(notifications_repository: Arc<T1>, quest_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            notifications_repository,
+            quest_viewing_repository,
+        }
+    }
+
+    pub async fn list(
+        &self,
+        recipient: AuthenticatedIdentity,
+        filter: &NotificationFilter,
+    ) -> Result<Vec<NotificationModel>> {
+        let limit = filter
+            .limit
+            .unwrap_or(DEFAULT_NOTIFICATIONS_LIMIT)
+            .clamp(1, MAX_NOTIFICATIONS_LIMIT);
+
+        let results = self
+            .notifications_repository
+            .notifications_by_recipient(
+                recipient.role.to_string(),
+                recipient.id,
+                filter.unread_only.unwrap_or(false),
+                limit,
+            )
+            .await?;
+
+        Ok(results
+            .iter()
+            .map(|notification| notification.to_model())
+            .collect())
+    }
+
+    pub async fn unread_count(
+        &self,
+        recipient: AuthenticatedIdentity,
+    ) -> Result<UnreadNotificationsModel> {
+        let unread = self
+            .notifications_repository
+            .unread_counting_by_recipient(recipient.role.to_string(), recipient.id)
+            .await?;
+
+        Ok(UnreadNotificationsModel { unread })
+    }
+
+    pub async fn mark_read(
+        &self,
+        notification_id: i32,
+        recipient: AuthenticatedIdentity,
+    ) -> Result<i32> {
+        let result = self
+            .notifications_repository
+            .mark_read(notification_id, recipient.role.to_string(), recipient.id)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn mark_all_read(&self, recipient: AuthenticatedIdentity) -> Result<usize> {
+        let result = self
+            .notifications_repository
+            .mark_all_read(recipient.role.to_string(), recipient.id)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn notify(&self, idempotency_key: &str, event: &QuestEventModel) -> Result<usize> {
+        let messages = match event.kind {
+            QuestEventKinds::CrewJoined | QuestEventKinds::CrewLeft => {
+                self.commander_messages(event).await?
+            }
+            QuestEventKinds::StatusChanged => self.crew_messages(event).await?,
+            QuestEventKinds::QuestAdded
+            | QuestEventKinds::QuestEdited
+            | QuestEventKinds::QuestRemoved => Vec::new(),
+        };
+
+        if messages.is_empty() {
+            return Ok(0);
+        }
+
+        let result = self
+            .notifications_repository
+            .add(
+                messages
+                    .iter()
+                    .map(|message| message.to_entity(idempotency_key, event))
+                    .collect(),
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn commander_messages(
+        &self,
+        event: &QuestEventModel,
+    ) -> Result<Vec<NotificationMessageModel>> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(event.quest_id)
+            .await?;
+
+        let adventurer_id = event.adventurer_id.unwrap_or_default();
+
+        let message = match event.kind {
+            QuestEventKinds::CrewJoined => format!(
+                "Adventurer #{} has joined your quest \"{}\"",
+                adventurer_id, quest.name
+            ),
+            _ => format!(
+                "Adventurer #{} has left your quest \"{}\"",
+                adventurer_id, quest.name
+            ),
+        };
+
+        Ok(vec![NotificationMessageModel {
+            recipient: AuthenticatedIdentity {
+                role: AuthenticatedRoles::GuildCommander,
+                id: quest.guild_commander_id,
+            },
+            message,
+        }])
+    }
+
+    async fn crew_messages(
+        &self,
+        event: &QuestEventModel,
+    ) -> Result<Vec<NotificationMessageModel>> {
+        let Some(status) = event.status.as_ref() else {
+            return Ok(Vec::new());
+        };
+
+        if event.recipient_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let quest = self
+            .quest_viewing_repository
+            .view_details(event.quest_id)
+            .await?;
+
+        let message = format!("Quest \"{}\" is now {}", quest.name, status);
+
+        Ok(event
+            .recipient_ids
+            .iter()
+            .map(|adventurer_id| NotificationMessageModel {
+                recipient: AuthenticatedIdentity {
+                    role: AuthenticatedRoles::Adventurer,
+                    id: *adventurer_id,
+                },
+                message: message.clone(),
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl<T1, T2> QuestEventHandler for NotificationsUseCase<T1, T2>
+where
+    T1: NotificationsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    fn name(&self) -> &'static str {
+        "notifications"
+    }
+
+    async fn handle(&self, outbox_event: &OutboxEventModel) -> Result<()> {
+        self.notify(&outbox_event.idempotency_key, &outbox_event.event)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::use_cases::notifications::NotificationsUseCase,
+        domain::{
+            entities::quests::QuestEntity,
+            repositories::{
+                notifications::MockNotificationsRepository,
+                quest_viewing::MockQuestViewingRepository,
+            },
+            value_objects::{
+                authenticated_roles::AuthenticatedRoles,
+                quest_adventurer_junction::DEFAULT_CREW_SIZE,
+                quest_difficulties::QuestDifficulties,
+                quest_event_model::{QuestEventKinds, QuestEventModel},
+                quest_statuses::QuestStatuses,
+            },
+        },
+    };
+
+    fn quest_viewing_repo() -> MockQuestViewingRepository {
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestEntity {
+                        id: 1,
+                        name: "Dragon Hunt".to_string(),
+                        description: Some("test".to_string()),
+                        status: QuestStatuses::InJourney.to_string(),
+                        guild_commander_id: 7,
+                        approval_required: false,
+                        starts_at: None,
+                        due_at: None,
+                        join_deadline: None,
+                        reward_gold: 0,
+                        reward_xp: 0,
+                        difficulty: QuestDifficulties::Normal.to_string(),
+                        category_id: None,
+                        max_adventurers: DEFAULT_CREW_SIZE,
+                        series_id: None,
+                        objectives_required: false,
+                        version: 1,
+                        created_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+
+                        updated_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    })
+                })
+            });
+
+        mock_quest_viewing_repo
+    }
+
+    #[tokio::test]
+    async fn test_notify_status_changed_notifies_crew_recorded_with_the_event() {
+        let mut mock_notifications_repo = MockNotificationsRepository::new();
+        let mut mock_quest_viewing_repo = quest_viewing_repo();
+
+        mock_quest_viewing_repo.expect_crew_by_quest_id().never();
+
+        mock_notifications_repo
+            .expect_add()
+            .withf(|add_notification_entities| {
+                add_notification_entities.len() == 2
+                    && add_notification_entities.iter().all(|entity| {
+                        entity.recipient_role == AuthenticatedRoles::Adventurer.to_string()
+                            && entity.idempotency_key.as_deref() == Some("status-changed-key")
+                            && entity.message == "Quest \"Dragon Hunt\" is now InJourney"
+                    })
+            })
+            .times(1)
+            .returning(|entities| {
+                let count = entities.len();
+                Box::pin(async move { Ok(count) })
+            });
+
+        let use_case = NotificationsUseCase::new(
+            Arc::new(mock_notifications_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case
+            .notify(
+                "status-changed-key",
+                &QuestEventModel::status_changed(1, QuestStatuses::InJourney, vec![2, 3]),
+            )
+            .await;
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_notify_crew_joined_notifies_guild_commander() {
+        let mut mock_notifications_repo = MockNotificationsRepository::new();
+
+        mock_notifications_repo
+            .expect_add()
+            .withf(|add_notification_entities| {
+                add_notification_entities.len() == 1
+                    && add_notification_entities[0].recipient_role
+                        == AuthenticatedRoles::GuildCommander.to_string()
+                    && add_notification_entities[0].recipient_id == 7
+                    && add_notification_entities[0].kind == QuestEventKinds::CrewJoined.to_string()
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(1) }));
+
+        let use_case = NotificationsUseCase::new(
+            Arc::new(mock_notifications_repo),
+            Arc::new(quest_viewing_repo()),
+        );
+
+        let result = use_case
+            .notify(
+                "crew-joined-key",
+                &QuestEventModel::crew(QuestEventKinds::CrewJoined, 1, 2),
+            )
+            .await;
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_notify_quest_removed_sends_nothing() {
+        let mut mock_notifications_repo = MockNotificationsRepository::new();
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo.expect_view_details().never();
+        mock_quest_viewing_repo.expect_crew_by_quest_id().never();
+        mock_notifications_repo.expect_add().never();
+
+        let use_case = NotificationsUseCase::new(
+            Arc::new(mock_notifications_repo),
+            Arc::new(mock_quest_viewing_repo),
+        );
+
+        let result = use_case
+            .notify(
+                "quest-removed-key",
+                &QuestEventModel::quest(QuestEventKinds::QuestRemoved, 1),
+            )
+            .await;
+        assert_eq!(result.unwrap(), 0);
+    }
+}
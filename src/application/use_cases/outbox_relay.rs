@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime};
+use tracing::error;
+
+use crate::domain::{
+    entities::outbox_events::OutboxEventEntity,
+    repositories::{outbox::OutboxRepository, quest_event_handler::QuestEventHandler},
+    value_objects::outbox_event_model::outbox_retry_backoff,
+};
+
+pub const MAX_OUTBOX_ATTEMPTS: i32 = 20;
+pub const OUTBOX_RELAY_BATCH_SIZE: i64 = 100;
+pub const OUTBOX_CLAIM_SECONDS: i64 = 60;
+
+pub struct OutboxRelayUseCase<T>
+where
+    T: OutboxRepository + Send + Sync,
+{
+    outbox_repository: Arc<T>,
+    quest_event_handlers: Vec<Arc<dyn QuestEventHandler + Send + Sync>>,
+}
+
+impl<T> OutboxRelayUseCase<T>
+where
+    T: OutboxRepository + Send + Sync,
+{
+    pub fn new(outbox_repository: Arc<T>) -> Self {
+        Self {
+            outbox_repository,
+            quest_event_handlers: Vec::new(),
+        }
+    }
+
+    pub fn with_quest_event_handler(
+        mut self,
+        quest_event_handler: Arc<dyn QuestEventHandler + Send + Sync>,
+    ) -> Self {
+        self.quest_event_handlers.push(quest_event_handler);
+        self
+    }
+
+    pub async fn relay(&self, now: NaiveDateTime) -> Result<Vec<i32>> {
+        let pending_events = self
+            .outbox_repository
+            .pending_events(
+                now,
+                now + Duration::seconds(OUTBOX_CLAIM_SECONDS),
+                OUTBOX_RELAY_BATCH_SIZE,
+            )
+            .await?;
+
+        let mut processed_ids = Vec::new();
+        for pending_event in pending_events.iter() {
+            match self.relay_event(pending_event, now).await {
+                Ok(true) => processed_ids.push(pending_event.id),
+                Ok(false) => {}
+                Err(e) => error!("Failed to relay outbox event {}: {}", pending_event.id, e),
+            }
+        }
+
+        Ok(processed_ids)
+    }
+
+    async fn relay_event(
+        &self,
+        pending_event: &OutboxEventEntity,
+        now: NaiveDateTime,
+    ) -> Result<bool> {
+        let outbox_event = match pending_event.to_model() {
+            Ok(outbox_event) => outbox_event,
+            Err(e) => {
+                self.outbox_repository
+                    .mark_dead_lettered(pending_event.id, e.to_string())
+                    .await?;
+                return Ok(false);
+            }
+        };
+
+        let handled = self
+            .outbox_repository
+            .handlers_by_outbox_event_id(outbox_event.id)
+            .await?;
+
+        let mut failures = Vec::new();
+        for quest_event_handler in self.quest_event_handlers.iter() {
+            let name = quest_event_handler.name();
+
+            if handled.iter().any(|handler| handler == name) {
+                continue;
+            }
+
+            let result = match quest_event_handler.handle(&outbox_event).await {
+                Ok(_) => {
+                    self.outbox_repository
+                        .mark_handled(outbox_event.id, name.to_string())
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+
+            if let Err(e) = result {
+                failures.push(format!("{}: {}", name, e));
+            }
+        }
+
+        if !failures.is_empty() {
+            self.retry_or_dead_letter(pending_event, failures.join("; "), now)
+                .await?;
+            return Ok(false);
+        }
+
+        self.outbox_repository
+            .mark_processed(outbox_event.id, now)
+            .await?;
+
+        Ok(true)
+    }
+
+    async fn retry_or_dead_letter(
+        &self,
+        pending_event: &OutboxEventEntity,
+        last_error: String,
+        now: NaiveDateTime,
+    ) -> Result<()> {
+        let attempts = pending_event.attempts + 1;
+
+        if attempts >= MAX_OUTBOX_ATTEMPTS {
+            return self
+                .outbox_repository
+                .mark_dead_lettered(pending_event.id, last_error)
+                .await;
+        }
+
+        self.outbox_repository
+            .mark_failed(
+                pending_event.id,
+                last_error,
+                now + outbox_retry_backoff(attempts),
+            )
+            .await
+    }
+}
@@ -0,0 +1,239 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{Duration, NaiveDateTime, TimeZone, Utc};
+
+    use crate::{
+        application::use_cases::outbox_relay::{OutboxRelayUseCase, MAX_OUTBOX_ATTEMPTS},
+        domain::{
+            entities::outbox_events::OutboxEventEntity,
+            repositories::{
+                outbox::MockOutboxRepository, quest_event_handler::MockQuestEventHandler,
+            },
+            value_objects::{
+                outbox_event_model::OUTBOX_RETRY_BASE_SECONDS,
+                outbox_event_statuses::OutboxEventStatuses,
+                quest_event_model::{QuestEventKinds, QuestEventModel},
+                quest_statuses::QuestStatuses,
+            },
+        },
+    };
+
+    fn relayed_at() -> NaiveDateTime {
+        Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0)
+            .unwrap()
+            .naive_utc()
+    }
+
+    fn outbox_event(id: i32, attempts: i32) -> OutboxEventEntity {
+        let event = QuestEventModel::status_changed(1, QuestStatuses::InJourney, vec![2]);
+
+        OutboxEventEntity {
+            id,
+            idempotency_key: "0123456789abcdef0123456789abcdef".to_string(),
+            kind: QuestEventKinds::StatusChanged.to_string(),
+            quest_id: 1,
+            payload: serde_json::json!(event),
+            attempts,
+            last_error: None,
+            processed_at: None,
+            created_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            status: OutboxEventStatuses::Pending.to_string(),
+            next_attempt_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    fn outbox_repo(
+        outbox_events: Vec<OutboxEventEntity>,
+        handled: Vec<String>,
+    ) -> MockOutboxRepository {
+        let mut mock_outbox_repo = MockOutboxRepository::new();
+
+        mock_outbox_repo
+            .expect_pending_events()
+            .withf(|now, claimed_until, _| *now == relayed_at() && claimed_until > now)
+            .returning(move |_, _, _| {
+                let outbox_events = outbox_events.clone();
+                Box::pin(async move { Ok(outbox_events) })
+            });
+
+        mock_outbox_repo
+            .expect_handlers_by_outbox_event_id()
+            .returning(move |_| {
+                let handled = handled.clone();
+                Box::pin(async move { Ok(handled) })
+            });
+
+        mock_outbox_repo
+    }
+
+    fn quest_event_handler(name: &'static str, fails: bool) -> MockQuestEventHandler {
+        let mut mock_quest_event_handler = MockQuestEventHandler::new();
+
+        mock_quest_event_handler.expect_name().return_const(name);
+        mock_quest_event_handler
+            .expect_handle()
+            .withf(|outbox_event| {
+                outbox_event.idempotency_key == "0123456789abcdef0123456789abcdef"
+                    && outbox_event.event.kind == QuestEventKinds::StatusChanged
+            })
+            .times(1)
+            .returning(move |_| {
+                Box::pin(async move {
+                    if fails {
+                        Err(anyhow::anyhow!("Connection refused"))
+                    } else {
+                        Ok(())
+                    }
+                })
+            });
+
+        mock_quest_event_handler
+    }
+
+    #[tokio::test]
+    async fn test_relay_dispatches_to_every_handler_and_marks_processed() {
+        let mut mock_outbox_repo = outbox_repo(vec![outbox_event(1, 0)], Vec::new());
+
+        mock_outbox_repo
+            .expect_mark_handled()
+            .times(2)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        mock_outbox_repo
+            .expect_mark_processed()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        mock_outbox_repo.expect_mark_failed().never();
+
+        let use_case = OutboxRelayUseCase::new(Arc::new(mock_outbox_repo))
+            .with_quest_event_handler(Arc::new(quest_event_handler("notifications", false)))
+            .with_quest_event_handler(Arc::new(quest_event_handler("webhooks", false)));
+
+        let result = use_case.relay(relayed_at()).await;
+        assert_eq!(result.unwrap(), vec![1]);
+    }
+
+    #[tokio::test]
+    async fn test_relay_skips_handled_handlers_and_backs_off_failed_events() {
+        let mut mock_outbox_repo =
+            outbox_repo(vec![outbox_event(1, 0)], vec!["notifications".to_string()]);
+
+        let mut mock_notifications_handler = MockQuestEventHandler::new();
+        mock_notifications_handler
+            .expect_name()
+            .return_const("notifications");
+        mock_notifications_handler.expect_handle().never();
+
+        mock_outbox_repo.expect_mark_handled().never();
+        mock_outbox_repo.expect_mark_processed().never();
+        mock_outbox_repo.expect_mark_dead_lettered().never();
+        mock_outbox_repo
+            .expect_mark_failed()
+            .withf(|outbox_event_id, last_error, next_attempt_at| {
+                *outbox_event_id == 1
+                    && last_error == "webhooks: Connection refused"
+                    && *next_attempt_at
+                        == relayed_at() + Duration::seconds(OUTBOX_RETRY_BASE_SECONDS)
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let use_case = OutboxRelayUseCase::new(Arc::new(mock_outbox_repo))
+            .with_quest_event_handler(Arc::new(mock_notifications_handler))
+            .with_quest_event_handler(Arc::new(quest_event_handler("webhooks", true)));
+
+        let result = use_case.relay(relayed_at()).await;
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_relay_dead_letters_events_out_of_attempts() {
+        let mut mock_outbox_repo =
+            outbox_repo(vec![outbox_event(1, MAX_OUTBOX_ATTEMPTS - 1)], Vec::new());
+
+        mock_outbox_repo.expect_mark_failed().never();
+        mock_outbox_repo
+            .expect_mark_dead_lettered()
+            .withf(|outbox_event_id, last_error| {
+                *outbox_event_id == 1 && last_error == "webhooks: Connection refused"
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let use_case = OutboxRelayUseCase::new(Arc::new(mock_outbox_repo))
+            .with_quest_event_handler(Arc::new(quest_event_handler("webhooks", true)));
+
+        let result = use_case.relay(relayed_at()).await;
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_relay_dead_letters_undecodable_events() {
+        let mut undecodable_event = outbox_event(1, 0);
+        undecodable_event.payload = serde_json::json!({ "unexpected": true });
+
+        let mut mock_outbox_repo = outbox_repo(vec![undecodable_event], Vec::new());
+
+        mock_outbox_repo.expect_mark_failed().never();
+        mock_outbox_repo
+            .expect_mark_dead_lettered()
+            .withf(|outbox_event_id, _| *outbox_event_id == 1)
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let mut mock_webhooks_handler = MockQuestEventHandler::new();
+        mock_webhooks_handler.expect_name().return_const("webhooks");
+        mock_webhooks_handler.expect_handle().never();
+
+        let use_case = OutboxRelayUseCase::new(Arc::new(mock_outbox_repo))
+            .with_quest_event_handler(Arc::new(mock_webhooks_handler));
+
+        let result = use_case.relay(relayed_at()).await;
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_relay_keeps_going_when_one_event_errors() {
+        let mut mock_outbox_repo =
+            outbox_repo(vec![outbox_event(1, 0), outbox_event(2, 0)], Vec::new());
+
+        mock_outbox_repo
+            .expect_mark_handled()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        mock_outbox_repo
+            .expect_mark_processed()
+            .times(2)
+            .returning(|outbox_event_id, _| {
+                Box::pin(async move {
+                    if outbox_event_id == 1 {
+                        Err(anyhow::anyhow!("Connection reset"))
+                    } else {
+                        Ok(())
+                    }
+                })
+            });
+
+        let mut mock_webhooks_handler = MockQuestEventHandler::new();
+        mock_webhooks_handler.expect_name().return_const("webhooks");
+        mock_webhooks_handler
+            .expect_handle()
+            .times(2)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case = OutboxRelayUseCase::new(Arc::new(mock_outbox_repo))
+            .with_quest_event_handler(Arc::new(mock_webhooks_handler));
+
+        let result = use_case.relay(relayed_at()).await;
+        assert_eq!(result.unwrap(), vec![2]);
+    }
+}
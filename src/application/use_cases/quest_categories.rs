@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::domain::{
+    entities::quest_categories::QuestCategoryEntity,
+    repositories::quest_categories::QuestCategoriesRepository,
+    value_objects::quest_category_model::{
+        AddQuestCategoryModel, EditQuestCategoryModel, QuestCategoryModel,
+    },
+};
+
+pub struct QuestCategoriesUseCase<T>
+where
+    T: QuestCategoriesRepository + Send + Sync,
+{
+    quest_categories_repository: Arc<T>,
+}
+
+impl<T> QuestCategoriesUseCase<T>
+where
+    T: QuestCategoriesRepository + Send + Sync,
+{
+    pub fn new(quest_categories_repository: Arc<T>) -> Self {
+        Self {
+            quest_categories_repository,
+        }
+    }
+
+    pub async fn add(
+        &self,
+        guild_commander_id: i32,
+        add_quest_category_model: AddQuestCategoryModel,
+    ) -> Result<i32> {
+        Self::validate_name(&add_quest_category_model.name)?;
+
+        let result = self
+            .quest_categories_repository
+            .add(add_quest_category_model.to_entity(guild_commander_id))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn edit(
+        &self,
+        category_id: i32,
+        guild_commander_id: i32,
+        edit_quest_category_model: EditQuestCategoryModel,
+    ) -> Result<i32> {
+        Self::validate_name(&edit_quest_category_model.name)?;
+
+        self.owned_category(category_id, guild_commander_id).await?;
+
+        let result = self
+            .quest_categories_repository
+            .edit(category_id, edit_quest_category_model.to_entity())
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove(&self, category_id: i32, guild_commander_id: i32) -> Result<()> {
+        self.owned_category(category_id, guild_commander_id).await?;
+
+        self.quest_categories_repository.remove(category_id).await?;
+
+        Ok(())
+    }
+
+    pub async fn list(&self) -> Result<Vec<QuestCategoryModel>> {
+        let results = self.quest_categories_repository.list().await?;
+
+        Ok(results.iter().map(|category| category.to_model()).collect())
+    }
+
+    async fn owned_category(
+        &self,
+        category_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<QuestCategoryEntity> {
+        let category = self
+            .quest_categories_repository
+            .view_details(category_id)
+            .await?;
+
+        if category.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest category is not owned by this guild commander"
+            ));
+        }
+
+        Ok(category)
+    }
+
+    fn validate_name(name: &str) -> Result<()> {
+        if name.trim().is_empty() {
+            return Err(anyhow::anyhow!("Quest category name must not be empty"));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::use_cases::quest_categories::QuestCategoriesUseCase,
+        domain::{
+            entities::quest_categories::QuestCategoryEntity,
+            repositories::quest_categories::MockQuestCategoriesRepository,
+            value_objects::quest_category_model::{AddQuestCategoryModel, EditQuestCategoryModel},
+        },
+    };
+
+    #[tokio::test]
+    async fn test_edit_fails_when_category_is_not_owned() {
+        let mut mock_quest_categories_repo = MockQuestCategoriesRepository::new();
+
+        mock_quest_categories_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestCategoryEntity {
+                        id: 1,
+                        name: "test".to_string(),
+                        guild_commander_id: 2,
+                        created_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+
+                        updated_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    })
+                })
+            });
+
+        mock_quest_categories_repo.expect_edit().never();
+
+        let use_case = QuestCategoriesUseCase::new(Arc::new(mock_quest_categories_repo));
+
+        let result = use_case
+            .edit(
+                1,
+                1,
+                EditQuestCategoryModel {
+                    name: "renamed".to_string(),
+                },
+            )
+            .await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quest category is not owned by this guild commander"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_fails_with_blank_name() {
+        let mut mock_quest_categories_repo = MockQuestCategoriesRepository::new();
+
+        mock_quest_categories_repo.expect_add().never();
+
+        let use_case = QuestCategoriesUseCase::new(Arc::new(mock_quest_categories_repo));
+
+        let result = use_case
+            .add(
+                1,
+                AddQuestCategoryModel {
+                    name: "   ".to_string(),
+                },
+            )
+            .await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quest category name must not be empty"
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::Result;
+
+use crate::domain::{
+    entities::quest_comments::QuestCommentEntity,
+    repositories::{
+        crew_switchboard::CrewSwitchboardRepository, quest_comments::QuestCommentsRepository,
+        quest_viewing::QuestViewingRepository,
+    },
+    value_objects::{
+        authenticated_roles::{AuthenticatedIdentity, AuthenticatedRoles},
+        crew_membership_statuses::CrewMembershipStatuses,
+        quest_adventurer_junction::QuestAdventurerJunction,
+        quest_comment_model::{
+            AddQuestCommentModel, EditQuestCommentModel, QuestCommentFilter, QuestCommentPageModel,
+            QuestCommentThreadModel,
+        },
+    },
+};
+
+pub const MAX_COMMENT_LENGTH: usize = 2000;
+
+pub struct QuestCommentsUseCase<T1, T2, T3>
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwitchboardRepository + Send + Sync,
+{
+    quest_comments_repository: Arc<T1>,
+    quest_viewing_repository: Arc<T2>,
+    crew_switchboard_repository: Arc<T3>,
+}
+
+impl<T1, T2, T3> QuestCommentsUseCase<T1, T2, T3>
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwitchboardRepository + Send + Sync,
+{
+    pub fn new(
+        quest_comments_repository: Arc<T1>,
+        quest_viewing_repository: Arc<T2>,
+        crew_switchboard_repository: Arc<T3>,
+    ) -> Self {
+        Self {
+            quest_comments_repository,
+            quest_viewing_repository,
+            crew_switchboard_repository,
+        }
+    }
+
+    pub async fn add(
+        &self,
+        quest_id: i32,
+        author: AuthenticatedIdentity,
+        add_quest_comment_model: AddQuestCommentModel,
+    ) -> Result<i32> {
+        Self::validate_body(&add_quest_comment_model.body)?;
+
+        self.ensure_participant(quest_id, author).await?;
+
+        let parent_id = match add_quest_comment_model.parent_id {
+            Some(parent_id) => {
+                let parent = self.comment_of_quest(quest_id, parent_id).await?;
+
+                Some(parent.parent_id.unwrap_or(parent.id))
+            }
+            None => None,
+        };
+
+        let result = self
+            .quest_comments_repository
+            .add(add_quest_comment_model.to_entity(quest_id, parent_id, author))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn edit(
+        &self,
+        quest_id: i32,
+        comment_id: i32,
+        author: AuthenticatedIdentity,
+        edit_quest_comment_model: EditQuestCommentModel,
+    ) -> Result<i32> {
+        Self::validate_body(&edit_quest_comment_model.body)?;
+
+        self.owned_comment(quest_id, comment_id, author).await?;
+
+        let result = self
+            .quest_comments_repository
+            .edit(comment_id, edit_quest_comment_model.to_entity())
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove(
+        &self,
+        quest_id: i32,
+        comment_id: i32,
+        author: AuthenticatedIdentity,
+    ) -> Result<()> {
+        self.owned_comment(quest_id, comment_id, author).await?;
+
+        self.quest_comments_repository.remove(comment_id).await?;
+
+        Ok(())
+    }
+
+    pub async fn list(
+        &self,
+        quest_id: i32,
+        filter: &QuestCommentFilter,
+    ) -> Result<QuestCommentPageModel> {
+        let pagination = filter.pagination()?;
+
+        self.quest_viewing_repository.view_details(quest_id).await?;
+
+        let total = self
+            .quest_comments_repository
+            .threads_counting_by_quest_id(quest_id)
+            .await?;
+
+        let threads = self
+            .quest_comments_repository
+            .threads_by_quest_id(quest_id, pagination.per_page, pagination.offset)
+            .await?;
+
+        let replies = if threads.is_empty() {
+            Vec::new()
+        } else {
+            self.quest_comments_repository
+                .replies_by_parent_ids(threads.iter().map(|thread| thread.id).collect())
+                .await?
+        };
+
+        let mut replies_by_parent_id = HashMap::new();
+        for reply in replies.iter() {
+            if let Some(parent_id) = reply.parent_id {
+                replies_by_parent_id
+                    .entry(parent_id)
+                    .or_insert_with(Vec::new)
+                    .push(reply.to_model());
+            }
+        }
+
+        let threads = threads
+            .iter()
+            .map(|thread| QuestCommentThreadModel {
+                comment: thread.to_model(),
+                replies: replies_by_parent_id.remove(&thread.id).unwrap_or_default(),
+            })
+            .collect();
+
+        Ok(QuestCommentPageModel {
+            page: pagination.page,
+            per_page: pagination.per_page,
+            total,
+            threads,
+        })
+    }
+
+    async fn ensure_participant(&self, quest_id: i32, author: AuthenticatedIdentity) -> Result<()> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        let participant = match author.role {
+            AuthenticatedRoles::GuildCommander => quest.guild_commander_id == author.id,
+            AuthenticatedRoles::Adventurer => {
+                self.crew_switchboard_repository
+                    .membership_status(QuestAdventurerJunction {
+                        quest_id,
+                        adventurer_id: author.id,
+                    })
+                    .await?
+                    == Some(CrewMembershipStatuses::Joined)
+            }
+        };
+
+        if !participant {
+            return Err(anyhow::anyhow!(
+                "Only the quest's guild commander and crew can comment on this quest"
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn comment_of_quest(&self, quest_id: i32, comment_id: i32) -> Result<QuestCommentEntity> {
+        let comment = self
+            .quest_comments_repository
+            .view_details(comment_id)
+            .await?;
+
+        if comment.quest_id != quest_id {
+            return Err(anyhow::anyhow!("Quest comment not found"));
+        }
+
+        Ok(comment)
+    }
+
+    async fn owned_comment(
+        &self,
+        quest_id: i32,
+        comment_id: i32,
+        author: AuthenticatedIdentity,
+    ) -> Result<QuestCommentEntity> {
+        let comment = self.comment_of_quest(quest_id, comment_id).await?;
+
+        if comment.author_role != author.role.to_string() || comment.author_id != author.id {
+            return Err(anyhow::anyhow!("Quest comment is not owned by this author"));
+        }
+
+        Ok(comment)
+    }
+
+    fn validate_body(body: &str) -> Result<()> {
+        if body.trim().is_empty() {
+            return Err(anyhow::anyhow!("Quest comment must not be empty"));
+        }
+
+        if body.trim().chars().count() > MAX_COMMENT_LENGTH {
+            return Err(anyhow::anyhow!(
+                "Quest comment must not be longer than {} characters",
+                MAX_COMMENT_LENGTH
+            ));
+        }
+
+        Ok(())
+    }
+}
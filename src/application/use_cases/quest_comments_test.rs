@@ -0,0 +1,258 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::use_cases::quest_comments::QuestCommentsUseCase,
+        domain::{
+            entities::{quest_comments::QuestCommentEntity, quests::QuestEntity},
+            repositories::{
+                crew_switchboard::MockCrewSwitchboardRepository,
+                quest_comments::MockQuestCommentsRepository,
+                quest_viewing::MockQuestViewingRepository,
+            },
+            value_objects::{
+                authenticated_roles::{AuthenticatedIdentity, AuthenticatedRoles},
+                crew_membership_statuses::CrewMembershipStatuses,
+                quest_adventurer_junction::DEFAULT_CREW_SIZE,
+                quest_comment_model::{
+                    AddQuestCommentModel, QuestCommentFilter, DELETED_COMMENT_BODY,
+                },
+                quest_difficulties::QuestDifficulties,
+                quest_statuses::QuestStatuses,
+            },
+        },
+    };
+
+    fn comment(id: i32, parent_id: Option<i32>, deleted: bool) -> QuestCommentEntity {
+        QuestCommentEntity {
+            id,
+            quest_id: 1,
+            parent_id,
+            author_role: AuthenticatedRoles::Adventurer.to_string(),
+            author_id: 1,
+            body: "test".to_string(),
+            created_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            deleted_at: deleted.then(|| {
+                Utc.with_ymd_and_hms(1970, 1, 2, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc()
+            }),
+        }
+    }
+
+    fn quest_viewing_repo() -> MockQuestViewingRepository {
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestEntity {
+                        id: 1,
+                        name: "test".to_string(),
+                        description: Some("test".to_string()),
+                        status: QuestStatuses::Open.to_string(),
+                        guild_commander_id: 1,
+                        approval_required: false,
+                        starts_at: None,
+                        due_at: None,
+                        join_deadline: None,
+                        reward_gold: 0,
+                        reward_xp: 0,
+                        difficulty: QuestDifficulties::Normal.to_string(),
+                        category_id: None,
+                        max_adventurers: DEFAULT_CREW_SIZE,
+                        series_id: None,
+                        objectives_required: false,
+                        version: 1,
+                        created_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+
+                        updated_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    })
+                })
+            });
+
+        mock_quest_viewing_repo
+    }
+
+    #[tokio::test]
+    async fn test_add_fails_when_adventurer_is_not_in_crew() {
+        let mut mock_quest_comments_repo = MockQuestCommentsRepository::new();
+        let mut mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+
+        mock_crew_switchboard_repo
+            .expect_membership_status()
+            .returning(|_| Box::pin(async { Ok(Some(CrewMembershipStatuses::Pending)) }));
+
+        mock_quest_comments_repo.expect_add().never();
+
+        let use_case = QuestCommentsUseCase::new(
+            Arc::new(mock_quest_comments_repo),
+            Arc::new(quest_viewing_repo()),
+            Arc::new(mock_crew_switchboard_repo),
+        );
+
+        let result = use_case
+            .add(
+                1,
+                AuthenticatedIdentity {
+                    role: AuthenticatedRoles::Adventurer,
+                    id: 1,
+                },
+                AddQuestCommentModel {
+                    body: "Anyone bringing torches?".to_string(),
+                    parent_id: None,
+                },
+            )
+            .await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Only the quest's guild commander and crew can comment on this quest"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_attaches_nested_reply_to_thread_root() {
+        let mut mock_quest_comments_repo = MockQuestCommentsRepository::new();
+        let mock_crew_switchboard_repo = MockCrewSwitchboardRepository::new();
+
+        mock_quest_comments_repo
+            .expect_view_details()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(QuestCommentEntity {
+                        id: 2,
+                        quest_id: 1,
+                        parent_id: Some(1),
+                        author_role: AuthenticatedRoles::Adventurer.to_string(),
+                        author_id: 1,
+                        body: "test".to_string(),
+                        created_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+
+                        updated_at: Utc
+                            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                        deleted_at: None,
+                    })
+                })
+            });
+
+        mock_quest_comments_repo
+            .expect_add()
+            .withf(|add_quest_comment_entity| {
+                add_quest_comment_entity.parent_id == Some(1)
+                    && add_quest_comment_entity.author_role
+                        == AuthenticatedRoles::GuildCommander.to_string()
+                    && add_quest_comment_entity.body == "Yes"
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(3) }));
+
+        let use_case = QuestCommentsUseCase::new(
+            Arc::new(mock_quest_comments_repo),
+            Arc::new(quest_viewing_repo()),
+            Arc::new(mock_crew_switchboard_repo),
+        );
+
+        let result = use_case
+            .add(
+                1,
+                AuthenticatedIdentity {
+                    role: AuthenticatedRoles::GuildCommander,
+                    id: 1,
+                },
+                AddQuestCommentModel {
+                    body: " Yes ".to_string(),
+                    parent_id: Some(2),
+                },
+            )
+            .await;
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_list_keeps_replies_under_deleted_thread_root() {
+        let mut mock_quest_comments_repo = MockQuestCommentsRepository::new();
+
+        mock_quest_comments_repo
+            .expect_threads_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(1) }));
+
+        mock_quest_comments_repo
+            .expect_threads_by_quest_id()
+            .returning(|_, _, _| Box::pin(async { Ok(vec![comment(1, None, true)]) }));
+
+        mock_quest_comments_repo
+            .expect_replies_by_parent_ids()
+            .returning(|_| Box::pin(async { Ok(vec![comment(2, Some(1), false)]) }));
+
+        let use_case = QuestCommentsUseCase::new(
+            Arc::new(mock_quest_comments_repo),
+            Arc::new(quest_viewing_repo()),
+            Arc::new(MockCrewSwitchboardRepository::new()),
+        );
+
+        let result = use_case
+            .list(1, &QuestCommentFilter::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.threads.len(), 1);
+        assert!(result.threads[0].comment.deleted);
+        assert_eq!(result.threads[0].comment.body, DELETED_COMMENT_BODY);
+        assert_eq!(result.threads[0].replies.len(), 1);
+        assert_eq!(result.threads[0].replies[0].body, "test");
+    }
+
+    #[tokio::test]
+    async fn test_list_rejects_out_of_range_pages() {
+        let mut mock_quest_comments_repo = MockQuestCommentsRepository::new();
+
+        mock_quest_comments_repo
+            .expect_threads_by_quest_id()
+            .never();
+
+        let use_case = QuestCommentsUseCase::new(
+            Arc::new(mock_quest_comments_repo),
+            Arc::new(quest_viewing_repo()),
+            Arc::new(MockCrewSwitchboardRepository::new()),
+        );
+
+        for (page, message) in [
+            (0, "Page must be at least 1"),
+            (i64::MAX, "Page is out of range"),
+        ] {
+            let result = use_case
+                .list(
+                    1,
+                    &QuestCommentFilter {
+                        page: Some(page),
+                        per_page: Some(50),
+                    },
+                )
+                .await;
+            assert_eq!(result.unwrap_err().to_string(), message);
+        }
+    }
+}
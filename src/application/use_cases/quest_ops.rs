@@ -1,12 +1,40 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use chrono::NaiveDateTime;
+use validator::Validate;
 
 use crate::domain::{
+    entities::{
+        quest_dependencies::QuestDependencyEntity,
+        quest_objectives::QuestObjectiveEntity,
+        quests::{AddQuestEntity, ImportQuestEntity, QuestEntity},
+    },
     repositories::{quest_ops::QuestOpsRepository, quest_viewing::QuestViewingRepository},
-    value_objects::quest_model::{AddQuestModel, EditQuestModel},
+    value_objects::{
+        api_response_model::ValidationErrorModel,
+        input_sanitizer::Sanitize,
+        invalid_quest_model::InvalidQuestModel,
+        labels::normalize_labels,
+        quest_adventurer_junction::MAX_CREW_SIZE,
+        quest_bulk_model::{BulkAddQuestsModel, BulkPlan, BulkRemoveQuestsModel, BulkResultModel},
+        quest_model::{AddQuestModel, EditQuestModel},
+        quest_objective_model::{AddQuestObjectiveModel, EditQuestObjectiveModel},
+        quest_requirements_model::QuestRequirementsModel,
+        quest_reward_model::{QuestRewardModel, MAX_QUEST_REWARD},
+        quest_statuses::QuestStatuses,
+        quest_transfer_model::{
+            QuestExportModel, QuestImportModel, QuestImportReportModel, QuestImportRowModel,
+        },
+        quest_version_model::QuestVersionConflictModel,
+    },
 };
 
+pub const MAX_TAGS_PER_QUEST: usize = 10;
+pub const MAX_TAG_LENGTH: usize = 255;
+pub const MAX_OBJECTIVES_PER_QUEST: usize = 50;
+pub const MAX_OBJECTIVE_TITLE_LENGTH: usize = 255;
+
 pub struct QuestOpsUseCase<T1, T2>
 where
     T1: QuestOpsRepository + Send + Sync,
@@ -33,54 +61,667 @@ where
         guild_commander_id: i32,
         add_quest_model: AddQuestModel,
     ) -> Result<i32> {
-        let add_quest_entity = add_quest_model.to_entity(guild_commander_id);
-        let result = self.quest_ops_repository.add(add_quest_entity).await?;
+        let (add_quest_entity, tags) = Self::prepare_add(guild_commander_id, &add_quest_model)?;
+
+        let result = self
+            .quest_ops_repository
+            .add(add_quest_entity, tags)
+            .await?;
 
         Ok(result)
     }
 
+    pub async fn add_many(
+        &self,
+        guild_commander_id: i32,
+        bulk_add_quests_model: BulkAddQuestsModel,
+    ) -> Result<BulkResultModel> {
+        let prepared = bulk_add_quests_model
+            .quests
+            .iter()
+            .map(|add_quest_model| Self::prepare_add(guild_commander_id, add_quest_model))
+            .collect::<Vec<_>>();
+
+        let mut bulk_plan = BulkPlan::new(
+            prepared,
+            bulk_add_quests_model.all_or_nothing.unwrap_or(false),
+        );
+
+        let executed = match bulk_plan.take_executable() {
+            Some(quests) => {
+                self.quest_ops_repository
+                    .add_many(quests, bulk_plan.all_or_nothing())
+                    .await?
+            }
+            None => Vec::new(),
+        };
+
+        let requested_quest_ids = vec![None; bulk_add_quests_model.quests.len()];
+
+        Ok(bulk_plan.finish(requested_quest_ids, executed))
+    }
+
+    pub async fn export_quests(&self, guild_commander_id: i32) -> Result<Vec<QuestExportModel>> {
+        let quest_export_entities = self
+            .quest_viewing_repository
+            .quest_exports_by_guild_commander_id(guild_commander_id)
+            .await?;
+
+        let quest_export_models = quest_export_entities
+            .iter()
+            .map(|quest_export_entity| quest_export_entity.to_model())
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(quest_export_models)
+    }
+
+    pub async fn import_quests(
+        &self,
+        guild_commander_id: i32,
+        rows: Vec<Result<QuestImportModel>>,
+        dry_run: bool,
+    ) -> Result<QuestImportReportModel> {
+        let prepared = rows
+            .into_iter()
+            .map(|row| Self::prepare_import(guild_commander_id, row))
+            .collect::<Vec<_>>();
+
+        let mut import_rows = prepared
+            .iter()
+            .enumerate()
+            .map(|(index, prepared)| QuestImportRowModel {
+                row: index + 1,
+                quest_id: None,
+                errors: prepared.as_ref().err().cloned().unwrap_or_default(),
+            })
+            .collect::<Vec<_>>();
+
+        let rejected = import_rows.iter().any(|row| !row.errors.is_empty());
+        if dry_run || rejected {
+            return Ok(QuestImportReportModel::new(dry_run, false, import_rows));
+        }
+
+        let quests = prepared.into_iter().flatten().collect::<Vec<_>>();
+        let results = self.quest_ops_repository.import_many(quests).await?;
+
+        let committed = results.iter().all(Result::is_ok);
+        for (import_row, result) in import_rows.iter_mut().zip(results) {
+            match result {
+                Ok(quest_id) if committed => import_row.quest_id = Some(quest_id),
+                Ok(_) => {}
+                Err(e) => import_row.errors.push(e.to_string()),
+            }
+        }
+
+        Ok(QuestImportReportModel::new(false, committed, import_rows))
+    }
+
     pub async fn edit(
         &self,
         quest_id: i32,
         guild_commander_id: i32,
+        expected_version: i32,
         edit_quest_model: EditQuestModel,
     ) -> Result<i32> {
+        let quest = self.ensure_owned(quest_id, guild_commander_id).await?;
+
+        if quest.version != expected_version {
+            return Err(
+                QuestVersionConflictModel::new(quest_id, expected_version, quest.version).into(),
+            );
+        }
+
         let adventurers_count = self
             .quest_viewing_repository
             .adventurers_counting_by_quest_id(quest_id)
             .await?;
 
         if adventurers_count > 0 {
+            return Err(
+                InvalidQuestModel::new("Quest has been taken by adventurers for now").into(),
+            );
+        }
+
+        Self::validate_schedule(
+            edit_quest_model.starts_at.or(quest.starts_at),
+            edit_quest_model.due_at.or(quest.due_at),
+            edit_quest_model.join_deadline.or(quest.join_deadline),
+        )?;
+        validate_reward(edit_quest_model.reward.as_ref())?;
+        validate_crew_size(edit_quest_model.max_adventurers)?;
+        let tags = edit_quest_model
+            .tags
+            .clone()
+            .map(normalize_tags)
+            .transpose()?;
+
+        let edit_quest_entity = edit_quest_model.to_entity();
+        let result = self
+            .quest_ops_repository
+            .edit(
+                quest_id,
+                guild_commander_id,
+                expected_version,
+                edit_quest_entity,
+                tags,
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn clone_quest(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+        let result = self
+            .quest_ops_repository
+            .clone_quest(quest_id, guild_commander_id)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn set_requirements(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        quest_requirements_model: QuestRequirementsModel,
+    ) -> Result<i32> {
+        self.ensure_owned(quest_id, guild_commander_id).await?;
+        let quest_requirements_model = Self::prepare_requirements(quest_requirements_model)?;
+
+        let result = self
+            .quest_ops_repository
+            .upsert_requirements(quest_requirements_model.to_entity(quest_id))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove_requirements(&self, quest_id: i32, guild_commander_id: i32) -> Result<()> {
+        self.ensure_owned(quest_id, guild_commander_id).await?;
+
+        self.quest_ops_repository
+            .remove_requirements(quest_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn add_objective(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        add_quest_objective_model: AddQuestObjectiveModel,
+    ) -> Result<i32> {
+        let quest = self.ensure_owned(quest_id, guild_commander_id).await?;
+        Self::ensure_objectives_editable(&quest)?;
+        Self::validate_objective_title(&add_quest_objective_model.title)?;
+
+        let objectives = self
+            .quest_viewing_repository
+            .objectives_by_quest_id(quest_id)
+            .await?;
+
+        if objectives.len() >= MAX_OBJECTIVES_PER_QUEST {
             return Err(anyhow::anyhow!(
-                "Quest has been taken by adventurers for now"
+                "Quest must not have more than {} objectives",
+                MAX_OBJECTIVES_PER_QUEST
             ));
         }
 
-        let edit_quest_entity = edit_quest_model.to_entity(guild_commander_id);
+        if let Some(position) = add_quest_objective_model.position {
+            Self::validate_objective_position(position, &objectives, None)?;
+        }
+
+        let position = add_quest_objective_model.position.unwrap_or_else(|| {
+            objectives
+                .iter()
+                .map(|objective| objective.position)
+                .max()
+                .map_or(1, |position| position + 1)
+        });
+
         let result = self
             .quest_ops_repository
-            .edit(quest_id, edit_quest_entity)
+            .add_objective(add_quest_objective_model.to_entity(quest_id, position))
             .await?;
 
         Ok(result)
     }
 
+    pub async fn edit_objective(
+        &self,
+        quest_id: i32,
+        objective_id: i32,
+        guild_commander_id: i32,
+        edit_quest_objective_model: EditQuestObjectiveModel,
+    ) -> Result<i32> {
+        let quest = self.ensure_owned(quest_id, guild_commander_id).await?;
+        Self::ensure_objectives_editable(&quest)?;
+
+        if let Some(title) = edit_quest_objective_model.title.as_deref() {
+            Self::validate_objective_title(title)?;
+        }
+
+        let objectives = self.ensure_objective_exists(quest_id, objective_id).await?;
+
+        if let Some(position) = edit_quest_objective_model.position {
+            Self::validate_objective_position(position, &objectives, Some(objective_id))?;
+        }
+
+        let result = self
+            .quest_ops_repository
+            .edit_objective(objective_id, edit_quest_objective_model.to_entity())
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove_objective(
+        &self,
+        quest_id: i32,
+        objective_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<()> {
+        let quest = self.ensure_owned(quest_id, guild_commander_id).await?;
+        Self::ensure_objectives_editable(&quest)?;
+
+        self.ensure_objective_exists(quest_id, objective_id).await?;
+
+        self.quest_ops_repository
+            .remove_objective(objective_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn add_dependency(
+        &self,
+        quest_id: i32,
+        prerequisite_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<()> {
+        self.ensure_owned(quest_id, guild_commander_id).await?;
+
+        if quest_id == prerequisite_id {
+            return Err(InvalidQuestModel::new("Quest must not depend on itself").into());
+        }
+
+        self.quest_ops_repository
+            .add_dependency(QuestDependencyEntity {
+                quest_id,
+                prerequisite_id,
+                created_at: chrono::Utc::now().naive_utc(),
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_dependency(
+        &self,
+        quest_id: i32,
+        prerequisite_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<()> {
+        self.ensure_owned(quest_id, guild_commander_id).await?;
+
+        self.quest_ops_repository
+            .remove_dependency(quest_id, prerequisite_id)
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn remove(&self, quest_id: i32, guild_commander_id: i32) -> Result<()> {
+        self.ensure_removable(quest_id, guild_commander_id).await?;
+
+        self.quest_ops_repository
+            .remove(quest_id, guild_commander_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_many(
+        &self,
+        guild_commander_id: i32,
+        bulk_remove_quests_model: BulkRemoveQuestsModel,
+    ) -> Result<BulkResultModel> {
+        let quest_ids = bulk_remove_quests_model.quest_ids;
+
+        let mut prepared = Vec::with_capacity(quest_ids.len());
+        for quest_id in quest_ids.iter() {
+            prepared.push(
+                self.ensure_removable(*quest_id, guild_commander_id)
+                    .await
+                    .map(|_| *quest_id),
+            );
+        }
+
+        let mut bulk_plan = BulkPlan::new(
+            prepared,
+            bulk_remove_quests_model.all_or_nothing.unwrap_or(false),
+        );
+
+        let executed = match bulk_plan.take_executable() {
+            Some(quest_ids) => {
+                self.quest_ops_repository
+                    .remove_many(quest_ids, guild_commander_id, bulk_plan.all_or_nothing())
+                    .await?
+            }
+            None => Vec::new(),
+        };
+
+        let requested_quest_ids = quest_ids.into_iter().map(Some).collect();
+
+        Ok(bulk_plan.finish(requested_quest_ids, executed))
+    }
+
+    async fn ensure_removable(&self, quest_id: i32, guild_commander_id: i32) -> Result<()> {
+        self.ensure_owned(quest_id, guild_commander_id).await?;
+
         let adventurers_count = self
             .quest_viewing_repository
             .adventurers_counting_by_quest_id(quest_id)
             .await?;
 
         if adventurers_count > 0 {
+            return Err(
+                InvalidQuestModel::new("Quest has been taken by adventurers for now").into(),
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn ensure_owned(&self, quest_id: i32, guild_commander_id: i32) -> Result<QuestEntity> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        if quest.guild_commander_id != guild_commander_id {
             return Err(anyhow::anyhow!(
-                "Quest has been taken by adventurers for now"
+                "Quest is not owned by this guild commander"
             ));
         }
 
-        self.quest_ops_repository
-            .remove(quest_id, guild_commander_id)
+        Ok(quest)
+    }
+
+    async fn ensure_objective_exists(
+        &self,
+        quest_id: i32,
+        objective_id: i32,
+    ) -> Result<Vec<QuestObjectiveEntity>> {
+        let objectives = self
+            .quest_viewing_repository
+            .objectives_by_quest_id(quest_id)
             .await?;
 
+        if !objectives
+            .iter()
+            .any(|objective| objective.id == objective_id)
+        {
+            return Err(anyhow::anyhow!("Quest objective not found"));
+        }
+
+        Ok(objectives)
+    }
+
+    fn ensure_objectives_editable(quest: &QuestEntity) -> Result<()> {
+        if quest.status == QuestStatuses::Completed.to_string() {
+            return Err(anyhow::anyhow!("Quest objectives can no longer be changed"));
+        }
+
         Ok(())
     }
+
+    fn prepare_add(
+        guild_commander_id: i32,
+        add_quest_model: &AddQuestModel,
+    ) -> Result<(AddQuestEntity, Vec<String>)> {
+        Self::validate_schedule(
+            add_quest_model.starts_at,
+            add_quest_model.due_at,
+            add_quest_model.join_deadline,
+        )?;
+        validate_reward(add_quest_model.reward.as_ref())?;
+        validate_crew_size(add_quest_model.max_adventurers)?;
+        let tags = normalize_tags(add_quest_model.tags.clone().unwrap_or_default())?;
+
+        Ok((add_quest_model.to_entity(guild_commander_id), tags))
+    }
+
+    fn prepare_import(
+        guild_commander_id: i32,
+        row: Result<QuestImportModel>,
+    ) -> Result<ImportQuestEntity, Vec<String>> {
+        let mut quest_import_model = row.map_err(|e| vec![e.to_string()])?;
+        quest_import_model.quest.sanitize();
+
+        let mut errors = match quest_import_model.quest.validate() {
+            Ok(()) => Vec::new(),
+            Err(validation_errors) => ValidationErrorModel::from(&validation_errors)
+                .fields
+                .into_iter()
+                .flat_map(|(field, messages)| {
+                    messages
+                        .into_iter()
+                        .map(move |message| format!("{}: {}", field, message))
+                })
+                .collect(),
+        };
+
+        let prepared = Self::prepare_add(guild_commander_id, &quest_import_model.quest)
+            .map_err(|e| errors.push(e.to_string()));
+
+        let objectives = Self::prepare_import_objectives(quest_import_model.objectives)
+            .map_err(|e| errors.push(e.to_string()));
+
+        let requirements = quest_import_model
+            .requirements
+            .map(Self::prepare_requirements)
+            .transpose()
+            .map_err(|e| errors.push(e.to_string()));
+
+        match (prepared, objectives, requirements) {
+            (Ok((quest, tags)), Ok(objectives), Ok(requirements)) if errors.is_empty() => {
+                Ok(ImportQuestEntity {
+                    quest,
+                    tags,
+                    objectives,
+                    requirements,
+                })
+            }
+            _ => Err(errors),
+        }
+    }
+
+    fn prepare_import_objectives(
+        objectives: Vec<AddQuestObjectiveModel>,
+    ) -> Result<Vec<(i32, AddQuestObjectiveModel)>> {
+        if objectives.len() > MAX_OBJECTIVES_PER_QUEST {
+            return Err(InvalidQuestModel::new(format!(
+                "Quest must not have more than {} objectives",
+                MAX_OBJECTIVES_PER_QUEST
+            ))
+            .into());
+        }
+
+        let mut next_position = objectives
+            .iter()
+            .filter_map(|objective| objective.position)
+            .max()
+            .map_or(1, |position| position + 1);
+
+        let mut positions = Vec::with_capacity(objectives.len());
+        for objective in objectives.iter() {
+            Self::validate_objective_title(&objective.title)?;
+
+            let position = objective.position.unwrap_or_else(|| {
+                next_position += 1;
+                next_position - 1
+            });
+
+            if position < 0 {
+                return Err(InvalidQuestModel::new(
+                    "Quest objective position must not be negative",
+                )
+                .into());
+            }
+
+            if positions.contains(&position) {
+                return Err(InvalidQuestModel::new(format!(
+                    "Quest objective position {} is already taken",
+                    position
+                ))
+                .into());
+            }
+
+            positions.push(position);
+        }
+
+        Ok(positions.into_iter().zip(objectives).collect())
+    }
+
+    fn prepare_requirements(
+        mut quest_requirements_model: QuestRequirementsModel,
+    ) -> Result<QuestRequirementsModel> {
+        let negative_minimum = quest_requirements_model
+            .min_level
+            .is_some_and(|min| min < 0)
+            || quest_requirements_model
+                .min_completed_quests
+                .is_some_and(|min| min < 0);
+
+        if negative_minimum {
+            return Err(InvalidQuestModel::new("Quest requirements must not be negative").into());
+        }
+
+        quest_requirements_model.required_skills =
+            normalize_labels(quest_requirements_model.required_skills);
+
+        Ok(quest_requirements_model)
+    }
+
+    fn validate_objective_title(title: &str) -> Result<()> {
+        if title.trim().is_empty() {
+            return Err(InvalidQuestModel::new("Quest objective title must not be empty").into());
+        }
+
+        if title.trim().chars().count() > MAX_OBJECTIVE_TITLE_LENGTH {
+            return Err(InvalidQuestModel::new(format!(
+                "Quest objective title must not be longer than {} characters",
+                MAX_OBJECTIVE_TITLE_LENGTH
+            ))
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn validate_objective_position(
+        position: i32,
+        objectives: &[QuestObjectiveEntity],
+        objective_id: Option<i32>,
+    ) -> Result<()> {
+        if position < 0 {
+            return Err(
+                InvalidQuestModel::new("Quest objective position must not be negative").into(),
+            );
+        }
+
+        if objectives
+            .iter()
+            .any(|objective| objective.position == position && Some(objective.id) != objective_id)
+        {
+            return Err(InvalidQuestModel::new(format!(
+                "Quest objective position {} is already taken",
+                position
+            ))
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn validate_schedule(
+        starts_at: Option<NaiveDateTime>,
+        due_at: Option<NaiveDateTime>,
+        join_deadline: Option<NaiveDateTime>,
+    ) -> Result<()> {
+        if let (Some(starts_at), Some(due_at)) = (starts_at, due_at) {
+            if due_at <= starts_at {
+                return Err(
+                    InvalidQuestModel::new("Quest due date must be after its start date").into(),
+                );
+            }
+        }
+
+        if let (Some(join_deadline), Some(due_at)) = (join_deadline, due_at) {
+            if join_deadline > due_at {
+                return Err(InvalidQuestModel::new(
+                    "Quest join deadline must not be after its due date",
+                )
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn validate_crew_size(max_adventurers: Option<i32>) -> Result<()> {
+    if let Some(max_adventurers) = max_adventurers {
+        if !(1..=MAX_CREW_SIZE).contains(&max_adventurers) {
+            return Err(InvalidQuestModel::new(format!(
+                "Quest crew size must be between 1 and {}",
+                MAX_CREW_SIZE
+            ))
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_reward(reward: Option<&QuestRewardModel>) -> Result<()> {
+    if let Some(reward) = reward {
+        if reward.gold < 0 || reward.xp < 0 {
+            return Err(InvalidQuestModel::new("Quest reward must not be negative").into());
+        }
+
+        if reward.gold > MAX_QUEST_REWARD || reward.xp > MAX_QUEST_REWARD {
+            return Err(InvalidQuestModel::new(format!(
+                "Quest reward must not exceed {}",
+                MAX_QUEST_REWARD
+            ))
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+pub fn normalize_tags(tags: Vec<String>) -> Result<Vec<String>> {
+    let normalized_tags = normalize_labels(tags);
+
+    if normalized_tags.len() > MAX_TAGS_PER_QUEST {
+        return Err(InvalidQuestModel::new(format!(
+            "Quest must not have more than {} tags",
+            MAX_TAGS_PER_QUEST
+        ))
+        .into());
+    }
+
+    if normalized_tags
+        .iter()
+        .any(|tag| tag.chars().count() > MAX_TAG_LENGTH)
+    {
+        return Err(InvalidQuestModel::new(format!(
+            "Quest tags must not be longer than {} characters",
+            MAX_TAG_LENGTH
+        ))
+        .into());
+    }
+
+    Ok(normalized_tags)
 }
@@ -0,0 +1,999 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::use_cases::quest_ops::{
+            QuestOpsUseCase, MAX_OBJECTIVE_TITLE_LENGTH, MAX_TAGS_PER_QUEST, MAX_TAG_LENGTH,
+        },
+        domain::{
+            entities::{
+                quest_objectives::QuestObjectiveEntity,
+                quest_requirements::QuestRequirementEntity,
+                quest_status_histories::QuestStatusHistoryEntity,
+                quests::{QuestEntity, QuestExportEntity},
+            },
+            repositories::{
+                quest_ops::MockQuestOpsRepository, quest_viewing::MockQuestViewingRepository,
+            },
+            value_objects::{
+                crew_member_model::CrewMemberModel,
+                invalid_quest_model::InvalidQuestModel,
+                quest_adventurer_junction::{DEFAULT_CREW_SIZE, MAX_CREW_SIZE},
+                quest_bulk_model::{
+                    BulkAddQuestsModel, BulkItemStatuses, BulkRemoveQuestsModel,
+                    ROLLED_BACK_MESSAGE,
+                },
+                quest_dependency_model::{creates_cycle, QuestDependencyEdgeModel},
+                quest_difficulties::QuestDifficulties,
+                quest_model::{AddQuestModel, EditQuestModel},
+                quest_objective_model::{AddQuestObjectiveModel, EditQuestObjectiveModel},
+                quest_requirements_model::QuestRequirementsModel,
+                quest_reward_model::{QuestRewardModel, MAX_QUEST_REWARD},
+                quest_statuses::QuestStatuses,
+                quest_transfer_model::{parse_quest_import, quests_to_csv, TransferFormats},
+                quest_version_model::QuestVersionConflictModel,
+            },
+        },
+    };
+
+    fn add_quest_model(tags: Vec<String>) -> AddQuestModel {
+        AddQuestModel {
+            name: "test".to_string(),
+            description: Some("test".to_string()),
+            approval_required: None,
+            starts_at: None,
+            due_at: None,
+            join_deadline: None,
+            reward: None,
+            difficulty: Some(QuestDifficulties::Legendary),
+            category_id: None,
+            max_adventurers: None,
+            objectives_required: None,
+            tags: Some(tags),
+        }
+    }
+
+    fn open_quest(id: i32, guild_commander_id: i32) -> QuestEntity {
+        QuestEntity {
+            id,
+            name: "test".to_string(),
+            description: Some("test".to_string()),
+            status: QuestStatuses::Open.to_string(),
+            guild_commander_id,
+            approval_required: false,
+            starts_at: None,
+            due_at: None,
+            join_deadline: None,
+            reward_gold: 0,
+            reward_xp: 0,
+            difficulty: QuestDifficulties::Normal.to_string(),
+            category_id: None,
+            max_adventurers: DEFAULT_CREW_SIZE,
+            series_id: None,
+            objectives_required: false,
+            version: 1,
+            created_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    fn objective(id: i32, position: i32) -> QuestObjectiveEntity {
+        QuestObjectiveEntity {
+            id,
+            quest_id: 1,
+            position,
+            title: format!("objective-{}", id),
+            description: None,
+            done: false,
+            completed_by: None,
+            completed_at: None,
+            created_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_normalizes_tags() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_ops_repo
+            .expect_add()
+            .withf(|add_quest_entity, tags| {
+                add_quest_entity.difficulty == QuestDifficulties::Legendary.to_string()
+                    && *tags == vec!["dragons".to_string(), "night".to_string()]
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(1) }));
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case
+            .add(
+                1,
+                add_quest_model(vec![
+                    " Dragons ".to_string(),
+                    "dragons".to_string(),
+                    "".to_string(),
+                    "NIGHT".to_string(),
+                ]),
+            )
+            .await;
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_fails_with_too_many_tags() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_ops_repo.expect_add().never();
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let tags = (0..=MAX_TAGS_PER_QUEST)
+            .map(|index| format!("tag-{}", index))
+            .collect();
+
+        let result = use_case.add(1, add_quest_model(tags)).await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quest must not have more than 10 tags"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_rejects_tags_longer_than_the_column() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_ops_repo.expect_add().never();
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case
+            .add(1, add_quest_model(vec!["a".repeat(MAX_TAG_LENGTH + 1)]))
+            .await;
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Quest tags must not be longer than 255 characters"
+        );
+        assert!(err.downcast_ref::<InvalidQuestModel>().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_add_fails_with_oversized_crew() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_ops_repo.expect_add().never();
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let mut add_quest_model = add_quest_model(vec![]);
+        add_quest_model.max_adventurers = Some(MAX_CREW_SIZE + 1);
+
+        let result = use_case.add(1, add_quest_model).await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quest crew size must be between 1 and 20"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_fails_with_reward_above_limit() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_ops_repo.expect_add().never();
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let mut add_quest_model = add_quest_model(vec![]);
+        add_quest_model.reward = Some(QuestRewardModel {
+            gold: MAX_QUEST_REWARD + 1,
+            xp: 0,
+        });
+
+        let result = use_case.add(1, add_quest_model).await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!("Quest reward must not exceed {}", MAX_QUEST_REWARD)
+        );
+    }
+
+    #[test]
+    fn test_partial_reward_does_not_overflow() {
+        let reward = QuestRewardModel {
+            gold: i32::MAX,
+            xp: MAX_QUEST_REWARD,
+        };
+
+        assert_eq!(
+            reward.partial(50, 25),
+            QuestRewardModel {
+                gold: i32::MAX / 2,
+                xp: MAX_QUEST_REWARD / 4,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_edit_fails_with_stale_version() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_view_details().returning(|quest_id| {
+            Box::pin(async move {
+                Ok(QuestEntity {
+                    id: quest_id,
+                    name: "test".to_string(),
+                    description: Some("test".to_string()),
+                    status: QuestStatuses::Open.to_string(),
+                    guild_commander_id: 1,
+                    approval_required: false,
+                    starts_at: None,
+                    due_at: None,
+                    join_deadline: None,
+                    reward_gold: 0,
+                    reward_xp: 0,
+                    difficulty: QuestDifficulties::Normal.to_string(),
+                    category_id: None,
+                    max_adventurers: DEFAULT_CREW_SIZE,
+                    series_id: None,
+                    objectives_required: false,
+                    version: 3,
+                    created_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+
+                    updated_at: Utc
+                        .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                        .unwrap()
+                        .naive_utc(),
+                })
+            })
+        });
+
+        mock_quest_ops_repo.expect_edit().never();
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let edit_quest_model = EditQuestModel {
+            name: Some("renamed".to_string()),
+            description: None,
+            approval_required: None,
+            starts_at: None,
+            due_at: None,
+            join_deadline: None,
+            reward: None,
+            difficulty: None,
+            category_id: None,
+            max_adventurers: None,
+            objectives_required: None,
+            tags: None,
+            version: Some(2),
+        };
+
+        let result = use_case.edit(1, 1, 2, edit_quest_model).await;
+        let conflict = result
+            .unwrap_err()
+            .downcast::<QuestVersionConflictModel>()
+            .unwrap();
+        assert_eq!(conflict.expected_version, 2);
+        assert_eq!(conflict.current_version, 3);
+    }
+
+    #[tokio::test]
+    async fn test_edit_rejects_quests_owned_by_another_commander() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(open_quest(quest_id, 2)) }));
+
+        mock_quest_ops_repo.expect_edit().never();
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let edit_quest_model = EditQuestModel {
+            name: Some("renamed".to_string()),
+            description: None,
+            approval_required: None,
+            starts_at: None,
+            due_at: None,
+            join_deadline: None,
+            reward: None,
+            difficulty: None,
+            category_id: None,
+            max_adventurers: None,
+            objectives_required: None,
+            tags: None,
+            version: Some(1),
+        };
+
+        let result = use_case.edit(1, 1, 1, edit_quest_model).await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quest is not owned by this guild commander"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_edit_validates_schedule_against_stored_dates() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo.expect_view_details().returning(|quest_id| {
+            Box::pin(async move {
+                Ok(QuestEntity {
+                    starts_at: Some(
+                        Utc.with_ymd_and_hms(2030, 1, 10, 0, 0, 0)
+                            .unwrap()
+                            .naive_utc(),
+                    ),
+                    ..open_quest(quest_id, 1)
+                })
+            })
+        });
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(0) }));
+
+        mock_quest_ops_repo.expect_edit().never();
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let edit_quest_model = EditQuestModel {
+            name: None,
+            description: None,
+            approval_required: None,
+            starts_at: None,
+            due_at: Some(
+                Utc.with_ymd_and_hms(2030, 1, 5, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+            ),
+            join_deadline: None,
+            reward: None,
+            difficulty: None,
+            category_id: None,
+            max_adventurers: None,
+            objectives_required: None,
+            tags: None,
+            version: Some(1),
+        };
+
+        let result = use_case.edit(1, 1, 1, edit_quest_model).await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quest due date must be after its start date"
+        );
+    }
+
+    #[test]
+    fn test_clone_entity_reopens_quest_for_the_caller() {
+        let mut quest = open_quest(1, 1);
+        quest.status = QuestStatuses::Completed.to_string();
+        quest.due_at = Some(
+            Utc.with_ymd_and_hms(1970, 1, 2, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        );
+        quest.category_id = Some(7);
+        quest.series_id = Some(3);
+
+        let own_clone = quest.to_clone_entity(1);
+        assert_eq!(own_clone.status, QuestStatuses::Open.to_string());
+        assert_eq!(own_clone.due_at, None);
+        assert_eq!(own_clone.series_id, None);
+        assert_eq!(own_clone.category_id, Some(7));
+
+        let foreign_clone = quest.to_clone_entity(2);
+        assert_eq!(foreign_clone.guild_commander_id, 2);
+        assert_eq!(foreign_clone.category_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_add_objective_rejects_taken_and_negative_positions() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(open_quest(quest_id, 1)) }));
+
+        mock_quest_repo
+            .expect_objectives_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![objective(1, 1), objective(2, 2)]) }));
+
+        mock_quest_ops_repo.expect_add_objective().never();
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        for (position, message) in [
+            (2, "Quest objective position 2 is already taken"),
+            (-1, "Quest objective position must not be negative"),
+        ] {
+            let err = use_case
+                .add_objective(
+                    1,
+                    1,
+                    AddQuestObjectiveModel {
+                        title: "Scout the cave".to_string(),
+                        description: None,
+                        position: Some(position),
+                    },
+                )
+                .await
+                .unwrap_err();
+            assert_eq!(err.to_string(), message);
+            assert!(err.downcast_ref::<InvalidQuestModel>().is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_edit_objective_validates_title_length_and_keeps_own_position() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(open_quest(quest_id, 1)) }));
+
+        mock_quest_repo
+            .expect_objectives_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(vec![objective(1, 1), objective(2, 2)]) }));
+
+        mock_quest_ops_repo
+            .expect_edit_objective()
+            .times(1)
+            .returning(|objective_id, _| Box::pin(async move { Ok(objective_id) }));
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let err = use_case
+            .edit_objective(
+                1,
+                2,
+                1,
+                EditQuestObjectiveModel {
+                    title: Some("a".repeat(MAX_OBJECTIVE_TITLE_LENGTH + 1)),
+                    description: None,
+                    position: None,
+                },
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Quest objective title must not be longer than 255 characters"
+        );
+
+        let result = use_case
+            .edit_objective(
+                1,
+                2,
+                1,
+                EditQuestObjectiveModel {
+                    title: None,
+                    description: None,
+                    position: Some(2),
+                },
+            )
+            .await;
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn test_creates_cycle_follows_the_prerequisite_chain() {
+        let edges = [(3, 2), (2, 1)]
+            .into_iter()
+            .map(|(quest_id, prerequisite_id)| QuestDependencyEdgeModel {
+                quest_id,
+                prerequisite_id,
+            })
+            .collect::<Vec<_>>();
+
+        assert!(creates_cycle(&edges, 1, 3));
+        assert!(!creates_cycle(&edges, 4, 3));
+    }
+
+    #[tokio::test]
+    async fn test_add_dependency_leaves_cycle_check_to_the_repository_transaction() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(open_quest(quest_id, 1)) }));
+
+        mock_quest_repo.expect_chain_dependencies().never();
+
+        mock_quest_ops_repo
+            .expect_add_dependency()
+            .withf(|quest_dependency_entity| {
+                quest_dependency_entity.quest_id == 1
+                    && quest_dependency_entity.prerequisite_id == 3
+            })
+            .times(1)
+            .returning(|_| {
+                Box::pin(async {
+                    Err(InvalidQuestModel::new("Quest dependency would create a cycle").into())
+                })
+            });
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let err = use_case.add_dependency(1, 3, 1).await.unwrap_err();
+        assert_eq!(err.to_string(), "Quest dependency would create a cycle");
+        assert!(err.downcast_ref::<InvalidQuestModel>().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_add_dependency_rejects_self_dependency() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(open_quest(quest_id, 1)) }));
+
+        mock_quest_ops_repo.expect_add_dependency().never();
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let err = use_case.add_dependency(1, 1, 1).await.unwrap_err();
+        assert_eq!(err.to_string(), "Quest must not depend on itself");
+        assert!(err.downcast_ref::<InvalidQuestModel>().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_add_many_reports_each_quest() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_ops_repo
+            .expect_add_many()
+            .withf(|quests, all_or_nothing| quests.len() == 2 && !*all_or_nothing)
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(vec![Ok(10), Ok(11)]) }));
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let mut invalid_quest = add_quest_model(vec![]);
+        invalid_quest.reward = Some(QuestRewardModel { gold: -1, xp: 0 });
+
+        let result = use_case
+            .add_many(
+                1,
+                BulkAddQuestsModel {
+                    quests: vec![
+                        add_quest_model(vec![]),
+                        invalid_quest,
+                        add_quest_model(vec![]),
+                    ],
+                    all_or_nothing: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(result.committed);
+        assert_eq!((result.succeeded, result.failed), (2, 1));
+        assert_eq!(result.results[0].quest_id, Some(10));
+        assert_eq!(result.results[1].status, BulkItemStatuses::Failed);
+        assert_eq!(
+            result.results[1].error.as_deref(),
+            Some("Quest reward must not be negative")
+        );
+        assert_eq!(result.results[2].quest_id, Some(11));
+    }
+
+    #[tokio::test]
+    async fn test_add_many_all_or_nothing_skips_writes_when_a_quest_is_invalid() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_ops_repo.expect_add_many().never();
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let mut invalid_quest = add_quest_model(vec![]);
+        invalid_quest.max_adventurers = Some(0);
+
+        let result = use_case
+            .add_many(
+                1,
+                BulkAddQuestsModel {
+                    quests: vec![add_quest_model(vec![]), invalid_quest],
+                    all_or_nothing: Some(true),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(!result.committed);
+        assert_eq!((result.succeeded, result.failed), (0, 2));
+        assert_eq!(result.results[0].status, BulkItemStatuses::RolledBack);
+        assert_eq!(
+            result.results[0].error.as_deref(),
+            Some(ROLLED_BACK_MESSAGE)
+        );
+        assert_eq!(result.results[1].status, BulkItemStatuses::Failed);
+    }
+
+    #[tokio::test]
+    async fn test_remove_many_rolls_back_when_a_removal_fails() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(open_quest(quest_id, 1)) }));
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(0) }));
+
+        mock_quest_ops_repo
+            .expect_remove_many()
+            .withf(|quest_ids, guild_commander_id, all_or_nothing| {
+                *quest_ids == vec![1, 2] && *guild_commander_id == 1 && *all_or_nothing
+            })
+            .times(1)
+            .returning(|_, _, _| {
+                Box::pin(async { Ok(vec![Ok(1), Err(anyhow::anyhow!("Quest is not removable"))]) })
+            });
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case
+            .remove_many(
+                1,
+                BulkRemoveQuestsModel {
+                    quest_ids: vec![1, 2],
+                    all_or_nothing: Some(true),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(!result.committed);
+        assert_eq!(result.results[0].quest_id, Some(1));
+        assert_eq!(result.results[0].status, BulkItemStatuses::RolledBack);
+        assert_eq!(result.results[1].quest_id, Some(2));
+        assert_eq!(
+            result.results[1].error.as_deref(),
+            Some("Quest is not removable")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_quests_dry_run_reports_failing_rows() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_ops_repo.expect_import_many().never();
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let rows = parse_quest_import(
+            TransferFormats::Csv,
+            "name,reward_gold,max_adventurers,tags,objectives\n\
+             Slay the dragon,100,4,dragons;night,\n\
+             \" \",10,,,\n\
+             Guard the gate,lots,,,\n\
+             Rescue the cat,-5,,,\n\
+             Find the map,,,,\"[{\"\"title\"\":\"\" \"\"}]\"\n",
+        )
+        .unwrap();
+
+        let report = use_case.import_quests(1, rows, true).await.unwrap();
+
+        assert!(report.dry_run);
+        assert!(!report.committed);
+        assert_eq!((report.valid, report.invalid), (1, 4));
+        assert!(report.rows[0].errors.is_empty());
+        assert_eq!(
+            report.rows[1].errors,
+            vec!["name: Must be between 1 and 255 characters".to_string()]
+        );
+        assert_eq!(report.rows[2].errors.len(), 1);
+        assert_eq!(
+            report.rows[3].errors,
+            vec!["Quest reward must not be negative".to_string()]
+        );
+        assert_eq!(
+            report.rows[4].errors,
+            vec!["Quest objective title must not be empty".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_quests_adds_every_row_in_one_batch() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_ops_repo
+            .expect_import_many()
+            .withf(|quests| {
+                quests.len() == 2
+                    && quests[0].quest.name == "Slay the dragon"
+                    && quests[0].tags == vec!["dragons".to_string()]
+                    && quests[0]
+                        .objectives
+                        .iter()
+                        .map(|(position, objective)| (*position, objective.title.as_str()))
+                        .eq([(2, "Scout"), (3, "Strike")])
+                    && quests[0]
+                        .requirements
+                        .as_ref()
+                        .is_some_and(|requirements| requirements.min_level == Some(3))
+                    && quests[1].objectives.is_empty()
+                    && quests[1].requirements.is_none()
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(vec![Ok(7), Ok(8)]) }));
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let rows = parse_quest_import(
+            TransferFormats::Json,
+            r#"[
+                {
+                    "name": " Slay the dragon ",
+                    "tags": ["Dragons"],
+                    "objectives": [{ "title": "Scout", "position": 2 }, { "title": "Strike" }],
+                    "requirements": { "min_level": 3 }
+                },
+                { "name": "Guard" }
+            ]"#,
+        )
+        .unwrap();
+
+        let report = use_case.import_quests(1, rows, false).await.unwrap();
+
+        assert!(report.committed);
+        assert_eq!(report.rows[0].quest_id, Some(7));
+        assert_eq!(report.rows[1].quest_id, Some(8));
+    }
+
+    fn quest_export_entity(guild_commander_id: i32) -> QuestExportEntity {
+        let timestamp = Utc
+            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+            .unwrap()
+            .naive_utc();
+
+        QuestExportEntity {
+            quest: QuestEntity {
+                id: 1,
+                name: "test".to_string(),
+                description: None,
+                status: QuestStatuses::InJourney.to_string(),
+                guild_commander_id,
+                approval_required: false,
+                starts_at: None,
+                due_at: None,
+                join_deadline: None,
+                reward_gold: 10,
+                reward_xp: 5,
+                difficulty: QuestDifficulties::Hard.to_string(),
+                category_id: None,
+                max_adventurers: DEFAULT_CREW_SIZE,
+                series_id: None,
+                objectives_required: true,
+                version: 2,
+                created_at: timestamp,
+                updated_at: timestamp,
+            },
+            adventurers_count: 2,
+            tags: vec!["dragons".to_string()],
+            objectives: vec![QuestObjectiveEntity {
+                title: "Scout, then strike".to_string(),
+                description: Some("Quietly".to_string()),
+                done: true,
+                ..objective(1, 4)
+            }],
+            requirements: Some(QuestRequirementEntity {
+                quest_id: 1,
+                min_level: Some(3),
+                min_completed_quests: None,
+                required_skills: serde_json::json!(["archery"]),
+                allowed_adventurer_ids: serde_json::json!([1, 2]),
+                created_at: timestamp,
+                updated_at: timestamp,
+            }),
+            crew: ["ada", "bo"]
+                .iter()
+                .enumerate()
+                .map(|(index, username)| CrewMemberModel {
+                    id: index as i32 + 1,
+                    username: username.to_string(),
+                    joined_at: timestamp,
+                })
+                .collect(),
+            status_histories: vec![QuestStatusHistoryEntity {
+                id: 1,
+                quest_id: 1,
+                status: QuestStatuses::InJourney.to_string(),
+                actor: "GuildCommander".to_string(),
+                guild_commander_id: Some(guild_commander_id),
+                created_at: timestamp,
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_quests_includes_crew_and_status_history() {
+        let mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_quest_exports_by_guild_commander_id()
+            .times(1)
+            .returning(|guild_commander_id| {
+                Box::pin(async move { Ok(vec![quest_export_entity(guild_commander_id)]) })
+            });
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let quest_export_models = use_case.export_quests(1).await.unwrap();
+        assert_eq!(quest_export_models[0].quest.adventurers_count, 2);
+        assert_eq!(quest_export_models[0].quest.progress, Some(100));
+        assert_eq!(quest_export_models[0].crew.len(), 2);
+        assert_eq!(quest_export_models[0].status_history.len(), 1);
+
+        let csv = quests_to_csv(&quest_export_models).unwrap();
+        let row = csv.lines().nth(1).unwrap();
+        assert!(row.contains("ada;bo"));
+        assert!(row.contains("InJourney@1970-01-01T00:00:00"));
+
+        let rows = parse_quest_import(TransferFormats::Csv, &csv).unwrap();
+        let quest_import_model = rows.into_iter().next().unwrap().unwrap();
+        assert_eq!(
+            quest_import_model.quest.reward,
+            Some(QuestRewardModel { gold: 10, xp: 5 })
+        );
+        assert_eq!(
+            quest_import_model.quest.tags,
+            Some(vec!["dragons".to_string()])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_round_trips_objectives_and_requirements() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_quest_exports_by_guild_commander_id()
+            .returning(|guild_commander_id| {
+                Box::pin(async move { Ok(vec![quest_export_entity(guild_commander_id)]) })
+            });
+
+        mock_quest_ops_repo
+            .expect_import_many()
+            .withf(|quests| {
+                let objectives = &quests[0].objectives;
+                let requirements = quests[0].requirements.as_ref().unwrap();
+
+                quests.len() == 1
+                    && objectives.len() == 1
+                    && objectives[0].0 == 4
+                    && objectives[0].1.title == "Scout, then strike"
+                    && objectives[0].1.description.as_deref() == Some("Quietly")
+                    && *requirements
+                        == QuestRequirementsModel {
+                            min_level: Some(3),
+                            min_completed_quests: None,
+                            required_skills: vec!["archery".to_string()],
+                            allowed_adventurer_ids: vec![1, 2],
+                        }
+            })
+            .times(2)
+            .returning(|_| Box::pin(async { Ok(vec![Ok(9)]) }));
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let quest_export_models = use_case.export_quests(1).await.unwrap();
+
+        for (format, body) in [
+            (
+                TransferFormats::Csv,
+                quests_to_csv(&quest_export_models).unwrap(),
+            ),
+            (
+                TransferFormats::Json,
+                serde_json::to_string(&quest_export_models).unwrap(),
+            ),
+        ] {
+            let rows = parse_quest_import(format, &body).unwrap();
+            let report = use_case.import_quests(1, rows, false).await.unwrap();
+
+            assert!(report.committed);
+            assert_eq!(report.rows[0].quest_id, Some(9));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_remove_many_rejects_quests_owned_by_another_commander() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(open_quest(quest_id, quest_id)) }));
+
+        mock_quest_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(0) }));
+
+        mock_quest_ops_repo
+            .expect_remove_many()
+            .withf(|quest_ids, _, _| *quest_ids == vec![1])
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(vec![Ok(1)]) }));
+
+        let use_case =
+            QuestOpsUseCase::new(Arc::new(mock_quest_ops_repo), Arc::new(mock_quest_repo));
+
+        let result = use_case
+            .remove_many(
+                1,
+                BulkRemoveQuestsModel {
+                    quest_ids: vec![1, 2],
+                    all_or_nothing: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.results[0].status, BulkItemStatuses::Succeeded);
+        assert_eq!(result.results[1].status, BulkItemStatuses::Failed);
+        assert_eq!(
+            result.results[1].error.as_deref(),
+            Some("Quest is not owned by this guild commander")
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime};
+
+use crate::{
+    application::use_cases::quest_ops::{normalize_tags, validate_crew_size, validate_reward},
+    domain::{
+        entities::{quest_series::QuestSeriesEntity, quests::AddQuestEntity},
+        repositories::quest_series::QuestSeriesRepository,
+        value_objects::{
+            invalid_quest_model::InvalidQuestModel,
+            quest_recurrence_model::QuestRecurrenceModel,
+            quest_series_model::{AddQuestSeriesModel, EditQuestSeriesModel, QuestSeriesModel},
+            quest_series_statuses::QuestSeriesStatuses,
+        },
+    },
+};
+
+pub const MATERIALIZATION_HORIZON_DAYS: i64 = 7;
+pub const MAX_OCCURRENCES_PER_RUN: usize = 50;
+
+pub struct QuestSeriesUseCase<T>
+where
+    T: QuestSeriesRepository + Send + Sync,
+{
+    quest_series_repository: Arc<T>,
+}
+
+impl<T> QuestSeriesUseCase<T>
+where
+    T: QuestSeriesRepository + Send + Sync,
+{
+    pub fn new(quest_series_repository: Arc<T>) -> Self {
+        Self {
+            quest_series_repository,
+        }
+    }
+
+    pub async fn add(
+        &self,
+        guild_commander_id: i32,
+        add_quest_series_model: AddQuestSeriesModel,
+    ) -> Result<i32> {
+        Self::validate_name(&add_quest_series_model.name)?;
+        validate_reward(add_quest_series_model.reward.as_ref())?;
+        validate_crew_size(add_quest_series_model.max_adventurers)?;
+        Self::validate_recurrence(
+            &add_quest_series_model.recurrence,
+            add_quest_series_model.starts_at,
+            add_quest_series_model.duration_minutes,
+            add_quest_series_model.ends_at,
+        )?;
+        let tags = normalize_tags(add_quest_series_model.tags.clone().unwrap_or_default())?;
+
+        let series_id = self
+            .quest_series_repository
+            .add(add_quest_series_model.to_entity(guild_commander_id, tags))
+            .await?;
+
+        let series = self.quest_series_repository.view_details(series_id).await?;
+        self.materialize_series(&series, chrono::Utc::now().naive_utc())
+            .await?;
+
+        Ok(series_id)
+    }
+
+    pub async fn edit(
+        &self,
+        series_id: i32,
+        guild_commander_id: i32,
+        edit_quest_series_model: EditQuestSeriesModel,
+    ) -> Result<i32> {
+        let series = self.owned_series(series_id, guild_commander_id).await?;
+
+        if series.status == QuestSeriesStatuses::Ended.to_string() {
+            return Err(anyhow::anyhow!("Quest series has already ended"));
+        }
+
+        if let Some(name) = edit_quest_series_model.name.as_deref() {
+            Self::validate_name(name)?;
+        }
+        validate_reward(edit_quest_series_model.reward.as_ref())?;
+        validate_crew_size(edit_quest_series_model.max_adventurers)?;
+        Self::validate_recurrence(
+            edit_quest_series_model
+                .recurrence
+                .as_ref()
+                .unwrap_or(&series.recurrence()),
+            edit_quest_series_model
+                .starts_at
+                .unwrap_or(series.starts_at),
+            edit_quest_series_model
+                .duration_minutes
+                .or(series.duration_minutes),
+            edit_quest_series_model.ends_at.or(series.ends_at),
+        )?;
+        let tags = edit_quest_series_model
+            .tags
+            .clone()
+            .map(normalize_tags)
+            .transpose()?;
+
+        let now = chrono::Utc::now().naive_utc();
+        let edit_quest_series_entity = edit_quest_series_model.to_entity(tags);
+        let edited_series = series.with_edit(&edit_quest_series_entity);
+
+        let (add_quest_entities, materialized_until) =
+            if edited_series.status == QuestSeriesStatuses::Active.to_string() {
+                let (add_quest_entities, materialized_until) =
+                    Self::plan_materialization(&edited_series, now)?;
+                (add_quest_entities, Some(materialized_until))
+            } else {
+                (Vec::new(), None)
+            };
+
+        self.quest_series_repository
+            .edit(
+                series_id,
+                series.updated_at,
+                edit_quest_series_entity,
+                add_quest_entities,
+                materialized_until,
+                now,
+            )
+            .await?;
+
+        Ok(series_id)
+    }
+
+    pub async fn pause(&self, series_id: i32, guild_commander_id: i32) -> Result<i32> {
+        let series = self.owned_series(series_id, guild_commander_id).await?;
+
+        if series.status != QuestSeriesStatuses::Active.to_string() {
+            return Err(anyhow::anyhow!("Quest series is not active"));
+        }
+
+        let result = self
+            .quest_series_repository
+            .update_status(series_id, QuestSeriesStatuses::Paused.to_string())
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn resume(&self, series_id: i32, guild_commander_id: i32) -> Result<i32> {
+        let series = self.owned_series(series_id, guild_commander_id).await?;
+
+        if series.status != QuestSeriesStatuses::Paused.to_string() {
+            return Err(anyhow::anyhow!("Quest series is not paused"));
+        }
+
+        let result = self
+            .quest_series_repository
+            .update_status(series_id, QuestSeriesStatuses::Active.to_string())
+            .await?;
+
+        self.materialize_series(&series, chrono::Utc::now().naive_utc())
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn end(&self, series_id: i32, guild_commander_id: i32) -> Result<i32> {
+        let series = self.owned_series(series_id, guild_commander_id).await?;
+
+        if series.status == QuestSeriesStatuses::Ended.to_string() {
+            return Err(anyhow::anyhow!("Quest series has already ended"));
+        }
+
+        let result = self
+            .quest_series_repository
+            .update_status(series_id, QuestSeriesStatuses::Ended.to_string())
+            .await?;
+
+        self.quest_series_repository
+            .discard_pending_quests(series_id, chrono::Utc::now().naive_utc())
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn view_details(
+        &self,
+        series_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<QuestSeriesModel> {
+        let series = self.owned_series(series_id, guild_commander_id).await?;
+
+        Ok(series.to_model())
+    }
+
+    pub async fn list(&self, guild_commander_id: i32) -> Result<Vec<QuestSeriesModel>> {
+        let results = self
+            .quest_series_repository
+            .series_by_guild_commander_id(guild_commander_id)
+            .await?;
+
+        Ok(results.iter().map(|series| series.to_model()).collect())
+    }
+
+    pub async fn materialize_due_series(&self, now: NaiveDateTime) -> Result<Vec<i32>> {
+        let active_series = self.quest_series_repository.active_series().await?;
+
+        let mut quest_ids = Vec::new();
+        for series in active_series.iter() {
+            if series.ends_at.is_some_and(|ends_at| ends_at <= now) {
+                self.quest_series_repository
+                    .update_status(series.id, QuestSeriesStatuses::Ended.to_string())
+                    .await?;
+                continue;
+            }
+
+            quest_ids.extend(self.materialize_series(series, now).await?);
+        }
+
+        Ok(quest_ids)
+    }
+
+    async fn materialize_series(
+        &self,
+        series: &QuestSeriesEntity,
+        now: NaiveDateTime,
+    ) -> Result<Vec<i32>> {
+        let (add_quest_entities, materialized_until) = Self::plan_materialization(series, now)?;
+
+        let result = self
+            .quest_series_repository
+            .materialize(
+                series.id,
+                add_quest_entities,
+                series.tags(),
+                materialized_until,
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    fn plan_materialization(
+        series: &QuestSeriesEntity,
+        now: NaiveDateTime,
+    ) -> Result<(Vec<AddQuestEntity>, NaiveDateTime)> {
+        let from = series
+            .materialized_until
+            .map_or(now, |materialized_until| materialized_until.max(now));
+
+        let horizon = now + Duration::days(MATERIALIZATION_HORIZON_DAYS);
+        let until = series
+            .ends_at
+            .map_or(horizon, |ends_at| ends_at.min(horizon));
+
+        let occurrences = series.recurrence().occurrences(
+            series.starts_at,
+            from,
+            until,
+            MAX_OCCURRENCES_PER_RUN,
+        )?;
+
+        let materialized_until = if occurrences.len() == MAX_OCCURRENCES_PER_RUN {
+            occurrences.last().copied().unwrap_or(until)
+        } else {
+            until
+        };
+
+        let add_quest_entities = occurrences
+            .into_iter()
+            .map(|occurrence| series.to_quest_entity(occurrence))
+            .collect();
+
+        Ok((add_quest_entities, materialized_until))
+    }
+
+    async fn owned_series(
+        &self,
+        series_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<QuestSeriesEntity> {
+        let series = self.quest_series_repository.view_details(series_id).await?;
+
+        if series.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest series is not owned by this guild commander"
+            ));
+        }
+
+        Ok(series)
+    }
+
+    fn validate_name(name: &str) -> Result<()> {
+        if name.trim().is_empty() {
+            return Err(InvalidQuestModel::new("Quest series name must not be empty").into());
+        }
+
+        Ok(())
+    }
+
+    fn validate_recurrence(
+        recurrence: &QuestRecurrenceModel,
+        starts_at: NaiveDateTime,
+        duration_minutes: Option<i32>,
+        ends_at: Option<NaiveDateTime>,
+    ) -> Result<()> {
+        recurrence
+            .validate()
+            .map_err(|e| InvalidQuestModel::new(e.to_string()))?;
+
+        if duration_minutes.is_some_and(|minutes| minutes <= 0) {
+            return Err(InvalidQuestModel::new(
+                "Quest series duration must be a positive number of minutes",
+            )
+            .into());
+        }
+
+        if ends_at.is_some_and(|ends_at| ends_at <= starts_at) {
+            return Err(InvalidQuestModel::new(
+                "Quest series end date must be after its start date",
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+}
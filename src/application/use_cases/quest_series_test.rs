@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{NaiveDateTime, TimeZone, Utc};
+
+    use crate::{
+        application::use_cases::quest_series::QuestSeriesUseCase,
+        domain::{
+            entities::quest_series::QuestSeriesEntity,
+            repositories::quest_series::MockQuestSeriesRepository,
+            value_objects::{
+                quest_recurrence_model::{QuestRecurrenceFrequencies, QuestRecurrenceModel},
+                quest_series_model::{AddQuestSeriesModel, EditQuestSeriesModel},
+                quest_series_statuses::QuestSeriesStatuses,
+            },
+        },
+    };
+
+    fn datetime(day: u32, hour: u32) -> NaiveDateTime {
+        Utc.with_ymd_and_hms(2025, 1, day, hour, 0, 0)
+            .unwrap()
+            .naive_utc()
+    }
+
+    fn quest_series_entity(ends_at: Option<NaiveDateTime>) -> QuestSeriesEntity {
+        QuestSeriesEntity {
+            id: 1,
+            guild_commander_id: 1,
+            name: "Night watch".to_string(),
+            description: None,
+            approval_required: false,
+            max_adventurers: 4,
+            difficulty: "Normal".to_string(),
+            category_id: None,
+            tags: serde_json::json!(["patrol"]),
+            reward_gold: 10,
+            reward_xp: 20,
+            frequency: QuestRecurrenceFrequencies::Weekly.to_string(),
+            weekdays: serde_json::json!(["Mon", "Thu"]),
+            cron_expression: None,
+            starts_at: datetime(6, 9),
+            duration_minutes: Some(120),
+            ends_at,
+            status: QuestSeriesStatuses::Active.to_string(),
+            materialized_until: None,
+            created_at: datetime(1, 0),
+            updated_at: datetime(1, 0),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_materialize_due_series_creates_weekly_occurrences() {
+        let mut mock_quest_series_repo = MockQuestSeriesRepository::new();
+
+        mock_quest_series_repo
+            .expect_active_series()
+            .returning(|| Box::pin(async { Ok(vec![quest_series_entity(None)]) }));
+
+        mock_quest_series_repo
+            .expect_materialize()
+            .withf(|series_id, add_quest_entities, tags, materialized_until| {
+                let starts_at = add_quest_entities
+                    .iter()
+                    .map(|add_quest_entity| add_quest_entity.starts_at)
+                    .collect::<Vec<_>>();
+
+                *series_id == 1
+                    && starts_at == vec![Some(datetime(9, 9)), Some(datetime(13, 9))]
+                    && add_quest_entities[0].due_at == Some(datetime(9, 11))
+                    && add_quest_entities[0].series_occurrence_at == Some(datetime(9, 9))
+                    && *tags == vec!["patrol".to_string()]
+                    && *materialized_until == datetime(13, 10)
+            })
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(vec![11, 12]) }));
+
+        let use_case = QuestSeriesUseCase::new(Arc::new(mock_quest_series_repo));
+
+        let result = use_case.materialize_due_series(datetime(6, 10)).await;
+        assert_eq!(result.unwrap(), vec![11, 12]);
+    }
+
+    #[tokio::test]
+    async fn test_materialize_due_series_ends_expired_series() {
+        let mut mock_quest_series_repo = MockQuestSeriesRepository::new();
+
+        mock_quest_series_repo
+            .expect_active_series()
+            .returning(|| Box::pin(async { Ok(vec![quest_series_entity(Some(datetime(7, 0)))]) }));
+
+        mock_quest_series_repo
+            .expect_update_status()
+            .withf(|series_id, status| {
+                *series_id == 1 && status == &QuestSeriesStatuses::Ended.to_string()
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(1) }));
+
+        mock_quest_series_repo.expect_materialize().never();
+
+        let use_case = QuestSeriesUseCase::new(Arc::new(mock_quest_series_repo));
+
+        let result = use_case.materialize_due_series(datetime(8, 0)).await;
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_add_fails_with_invalid_cron_expression() {
+        let mut mock_quest_series_repo = MockQuestSeriesRepository::new();
+
+        mock_quest_series_repo.expect_add().never();
+
+        let use_case = QuestSeriesUseCase::new(Arc::new(mock_quest_series_repo));
+
+        let result = use_case
+            .add(
+                1,
+                AddQuestSeriesModel {
+                    name: "Night watch".to_string(),
+                    description: None,
+                    approval_required: None,
+                    max_adventurers: None,
+                    difficulty: None,
+                    category_id: None,
+                    tags: None,
+                    reward: None,
+                    recurrence: QuestRecurrenceModel {
+                        frequency: QuestRecurrenceFrequencies::Cron,
+                        weekdays: vec![],
+                        cron_expression: Some("every night".to_string()),
+                    },
+                    starts_at: datetime(6, 9),
+                    duration_minutes: None,
+                    ends_at: None,
+                },
+            )
+            .await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quest recurrence cron expression is invalid"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_edit_replans_occurrences_in_a_single_repository_call() {
+        let mut mock_quest_series_repo = MockQuestSeriesRepository::new();
+
+        mock_quest_series_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_series_entity(None)) }));
+
+        mock_quest_series_repo
+            .expect_edit()
+            .withf(
+                |series_id,
+                 expected_updated_at,
+                 edit_quest_series_entity,
+                 add_quest_entities,
+                 materialized_until,
+                 _| {
+                    *series_id == 1
+                        && *expected_updated_at == datetime(1, 0)
+                        && edit_quest_series_entity.name == Some("Night patrol".to_string())
+                        && !add_quest_entities.is_empty()
+                        && add_quest_entities.iter().all(|add_quest_entity| {
+                            add_quest_entity.name == "Night patrol"
+                                && add_quest_entity.max_adventurers == 6
+                        })
+                        && materialized_until.is_some()
+                },
+            )
+            .times(1)
+            .returning(|_, _, _, _, _, _| Box::pin(async { Ok(1) }));
+
+        mock_quest_series_repo
+            .expect_discard_pending_quests()
+            .never();
+        mock_quest_series_repo.expect_materialize().never();
+
+        let use_case = QuestSeriesUseCase::new(Arc::new(mock_quest_series_repo));
+
+        let result = use_case
+            .edit(
+                1,
+                1,
+                EditQuestSeriesModel {
+                    name: Some("Night patrol".to_string()),
+                    description: None,
+                    approval_required: None,
+                    max_adventurers: Some(6),
+                    difficulty: None,
+                    category_id: None,
+                    tags: None,
+                    reward: None,
+                    recurrence: None,
+                    starts_at: None,
+                    duration_minutes: None,
+                    ends_at: None,
+                },
+            )
+            .await;
+        assert_eq!(result.unwrap(), 1);
+    }
+}
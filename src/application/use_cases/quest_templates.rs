@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::use_cases::quest_ops::{
+        normalize_tags, validate_crew_size, validate_reward, QuestOpsUseCase,
+    },
+    domain::{
+        entities::quest_templates::QuestTemplateEntity,
+        repositories::{
+            quest_ops::QuestOpsRepository, quest_templates::QuestTemplatesRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            invalid_quest_model::InvalidQuestModel,
+            quest_difficulties::QuestDifficulties,
+            quest_model::AddQuestModel,
+            quest_template_model::{
+                AddQuestTemplateModel, EditQuestTemplateModel, QuestFromTemplateModel,
+                QuestTemplateModel,
+            },
+        },
+    },
+};
+
+pub struct QuestTemplatesUseCase<T1, T2, T3>
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    quest_templates_repository: Arc<T1>,
+    quest_ops_use_case: QuestOpsUseCase<T2, T3>,
+}
+
+impl<T1, T2, T3> QuestTemplatesUseCase<T1, T2, T3>
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    pub fn new(
+        quest_templates_repository: Arc<T1>,
+        quest_ops_repository: Arc<T2>,
+        quest_viewing_repository: Arc<T3>,
+    ) -> Self {
+        Self {
+            quest_templates_repository,
+            quest_ops_use_case: QuestOpsUseCase::new(
+                quest_ops_repository,
+                quest_viewing_repository,
+            ),
+        }
+    }
+
+    pub async fn add(
+        &self,
+        guild_commander_id: i32,
+        add_quest_template_model: AddQuestTemplateModel,
+    ) -> Result<i32> {
+        Self::validate_name(&add_quest_template_model.name)?;
+        validate_reward(add_quest_template_model.reward.as_ref())?;
+        validate_crew_size(add_quest_template_model.max_adventurers)?;
+        let tags = normalize_tags(add_quest_template_model.tags.clone().unwrap_or_default())?;
+
+        let result = self
+            .quest_templates_repository
+            .add(add_quest_template_model.to_entity(guild_commander_id, tags))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn edit(
+        &self,
+        template_id: i32,
+        guild_commander_id: i32,
+        edit_quest_template_model: EditQuestTemplateModel,
+    ) -> Result<i32> {
+        if let Some(name) = edit_quest_template_model.name.as_deref() {
+            Self::validate_name(name)?;
+        }
+        validate_reward(edit_quest_template_model.reward.as_ref())?;
+        validate_crew_size(edit_quest_template_model.max_adventurers)?;
+        let tags = edit_quest_template_model
+            .tags
+            .clone()
+            .map(normalize_tags)
+            .transpose()?;
+
+        self.owned_template(template_id, guild_commander_id).await?;
+
+        let result = self
+            .quest_templates_repository
+            .edit(template_id, edit_quest_template_model.to_entity(tags))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove(&self, template_id: i32, guild_commander_id: i32) -> Result<()> {
+        self.owned_template(template_id, guild_commander_id).await?;
+
+        self.quest_templates_repository.remove(template_id).await?;
+
+        Ok(())
+    }
+
+    pub async fn view_details(
+        &self,
+        template_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<QuestTemplateModel> {
+        let template = self.owned_template(template_id, guild_commander_id).await?;
+
+        Ok(template.to_model())
+    }
+
+    pub async fn list(&self, guild_commander_id: i32) -> Result<Vec<QuestTemplateModel>> {
+        let results = self
+            .quest_templates_repository
+            .templates_by_guild_commander_id(guild_commander_id)
+            .await?;
+
+        Ok(results.iter().map(|template| template.to_model()).collect())
+    }
+
+    pub async fn create_quest(
+        &self,
+        template_id: i32,
+        guild_commander_id: i32,
+        quest_from_template_model: QuestFromTemplateModel,
+    ) -> Result<i32> {
+        let template = self.owned_template(template_id, guild_commander_id).await?;
+
+        let add_quest_model = AddQuestModel {
+            name: quest_from_template_model
+                .name
+                .unwrap_or_else(|| template.name.clone()),
+            description: template.description.clone(),
+            approval_required: Some(template.approval_required),
+            starts_at: quest_from_template_model.starts_at,
+            due_at: quest_from_template_model.due_at,
+            join_deadline: quest_from_template_model.join_deadline,
+            reward: Some(template.to_model().reward),
+            difficulty: Some(QuestDifficulties::try_from(&template.difficulty)?),
+            category_id: template.category_id,
+            max_adventurers: Some(template.max_adventurers),
+            objectives_required: None,
+            tags: Some(template.tags()),
+        };
+
+        self.quest_ops_use_case
+            .add(guild_commander_id, add_quest_model)
+            .await
+    }
+
+    async fn owned_template(
+        &self,
+        template_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<QuestTemplateEntity> {
+        let template = self
+            .quest_templates_repository
+            .view_details(template_id)
+            .await?;
+
+        if template.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest template is not owned by this guild commander"
+            ));
+        }
+
+        Ok(template)
+    }
+
+    fn validate_name(name: &str) -> Result<()> {
+        if name.trim().is_empty() {
+            return Err(InvalidQuestModel::new("Quest template name must not be empty").into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::use_cases::quest_templates::QuestTemplatesUseCase,
+        domain::{
+            entities::quest_templates::QuestTemplateEntity,
+            repositories::{
+                quest_ops::MockQuestOpsRepository, quest_templates::MockQuestTemplatesRepository,
+                quest_viewing::MockQuestViewingRepository,
+            },
+            value_objects::{
+                quest_difficulties::QuestDifficulties,
+                quest_template_model::{EditQuestTemplateModel, QuestFromTemplateModel},
+            },
+        },
+    };
+
+    fn quest_template_entity(guild_commander_id: i32) -> QuestTemplateEntity {
+        QuestTemplateEntity {
+            id: 1,
+            guild_commander_id,
+            name: "Weekly patrol".to_string(),
+            description: Some("test".to_string()),
+            approval_required: true,
+            max_adventurers: 6,
+            difficulty: QuestDifficulties::Hard.to_string(),
+            category_id: None,
+            tags: serde_json::json!(["patrol", "night"]),
+            reward_gold: 50,
+            reward_xp: 120,
+            created_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+
+            updated_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_quest_copies_template_fields() {
+        let mut mock_quest_templates_repo = MockQuestTemplatesRepository::new();
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_templates_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_template_entity(1)) }));
+
+        mock_quest_ops_repo
+            .expect_add()
+            .withf(|add_quest_entity, tags| {
+                add_quest_entity.name == "Weekly patrol #2"
+                    && add_quest_entity.guild_commander_id == 1
+                    && add_quest_entity.approval_required
+                    && add_quest_entity.max_adventurers == 6
+                    && add_quest_entity.difficulty == QuestDifficulties::Hard.to_string()
+                    && add_quest_entity.reward_gold == 50
+                    && add_quest_entity.reward_xp == 120
+                    && *tags == vec!["patrol".to_string(), "night".to_string()]
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(7) }));
+
+        let use_case = QuestTemplatesUseCase::new(
+            Arc::new(mock_quest_templates_repo),
+            Arc::new(mock_quest_ops_repo),
+            Arc::new(mock_quest_repo),
+        );
+
+        let result = use_case
+            .create_quest(
+                1,
+                1,
+                QuestFromTemplateModel {
+                    name: Some("Weekly patrol #2".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert_eq!(result.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_edit_fails_when_template_is_not_owned() {
+        let mut mock_quest_templates_repo = MockQuestTemplatesRepository::new();
+        let mock_quest_ops_repo = MockQuestOpsRepository::new();
+        let mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_templates_repo
+            .expect_view_details()
+            .returning(|_| Box::pin(async { Ok(quest_template_entity(2)) }));
+
+        mock_quest_templates_repo.expect_edit().never();
+
+        let use_case = QuestTemplatesUseCase::new(
+            Arc::new(mock_quest_templates_repo),
+            Arc::new(mock_quest_ops_repo),
+            Arc::new(mock_quest_repo),
+        );
+
+        let result = use_case
+            .edit(
+                1,
+                1,
+                EditQuestTemplateModel {
+                    name: Some("renamed".to_string()),
+                    description: None,
+                    approval_required: None,
+                    max_adventurers: None,
+                    difficulty: None,
+                    category_id: None,
+                    tags: None,
+                    reward: None,
+                },
+            )
+            .await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quest template is not owned by this guild commander"
+        );
+    }
+}
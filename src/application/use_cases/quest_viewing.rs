@@ -1,10 +1,19 @@
-use std::sync::Arc;
+use std::{collections::BTreeSet, sync::Arc};
 
 use anyhow::Result;
 
 use crate::domain::{
     repositories::quest_viewing::QuestViewingRepository,
-    value_objects::{board_checking_filter::BoardCheckingFilter, quest_model::QuestModel},
+    value_objects::{
+        board_checking_filter::BoardCheckingFilter,
+        crew_member_model::CrewMemberModel,
+        crew_membership_statuses::CrewMembershipStatuses,
+        quest_dependency_model::{QuestDependencyGraphModel, QuestDependencyNodeModel},
+        quest_model::QuestModel,
+        quest_objective_model::objectives_progress,
+        quest_objective_model::QuestObjectiveModel,
+        quest_requirements_model::QuestRequirementsModel,
+    },
 };
 
 pub struct QuestViewingUseCase<T>
@@ -32,7 +41,18 @@ where
             .adventurers_counting_by_quest_id(quest_id)
             .await?;
 
-        let quest_model = result.to_model(adventurers_count);
+        let tags = self
+            .quest_viewing_repository
+            .tags_by_quest_id(quest_id)
+            .await?;
+
+        let objectives = self
+            .quest_viewing_repository
+            .objectives_by_quest_id(quest_id)
+            .await?;
+
+        let quest_model =
+            result.to_model(adventurers_count, tags, objectives_progress(&objectives));
 
         Ok(quest_model)
     }
@@ -47,10 +67,94 @@ where
                 .adventurers_counting_by_quest_id(quest.id)
                 .await?;
 
-            let quest_model = quest.to_model(adventurers_count);
+            let tags = self
+                .quest_viewing_repository
+                .tags_by_quest_id(quest.id)
+                .await?;
+
+            let objectives = self
+                .quest_viewing_repository
+                .objectives_by_quest_id(quest.id)
+                .await?;
+
+            let quest_model =
+                quest.to_model(adventurers_count, tags, objectives_progress(&objectives));
             quests_model.push(quest_model);
         }
 
         Ok(quests_model)
     }
+
+    pub async fn crew_roster(&self, quest_id: i32) -> Result<Vec<CrewMemberModel>> {
+        self.quest_viewing_repository.view_details(quest_id).await?;
+
+        let crew = self
+            .quest_viewing_repository
+            .crew_by_quest_id(quest_id, CrewMembershipStatuses::Joined)
+            .await?;
+
+        Ok(crew)
+    }
+
+    pub async fn objectives(&self, quest_id: i32) -> Result<Vec<QuestObjectiveModel>> {
+        self.quest_viewing_repository.view_details(quest_id).await?;
+
+        let objectives = self
+            .quest_viewing_repository
+            .objectives_by_quest_id(quest_id)
+            .await?;
+
+        Ok(objectives
+            .iter()
+            .map(|objective| objective.to_model())
+            .collect())
+    }
+
+    pub async fn dependency_graph(&self, quest_id: i32) -> Result<QuestDependencyGraphModel> {
+        self.quest_viewing_repository.view_details(quest_id).await?;
+
+        let edges = self
+            .quest_viewing_repository
+            .chain_dependencies(quest_id)
+            .await?
+            .iter()
+            .map(|dependency| dependency.to_model())
+            .collect::<Vec<_>>();
+
+        let mut quest_ids = BTreeSet::from([quest_id]);
+        for edge in edges.iter() {
+            quest_ids.insert(edge.quest_id);
+            quest_ids.insert(edge.prerequisite_id);
+        }
+
+        let nodes = self
+            .quest_viewing_repository
+            .quests_by_ids(quest_ids.into_iter().collect())
+            .await?
+            .into_iter()
+            .map(|quest| QuestDependencyNodeModel {
+                id: quest.id,
+                name: quest.name,
+                status: quest.status,
+            })
+            .collect();
+
+        Ok(QuestDependencyGraphModel { nodes, edges })
+    }
+
+    pub async fn requirements(&self, quest_id: i32) -> Result<QuestRequirementsModel> {
+        self.quest_viewing_repository.view_details(quest_id).await?;
+
+        let requirements = self
+            .quest_viewing_repository
+            .requirements_by_quest_id(quest_id)
+            .await?;
+
+        let result = requirements
+            .map(|requirements| requirements.to_model())
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(result)
+    }
 }
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime};
+
+use crate::domain::{
+    entities::{
+        webhook_deliveries::{UpdateWebhookDeliveryEntity, WebhookDeliveryEntity},
+        webhooks::WebhookEntity,
+    },
+    repositories::{webhook_sender::WebhookSender, webhooks::WebhooksRepository},
+    value_objects::{
+        webhook_delivery_statuses::WebhookDeliveryStatuses,
+        webhook_model::{retry_backoff, sign_payload, WebhookRequestModel},
+    },
+};
+
+pub const MAX_WEBHOOK_ATTEMPTS: i32 = 6;
+pub const WEBHOOK_DELIVERY_BATCH_SIZE: i64 = 50;
+pub const WEBHOOK_DELIVERY_CLAIM_SECONDS: i64 = 600;
+
+pub struct WebhookDeliveriesUseCase<T1, T2>
+where
+    T1: WebhooksRepository + Send + Sync,
+    T2: WebhookSender + Send + Sync,
+{
+    webhooks_repository: Arc<T1>,
+    webhook_sender: Arc<T2>,
+}
+
+impl<T1, T2> WebhookDeliveriesUseCase<T1, T2>
+where
+    T1: WebhooksRepository + Send + Sync,
+    T2: WebhookSender + Send + Sync,
+{
+    pub fn new(webhooks_repository: Arc<T1>, webhook_sender: Arc<T2>) -> Self {
+        Self {
+            webhooks_repository,
+            webhook_sender,
+        }
+    }
+
+    pub async fn deliver_due(&self, now: NaiveDateTime) -> Result<Vec<i32>> {
+        let due_deliveries = self
+            .webhooks_repository
+            .due_deliveries(
+                now,
+                now + Duration::seconds(WEBHOOK_DELIVERY_CLAIM_SECONDS),
+                WEBHOOK_DELIVERY_BATCH_SIZE,
+            )
+            .await?;
+
+        let mut delivered_ids = Vec::new();
+        for (delivery, webhook) in due_deliveries.iter() {
+            let result = self
+                .webhook_sender
+                .send(Self::to_request(delivery, webhook))
+                .await;
+
+            let attempts = delivery.attempts + 1;
+
+            let update_webhook_delivery_entity = match result {
+                Ok(status_code) if (200..300).contains(&status_code) => {
+                    delivered_ids.push(delivery.id);
+
+                    UpdateWebhookDeliveryEntity {
+                        status: WebhookDeliveryStatuses::Delivered.to_string(),
+                        attempts,
+                        next_attempt_at: delivery.next_attempt_at,
+                        last_status_code: Some(status_code as i32),
+                        last_error: None,
+                        delivered_at: Some(now),
+                        updated_at: now,
+                    }
+                }
+                Ok(status_code) => Self::retry_or_fail(
+                    attempts,
+                    now,
+                    Some(status_code as i32),
+                    format!("Webhook endpoint responded with status {}", status_code),
+                ),
+                Err(e) => Self::retry_or_fail(attempts, now, None, e.to_string()),
+            };
+
+            self.webhooks_repository
+                .update_delivery(delivery.id, update_webhook_delivery_entity)
+                .await?;
+        }
+
+        Ok(delivered_ids)
+    }
+
+    fn to_request(
+        delivery: &WebhookDeliveryEntity,
+        webhook: &WebhookEntity,
+    ) -> WebhookRequestModel {
+        let body = delivery.payload.to_string();
+
+        let mut headers = vec![
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("X-Quest-Event".to_string(), delivery.event_kind.clone()),
+            ("X-Quest-Delivery".to_string(), delivery.id.to_string()),
+            (
+                "X-Quest-Signature".to_string(),
+                sign_payload(&webhook.secret, &body),
+            ),
+        ];
+
+        if let Some(idempotency_key) = &delivery.idempotency_key {
+            headers.push((
+                "X-Quest-Idempotency-Key".to_string(),
+                idempotency_key.clone(),
+            ));
+        }
+
+        WebhookRequestModel {
+            url: webhook.url.clone(),
+            headers,
+            body,
+        }
+    }
+
+    fn retry_or_fail(
+        attempts: i32,
+        now: NaiveDateTime,
+        last_status_code: Option<i32>,
+        last_error: String,
+    ) -> UpdateWebhookDeliveryEntity {
+        let status = if attempts >= MAX_WEBHOOK_ATTEMPTS {
+            WebhookDeliveryStatuses::Failed
+        } else {
+            WebhookDeliveryStatuses::Pending
+        };
+
+        UpdateWebhookDeliveryEntity {
+            status: status.to_string(),
+            attempts,
+            next_attempt_at: now + retry_backoff(attempts),
+            last_status_code,
+            last_error: Some(last_error),
+            delivered_at: None,
+            updated_at: now,
+        }
+    }
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{Duration, TimeZone, Utc};
+
+    use crate::{
+        application::use_cases::webhook_deliveries::WebhookDeliveriesUseCase,
+        domain::{
+            entities::{webhook_deliveries::WebhookDeliveryEntity, webhooks::WebhookEntity},
+            repositories::{webhook_sender::MockWebhookSender, webhooks::MockWebhooksRepository},
+            value_objects::{
+                quest_event_model::QuestEventKinds,
+                webhook_delivery_statuses::WebhookDeliveryStatuses, webhook_model::sign_payload,
+            },
+        },
+    };
+
+    fn due_delivery(attempts: i32) -> (WebhookDeliveryEntity, WebhookEntity) {
+        (
+            WebhookDeliveryEntity {
+                id: 1,
+                webhook_id: 1,
+                event_kind: QuestEventKinds::CrewJoined.to_string(),
+                payload: serde_json::json!({ "quest_id": 1 }),
+                status: WebhookDeliveryStatuses::Pending.to_string(),
+                attempts,
+                next_attempt_at: Utc
+                    .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+                last_status_code: None,
+                last_error: None,
+                delivered_at: None,
+                created_at: Utc
+                    .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+
+                updated_at: Utc
+                    .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+                idempotency_key: Some("0123456789abcdef0123456789abcdef".to_string()),
+            },
+            WebhookEntity {
+                id: 1,
+                guild_commander_id: 1,
+                url: "http://localhost:9000/hooks".to_string(),
+                secret: "0123456789abcdef".to_string(),
+                events: serde_json::json!([]),
+                active: true,
+                created_at: Utc
+                    .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+
+                updated_at: Utc
+                    .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_deliver_due_signs_and_marks_delivered() {
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+        let mut mock_webhook_sender = MockWebhookSender::new();
+
+        mock_webhooks_repo
+            .expect_due_deliveries()
+            .withf(|now, claimed_until, _| claimed_until > now)
+            .returning(|_, _, _| Box::pin(async { Ok(vec![due_delivery(0)]) }));
+
+        mock_webhook_sender
+            .expect_send()
+            .withf(|webhook_request| {
+                webhook_request.headers.contains(&(
+                    "X-Quest-Signature".to_string(),
+                    sign_payload("0123456789abcdef", &webhook_request.body),
+                ))
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(204) }));
+
+        mock_webhooks_repo
+            .expect_update_delivery()
+            .withf(|_, update_webhook_delivery_entity| {
+                update_webhook_delivery_entity.status
+                    == WebhookDeliveryStatuses::Delivered.to_string()
+                    && update_webhook_delivery_entity.attempts == 1
+                    && update_webhook_delivery_entity.last_status_code == Some(204)
+            })
+            .times(1)
+            .returning(|delivery_id, _| Box::pin(async move { Ok(delivery_id) }));
+
+        let use_case = WebhookDeliveriesUseCase::new(
+            Arc::new(mock_webhooks_repo),
+            Arc::new(mock_webhook_sender),
+        );
+
+        let result = use_case.deliver_due(Utc::now().naive_utc()).await;
+        assert_eq!(result.unwrap(), vec![1]);
+    }
+
+    #[tokio::test]
+    async fn test_deliver_due_backs_off_exponentially_on_failure() {
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+        let mut mock_webhook_sender = MockWebhookSender::new();
+
+        let now = Utc
+            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+            .unwrap()
+            .naive_utc();
+
+        mock_webhooks_repo
+            .expect_due_deliveries()
+            .returning(|_, _, _| Box::pin(async { Ok(vec![due_delivery(2)]) }));
+
+        mock_webhook_sender
+            .expect_send()
+            .returning(|_| Box::pin(async { Ok(500) }));
+
+        mock_webhooks_repo
+            .expect_update_delivery()
+            .withf(move |_, update_webhook_delivery_entity| {
+                update_webhook_delivery_entity.status
+                    == WebhookDeliveryStatuses::Pending.to_string()
+                    && update_webhook_delivery_entity.attempts == 3
+                    && update_webhook_delivery_entity.next_attempt_at
+                        == now + Duration::seconds(120)
+            })
+            .times(1)
+            .returning(|delivery_id, _| Box::pin(async move { Ok(delivery_id) }));
+
+        let use_case = WebhookDeliveriesUseCase::new(
+            Arc::new(mock_webhooks_repo),
+            Arc::new(mock_webhook_sender),
+        );
+
+        let result = use_case.deliver_due(now).await;
+        assert!(result.unwrap().is_empty());
+    }
+}
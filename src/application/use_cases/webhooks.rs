@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::async_trait;
+
+use crate::domain::{
+    entities::webhooks::WebhookEntity,
+    repositories::{quest_event_handler::QuestEventHandler, webhooks::WebhooksRepository},
+    value_objects::{
+        outbox_event_model::OutboxEventModel,
+        quest_event_model::QuestEventModel,
+        webhook_model::{
+            ensure_public_host, parse_webhook_url, to_delivery_entity, AddWebhookModel,
+            EditWebhookModel, WebhookDeliveryFilter, WebhookDeliveryModel, WebhookModel,
+        },
+    },
+};
+
+pub const MIN_WEBHOOK_SECRET_LENGTH: usize = 16;
+pub const DEFAULT_WEBHOOK_DELIVERIES_LIMIT: i64 = 50;
+pub const MAX_WEBHOOK_DELIVERIES_LIMIT: i64 = 200;
+
+pub struct WebhooksUseCase<T>
+where
+    T: WebhooksRepository + Send + Sync,
+{
+    webhooks_repository: Arc<T>,
+}
+
+impl<T> WebhooksUseCase<T>
+where
+    T: WebhooksRepository + Send + Sync,
+{
+    pub fn new(webhooks_repository: Arc<T>) -> Self {
+        Self {
+            webhooks_repository,
+        }
+    }
+
+    pub async fn add(
+        &self,
+        guild_commander_id: i32,
+        add_webhook_model: AddWebhookModel,
+    ) -> Result<i32> {
+        Self::validate_url(&add_webhook_model.url)?;
+        Self::validate_secret(&add_webhook_model.secret)?;
+
+        let result = self
+            .webhooks_repository
+            .add(add_webhook_model.to_entity(guild_commander_id))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn edit(
+        &self,
+        webhook_id: i32,
+        guild_commander_id: i32,
+        edit_webhook_model: EditWebhookModel,
+    ) -> Result<i32> {
+        if let Some(url) = edit_webhook_model.url.as_ref() {
+            Self::validate_url(url)?;
+        }
+
+        if let Some(secret) = edit_webhook_model.secret.as_ref() {
+            Self::validate_secret(secret)?;
+        }
+
+        self.owned_webhook(webhook_id, guild_commander_id).await?;
+
+        let result = self
+            .webhooks_repository
+            .edit(webhook_id, edit_webhook_model.to_entity())
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove(&self, webhook_id: i32, guild_commander_id: i32) -> Result<()> {
+        self.owned_webhook(webhook_id, guild_commander_id).await?;
+
+        self.webhooks_repository.remove(webhook_id).await?;
+
+        Ok(())
+    }
+
+    pub async fn list(&self, guild_commander_id: i32) -> Result<Vec<WebhookModel>> {
+        let results = self
+            .webhooks_repository
+            .webhooks_by_guild_commander_id(guild_commander_id)
+            .await?;
+
+        results.iter().map(|webhook| webhook.to_model()).collect()
+    }
+
+    pub async fn deliveries(
+        &self,
+        webhook_id: i32,
+        guild_commander_id: i32,
+        filter: &WebhookDeliveryFilter,
+    ) -> Result<Vec<WebhookDeliveryModel>> {
+        self.owned_webhook(webhook_id, guild_commander_id).await?;
+
+        let limit = filter
+            .limit
+            .unwrap_or(DEFAULT_WEBHOOK_DELIVERIES_LIMIT)
+            .clamp(1, MAX_WEBHOOK_DELIVERIES_LIMIT);
+
+        let results = self
+            .webhooks_repository
+            .deliveries_by_webhook_id(webhook_id, limit)
+            .await?;
+
+        Ok(results.iter().map(|delivery| delivery.to_model()).collect())
+    }
+
+    pub async fn enqueue(&self, idempotency_key: &str, event: &QuestEventModel) -> Result<usize> {
+        let webhooks = self
+            .webhooks_repository
+            .active_webhooks_by_quest_id(event.quest_id)
+            .await?;
+
+        let mut deliveries = Vec::new();
+        for webhook in webhooks.iter() {
+            if webhook.subscribes_to(event.kind)? {
+                deliveries.push(to_delivery_entity(webhook.id, idempotency_key, event));
+            }
+        }
+
+        if deliveries.is_empty() {
+            return Ok(0);
+        }
+
+        let result = self
+            .webhooks_repository
+            .enqueue_deliveries(deliveries)
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn owned_webhook(
+        &self,
+        webhook_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<WebhookEntity> {
+        let webhook = self.webhooks_repository.view_details(webhook_id).await?;
+
+        if webhook.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Webhook is not owned by this guild commander"
+            ));
+        }
+
+        Ok(webhook)
+    }
+
+    fn validate_url(url: &str) -> Result<()> {
+        let url = parse_webhook_url(url)?;
+
+        ensure_public_host(&url)
+    }
+
+    fn validate_secret(secret: &str) -> Result<()> {
+        if secret.chars().count() < MIN_WEBHOOK_SECRET_LENGTH {
+            return Err(anyhow::anyhow!(
+                "Webhook secret must be at least {} characters",
+                MIN_WEBHOOK_SECRET_LENGTH
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T> QuestEventHandler for WebhooksUseCase<T>
+where
+    T: WebhooksRepository + Send + Sync,
+{
+    fn name(&self) -> &'static str {
+        "webhooks"
+    }
+
+    async fn handle(&self, outbox_event: &OutboxEventModel) -> Result<()> {
+        self.enqueue(&outbox_event.idempotency_key, &outbox_event.event)
+            .await?;
+
+        Ok(())
+    }
+}
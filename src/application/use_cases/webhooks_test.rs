@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::use_cases::webhooks::WebhooksUseCase,
+        domain::{
+            entities::webhooks::WebhookEntity,
+            repositories::webhooks::MockWebhooksRepository,
+            value_objects::quest_event_model::{QuestEventKinds, QuestEventModel},
+        },
+    };
+
+    fn webhook(id: i32, events: serde_json::Value) -> WebhookEntity {
+        WebhookEntity {
+            id,
+            guild_commander_id: 1,
+            url: "https://example.com/hooks".to_string(),
+            secret: "0123456789abcdef".to_string(),
+            events,
+            active: true,
+            created_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+
+            updated_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_only_delivers_to_subscribed_webhooks() {
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+
+        mock_webhooks_repo
+            .expect_active_webhooks_by_quest_id()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![
+                        webhook(1, serde_json::json!(["QuestAdded"])),
+                        webhook(2, serde_json::json!(["CrewJoined"])),
+                        webhook(3, serde_json::json!([])),
+                    ])
+                })
+            });
+
+        mock_webhooks_repo
+            .expect_enqueue_deliveries()
+            .withf(|deliveries| {
+                deliveries
+                    .iter()
+                    .map(|delivery| delivery.webhook_id)
+                    .eq([1, 3])
+            })
+            .times(1)
+            .returning(|deliveries| Box::pin(async move { Ok(deliveries.len()) }));
+
+        let use_case = WebhooksUseCase::new(Arc::new(mock_webhooks_repo));
+
+        let event = QuestEventModel::quest(QuestEventKinds::QuestAdded, 1);
+        let result = use_case.enqueue("key", &event).await;
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_fails_when_event_filter_cannot_be_decoded() {
+        let mut mock_webhooks_repo = MockWebhooksRepository::new();
+
+        mock_webhooks_repo
+            .expect_active_webhooks_by_quest_id()
+            .returning(|_| {
+                Box::pin(async { Ok(vec![webhook(1, serde_json::json!({ "kind": "all" }))]) })
+            });
+
+        mock_webhooks_repo.expect_enqueue_deliveries().never();
+
+        let use_case = WebhooksUseCase::new(Arc::new(mock_webhooks_repo));
+
+        let event = QuestEventModel::quest(QuestEventKinds::QuestAdded, 1);
+        assert!(use_case.enqueue("key", &event).await.is_err());
+    }
+}
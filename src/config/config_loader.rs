@@ -1,7 +1,10 @@
 use anyhow::Result;
 
 use super::{
-    config_model::{AdventurersSecret, Database, DotEnvyConfig, GuildCommandersSecret, Server},
+    config_model::{
+        AdventurersSecret, Cache, Database, DotEnvyConfig, GuildCommandersSecret, Leveling,
+        Rewards, Scheduler, Server, Webhooks,
+    },
     stage::Stage,
 };
 
@@ -24,7 +27,47 @@ pub fn load() -> Result<DotEnvyConfig> {
         url: std::env::var("DATABASE_URL").expect("DATABASE_URL is invalid"),
     };
 
-    Ok(DotEnvyConfig { server, database })
+    let scheduler = Scheduler {
+        quest_expiry_interval: scheduler_interval("SCHEDULER_QUEST_EXPIRY_INTERVAL", "60")?,
+        quest_recurrence_interval: scheduler_interval(
+            "SCHEDULER_QUEST_RECURRENCE_INTERVAL",
+            "300",
+        )?,
+        webhook_delivery_interval: scheduler_interval("SCHEDULER_WEBHOOK_DELIVERY_INTERVAL", "10")?,
+        outbox_relay_interval: scheduler_interval("SCHEDULER_OUTBOX_RELAY_INTERVAL", "1")?,
+    };
+
+    let cache = Cache {
+        quest_viewing_ttl: std::env::var("CACHE_QUEST_VIEWING_TTL")
+            .unwrap_or("10".to_string())
+            .parse()?,
+    };
+
+    let webhooks = Webhooks {
+        allow_private_targets: std::env::var("WEBHOOK_ALLOW_PRIVATE_TARGETS")
+            .unwrap_or("false".to_string())
+            .parse()?,
+    };
+
+    Ok(DotEnvyConfig {
+        server,
+        database,
+        scheduler,
+        cache,
+        webhooks,
+    })
+}
+
+fn scheduler_interval(key: &str, default: &str) -> Result<u64> {
+    let interval = std::env::var(key)
+        .unwrap_or(default.to_string())
+        .parse::<u64>()?;
+
+    if interval == 0 {
+        return Err(anyhow::anyhow!("{} must be greater than 0", key));
+    }
+
+    Ok(interval)
 }
 
 pub fn get_stage() -> Stage {
@@ -34,6 +77,36 @@ pub fn get_stage() -> Stage {
     Stage::try_from(&stage_str).unwrap_or_default()
 }
 
+pub fn get_rewards_env() -> Result<Rewards> {
+    dotenvy::dotenv().ok();
+
+    let rewards = Rewards {
+        failed_gold_percent: std::env::var("REWARD_FAILED_GOLD_PERCENT")
+            .unwrap_or("0".to_string())
+            .parse()?,
+        failed_xp_percent: std::env::var("REWARD_FAILED_XP_PERCENT")
+            .unwrap_or("25".to_string())
+            .parse()?,
+    };
+
+    Ok(rewards)
+}
+
+pub fn get_leveling_env() -> Result<Leveling> {
+    dotenvy::dotenv().ok();
+
+    let leveling = Leveling {
+        base_xp: std::env::var("LEVELING_BASE_XP")
+            .unwrap_or("100".to_string())
+            .parse()?,
+        exponent: std::env::var("LEVELING_EXPONENT")
+            .unwrap_or("1.5".to_string())
+            .parse()?,
+    };
+
+    Ok(leveling)
+}
+
 pub fn get_adventurers_secret_env() -> Result<AdventurersSecret> {
     dotenvy::dotenv().ok();
 
@@ -2,6 +2,9 @@
 pub struct DotEnvyConfig {
     pub server: Server,
     pub database: Database,
+    pub scheduler: Scheduler,
+    pub cache: Cache,
+    pub webhooks: Webhooks,
 }
 
 #[derive(Debug, Clone)]
@@ -16,6 +19,36 @@ pub struct Database {
     pub url: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct Scheduler {
+    pub quest_expiry_interval: u64,
+    pub quest_recurrence_interval: u64,
+    pub webhook_delivery_interval: u64,
+    pub outbox_relay_interval: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cache {
+    pub quest_viewing_ttl: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Webhooks {
+    pub allow_private_targets: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Rewards {
+    pub failed_gold_percent: i32,
+    pub failed_xp_percent: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Leveling {
+    pub base_xp: i64,
+    pub exponent: f64,
+}
+
 #[derive(Debug, Clone)]
 pub struct AdventurersSecret {
     pub secret: String,
@@ -0,0 +1,18 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::postgres::schema::adventurer_skills;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = adventurer_skills)]
+pub struct AdventurerSkillEntity {
+    pub id: i32,
+    pub adventurer_id: i32,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = adventurer_skills)]
+pub struct AddAdventurerSkillEntity {
+    pub adventurer_id: i32,
+    pub name: String,
+}
@@ -11,6 +11,8 @@ pub struct AdventurerEntity {
     pub password: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub gold: i64,
+    pub xp: i64,
 }
 
 #[derive(Debug, Clone, Insertable, Queryable)]
@@ -1,3 +1,18 @@
+pub mod adventurer_skills;
 pub mod adventurers;
 pub mod guild_commanders;
+pub mod notifications;
+pub mod outbox_events;
+pub mod quest_categories;
+pub mod quest_comments;
+pub mod quest_dependencies;
+pub mod quest_objectives;
+pub mod quest_requirements;
+pub mod quest_series;
+pub mod quest_status_histories;
+pub mod quest_tags;
+pub mod quest_templates;
 pub mod quests;
+pub mod reward_ledgers;
+pub mod webhook_deliveries;
+pub mod webhooks;
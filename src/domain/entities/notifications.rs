@@ -0,0 +1,46 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::notification_model::NotificationModel,
+    infrastructure::postgres::schema::notifications,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = notifications)]
+pub struct NotificationEntity {
+    pub id: i32,
+    pub recipient_role: String,
+    pub recipient_id: i32,
+    pub quest_id: Option<i32>,
+    pub kind: String,
+    pub message: String,
+    pub read_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl NotificationEntity {
+    pub fn to_model(&self) -> NotificationModel {
+        NotificationModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            kind: self.kind.clone(),
+            message: self.message.clone(),
+            read: self.read_at.is_some(),
+            read_at: self.read_at,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = notifications)]
+pub struct AddNotificationEntity {
+    pub recipient_role: String,
+    pub recipient_id: i32,
+    pub quest_id: Option<i32>,
+    pub kind: String,
+    pub message: String,
+    pub created_at: NaiveDateTime,
+    pub idempotency_key: Option<String>,
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{
+        outbox_event_model::OutboxEventModel, quest_event_model::QuestEventModel,
+    },
+    infrastructure::postgres::schema::outbox_events,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = outbox_events)]
+pub struct OutboxEventEntity {
+    pub id: i32,
+    pub idempotency_key: String,
+    pub kind: String,
+    pub quest_id: i32,
+    pub payload: serde_json::Value,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub processed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub status: String,
+    pub next_attempt_at: NaiveDateTime,
+}
+
+impl OutboxEventEntity {
+    pub fn to_model(&self) -> Result<OutboxEventModel> {
+        let event = serde_json::from_value::<QuestEventModel>(self.payload.clone())?;
+
+        Ok(OutboxEventModel {
+            id: self.id,
+            idempotency_key: self.idempotency_key.clone(),
+            event,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = outbox_events)]
+pub struct AddOutboxEventEntity {
+    pub idempotency_key: String,
+    pub kind: String,
+    pub quest_id: i32,
+    pub payload: serde_json::Value,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,45 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_category_model::QuestCategoryModel,
+    infrastructure::postgres::schema::quest_categories,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_categories)]
+pub struct QuestCategoryEntity {
+    pub id: i32,
+    pub name: String,
+    pub guild_commander_id: i32,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl QuestCategoryEntity {
+    pub fn to_model(&self) -> QuestCategoryModel {
+        QuestCategoryModel {
+            id: self.id,
+            name: self.name.clone(),
+            guild_commander_id: self.guild_commander_id,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_categories)]
+pub struct AddQuestCategoryEntity {
+    pub name: String,
+    pub guild_commander_id: i32,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, AsChangeset, Queryable)]
+#[diesel(table_name = quest_categories)]
+pub struct EditQuestCategoryEntity {
+    pub name: String,
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,62 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_comment_model::{QuestCommentModel, DELETED_COMMENT_BODY},
+    infrastructure::postgres::schema::quest_comments,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_comments)]
+pub struct QuestCommentEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub parent_id: Option<i32>,
+    pub author_role: String,
+    pub author_id: i32,
+    pub body: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+impl QuestCommentEntity {
+    pub fn to_model(&self) -> QuestCommentModel {
+        let deleted = self.deleted_at.is_some();
+
+        QuestCommentModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            parent_id: self.parent_id,
+            author_role: self.author_role.clone(),
+            author_id: self.author_id,
+            body: if deleted {
+                DELETED_COMMENT_BODY.to_string()
+            } else {
+                self.body.clone()
+            },
+            deleted,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_comments)]
+pub struct AddQuestCommentEntity {
+    pub quest_id: i32,
+    pub parent_id: Option<i32>,
+    pub author_role: String,
+    pub author_id: i32,
+    pub body: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, AsChangeset, Queryable)]
+#[diesel(table_name = quest_comments)]
+pub struct EditQuestCommentEntity {
+    pub body: String,
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,24 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_dependency_model::QuestDependencyEdgeModel,
+    infrastructure::postgres::schema::quest_dependencies,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, QueryableByName, Insertable)]
+#[diesel(table_name = quest_dependencies, primary_key(quest_id, prerequisite_id))]
+pub struct QuestDependencyEntity {
+    pub quest_id: i32,
+    pub prerequisite_id: i32,
+    pub created_at: NaiveDateTime,
+}
+
+impl QuestDependencyEntity {
+    pub fn to_model(&self) -> QuestDependencyEdgeModel {
+        QuestDependencyEdgeModel {
+            quest_id: self.quest_id,
+            prerequisite_id: self.prerequisite_id,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_objective_model::QuestObjectiveModel,
+    infrastructure::postgres::schema::quest_objectives,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_objectives)]
+pub struct QuestObjectiveEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub position: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub done: bool,
+    pub completed_by: Option<i32>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl QuestObjectiveEntity {
+    pub fn to_model(&self) -> QuestObjectiveModel {
+        QuestObjectiveModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            position: self.position,
+            title: self.title.clone(),
+            description: self.description.clone(),
+            done: self.done,
+            completed_by: self.completed_by,
+            completed_at: self.completed_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_objectives)]
+pub struct AddQuestObjectiveEntity {
+    pub quest_id: i32,
+    pub position: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, AsChangeset, Queryable)]
+#[diesel(table_name = quest_objectives)]
+pub struct EditQuestObjectiveEntity {
+    pub position: Option<i32>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,42 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_requirements_model::QuestRequirementsModel,
+    infrastructure::postgres::schema::quest_requirements,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_requirements, primary_key(quest_id))]
+pub struct QuestRequirementEntity {
+    pub quest_id: i32,
+    pub min_level: Option<i32>,
+    pub min_completed_quests: Option<i32>,
+    pub required_skills: serde_json::Value,
+    pub allowed_adventurer_ids: serde_json::Value,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl QuestRequirementEntity {
+    pub fn to_model(&self) -> Result<QuestRequirementsModel> {
+        Ok(QuestRequirementsModel {
+            min_level: self.min_level,
+            min_completed_quests: self.min_completed_quests,
+            required_skills: serde_json::from_value(self.required_skills.clone())?,
+            allowed_adventurer_ids: serde_json::from_value(self.allowed_adventurer_ids.clone())?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Insertable, AsChangeset, Queryable)]
+#[diesel(table_name = quest_requirements, treat_none_as_null = true)]
+pub struct UpsertQuestRequirementEntity {
+    pub quest_id: i32,
+    pub min_level: Option<i32>,
+    pub min_completed_quests: Option<i32>,
+    pub required_skills: serde_json::Value,
+    pub allowed_adventurer_ids: serde_json::Value,
+    pub updated_at: NaiveDateTime,
+}
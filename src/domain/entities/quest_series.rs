@@ -0,0 +1,178 @@
+use chrono::{Duration, NaiveDateTime};
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::quests::AddQuestEntity,
+        value_objects::{
+            quest_recurrence_model::{QuestRecurrenceFrequencies, QuestRecurrenceModel},
+            quest_reward_model::QuestRewardModel,
+            quest_series_model::QuestSeriesModel,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::postgres::schema::quest_series,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_series)]
+pub struct QuestSeriesEntity {
+    pub id: i32,
+    pub guild_commander_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub approval_required: bool,
+    pub max_adventurers: i32,
+    pub difficulty: String,
+    pub category_id: Option<i32>,
+    pub tags: serde_json::Value,
+    pub reward_gold: i32,
+    pub reward_xp: i32,
+    pub frequency: String,
+    pub weekdays: serde_json::Value,
+    pub cron_expression: Option<String>,
+    pub starts_at: NaiveDateTime,
+    pub duration_minutes: Option<i32>,
+    pub ends_at: Option<NaiveDateTime>,
+    pub status: String,
+    pub materialized_until: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl QuestSeriesEntity {
+    pub fn to_model(&self) -> QuestSeriesModel {
+        QuestSeriesModel {
+            id: self.id,
+            guild_commander_id: self.guild_commander_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            approval_required: self.approval_required,
+            max_adventurers: self.max_adventurers,
+            difficulty: self.difficulty.clone(),
+            category_id: self.category_id,
+            tags: self.tags(),
+            reward: QuestRewardModel {
+                gold: self.reward_gold,
+                xp: self.reward_xp,
+            },
+            recurrence: self.recurrence(),
+            starts_at: self.starts_at,
+            duration_minutes: self.duration_minutes,
+            ends_at: self.ends_at,
+            status: self.status.clone(),
+            materialized_until: self.materialized_until,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+
+    pub fn tags(&self) -> Vec<String> {
+        serde_json::from_value(self.tags.clone()).unwrap_or_default()
+    }
+
+    pub fn recurrence(&self) -> QuestRecurrenceModel {
+        QuestRecurrenceModel {
+            frequency: QuestRecurrenceFrequencies::try_from(&self.frequency).unwrap_or_default(),
+            weekdays: serde_json::from_value(self.weekdays.clone()).unwrap_or_default(),
+            cron_expression: self.cron_expression.clone(),
+        }
+    }
+
+    pub fn with_edit(&self, edit_quest_series_entity: &EditQuestSeriesEntity) -> Self {
+        let edit = edit_quest_series_entity.clone();
+
+        Self {
+            name: edit.name.unwrap_or_else(|| self.name.clone()),
+            description: edit.description.or_else(|| self.description.clone()),
+            approval_required: edit.approval_required.unwrap_or(self.approval_required),
+            max_adventurers: edit.max_adventurers.unwrap_or(self.max_adventurers),
+            difficulty: edit.difficulty.unwrap_or_else(|| self.difficulty.clone()),
+            category_id: edit.category_id.or(self.category_id),
+            tags: edit.tags.unwrap_or_else(|| self.tags.clone()),
+            reward_gold: edit.reward_gold.unwrap_or(self.reward_gold),
+            reward_xp: edit.reward_xp.unwrap_or(self.reward_xp),
+            frequency: edit.frequency.unwrap_or_else(|| self.frequency.clone()),
+            weekdays: edit.weekdays.unwrap_or_else(|| self.weekdays.clone()),
+            cron_expression: edit
+                .cron_expression
+                .or_else(|| self.cron_expression.clone()),
+            starts_at: edit.starts_at.unwrap_or(self.starts_at),
+            duration_minutes: edit.duration_minutes.or(self.duration_minutes),
+            ends_at: edit.ends_at.or(self.ends_at),
+            materialized_until: None,
+            updated_at: edit.updated_at,
+            ..self.clone()
+        }
+    }
+
+    pub fn to_quest_entity(&self, occurrence: NaiveDateTime) -> AddQuestEntity {
+        AddQuestEntity {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            status: QuestStatuses::Open.to_string(),
+            guild_commander_id: self.guild_commander_id,
+            approval_required: self.approval_required,
+            starts_at: Some(occurrence),
+            due_at: self
+                .duration_minutes
+                .map(|minutes| occurrence + Duration::minutes(minutes as i64)),
+            join_deadline: None,
+            reward_gold: self.reward_gold,
+            reward_xp: self.reward_xp,
+            difficulty: self.difficulty.clone(),
+            category_id: self.category_id,
+            max_adventurers: self.max_adventurers,
+            series_id: Some(self.id),
+            series_occurrence_at: Some(occurrence),
+            objectives_required: false,
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_series)]
+pub struct AddQuestSeriesEntity {
+    pub guild_commander_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub approval_required: bool,
+    pub max_adventurers: i32,
+    pub difficulty: String,
+    pub category_id: Option<i32>,
+    pub tags: serde_json::Value,
+    pub reward_gold: i32,
+    pub reward_xp: i32,
+    pub frequency: String,
+    pub weekdays: serde_json::Value,
+    pub cron_expression: Option<String>,
+    pub starts_at: NaiveDateTime,
+    pub duration_minutes: Option<i32>,
+    pub ends_at: Option<NaiveDateTime>,
+    pub status: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, AsChangeset, Queryable)]
+#[diesel(table_name = quest_series)]
+pub struct EditQuestSeriesEntity {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub approval_required: Option<bool>,
+    pub max_adventurers: Option<i32>,
+    pub difficulty: Option<String>,
+    pub category_id: Option<i32>,
+    pub tags: Option<serde_json::Value>,
+    pub reward_gold: Option<i32>,
+    pub reward_xp: Option<i32>,
+    pub frequency: Option<String>,
+    pub weekdays: Option<serde_json::Value>,
+    pub cron_expression: Option<String>,
+    pub starts_at: Option<NaiveDateTime>,
+    pub duration_minutes: Option<i32>,
+    pub ends_at: Option<NaiveDateTime>,
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,39 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_status_history_model::QuestStatusHistoryModel,
+    infrastructure::postgres::schema::quest_status_histories,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_status_histories)]
+pub struct QuestStatusHistoryEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub status: String,
+    pub actor: String,
+    pub guild_commander_id: Option<i32>,
+    pub created_at: NaiveDateTime,
+}
+
+impl QuestStatusHistoryEntity {
+    pub fn to_model(&self) -> QuestStatusHistoryModel {
+        QuestStatusHistoryModel {
+            status: self.status.clone(),
+            actor: self.actor.clone(),
+            guild_commander_id: self.guild_commander_id,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_status_histories)]
+pub struct AddQuestStatusHistoryEntity {
+    pub quest_id: i32,
+    pub status: String,
+    pub actor: String,
+    pub guild_commander_id: Option<i32>,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,18 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::postgres::schema::quest_tags;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_tags)]
+pub struct QuestTagEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_tags)]
+pub struct AddQuestTagEntity {
+    pub quest_id: i32,
+    pub name: String,
+}
@@ -0,0 +1,85 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{
+        quest_reward_model::QuestRewardModel, quest_template_model::QuestTemplateModel,
+    },
+    infrastructure::postgres::schema::quest_templates,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_templates)]
+pub struct QuestTemplateEntity {
+    pub id: i32,
+    pub guild_commander_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub approval_required: bool,
+    pub max_adventurers: i32,
+    pub difficulty: String,
+    pub category_id: Option<i32>,
+    pub tags: serde_json::Value,
+    pub reward_gold: i32,
+    pub reward_xp: i32,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl QuestTemplateEntity {
+    pub fn to_model(&self) -> QuestTemplateModel {
+        QuestTemplateModel {
+            id: self.id,
+            guild_commander_id: self.guild_commander_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            approval_required: self.approval_required,
+            max_adventurers: self.max_adventurers,
+            difficulty: self.difficulty.clone(),
+            category_id: self.category_id,
+            tags: self.tags(),
+            reward: QuestRewardModel {
+                gold: self.reward_gold,
+                xp: self.reward_xp,
+            },
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+
+    pub fn tags(&self) -> Vec<String> {
+        serde_json::from_value(self.tags.clone()).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_templates)]
+pub struct AddQuestTemplateEntity {
+    pub guild_commander_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub approval_required: bool,
+    pub max_adventurers: i32,
+    pub difficulty: String,
+    pub category_id: Option<i32>,
+    pub tags: serde_json::Value,
+    pub reward_gold: i32,
+    pub reward_xp: i32,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, AsChangeset, Queryable)]
+#[diesel(table_name = quest_templates)]
+pub struct EditQuestTemplateEntity {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub approval_required: Option<bool>,
+    pub max_adventurers: Option<i32>,
+    pub difficulty: Option<String>,
+    pub category_id: Option<i32>,
+    pub tags: Option<serde_json::Value>,
+    pub reward_gold: Option<i32>,
+    pub reward_xp: Option<i32>,
+    pub updated_at: NaiveDateTime,
+}
@@ -1,8 +1,24 @@
+use anyhow::Result;
 use chrono::NaiveDateTime;
 use diesel::prelude::*;
 
 use crate::{
-    domain::value_objects::quest_model::QuestModel, infrastructure::postgres::schema::quests,
+    domain::{
+        entities::{
+            quest_objectives::QuestObjectiveEntity, quest_requirements::QuestRequirementEntity,
+            quest_status_histories::QuestStatusHistoryEntity,
+        },
+        value_objects::{
+            crew_member_model::CrewMemberModel,
+            quest_model::QuestModel,
+            quest_objective_model::{objectives_progress, AddQuestObjectiveModel},
+            quest_requirements_model::QuestRequirementsModel,
+            quest_reward_model::QuestRewardModel,
+            quest_statuses::QuestStatuses,
+            quest_transfer_model::QuestExportModel,
+        },
+    },
+    infrastructure::postgres::schema::quests,
 };
 
 #[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
@@ -13,23 +29,85 @@ pub struct QuestEntity {
     pub description: Option<String>,
     pub status: String,
     pub guild_commander_id: i32,
+    pub approval_required: bool,
+    pub starts_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+    pub reward_gold: i32,
+    pub reward_xp: i32,
+    pub difficulty: String,
+    pub category_id: Option<i32>,
+    pub max_adventurers: i32,
+    pub series_id: Option<i32>,
+    pub objectives_required: bool,
+    pub version: i32,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
 
 impl QuestEntity {
-    pub fn to_model(&self, adventurers_count: i64) -> QuestModel {
+    pub fn to_model(
+        &self,
+        adventurers_count: i64,
+        tags: Vec<String>,
+        progress: Option<i32>,
+    ) -> QuestModel {
         QuestModel {
             id: self.id,
             name: self.name.clone(),
             description: self.description.clone(),
             status: self.status.clone(),
             guild_commander_id: self.guild_commander_id,
+            approval_required: self.approval_required,
+            starts_at: self.starts_at,
+            due_at: self.due_at,
+            join_deadline: self.join_deadline,
+            reward: self.reward(),
+            difficulty: self.difficulty.clone(),
+            category_id: self.category_id,
+            max_adventurers: self.max_adventurers,
+            series_id: self.series_id,
+            objectives_required: self.objectives_required,
+            version: self.version,
+            progress,
+            tags,
             adventurers_count,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
     }
+
+    pub fn to_clone_entity(&self, guild_commander_id: i32) -> AddQuestEntity {
+        AddQuestEntity {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            status: QuestStatuses::Open.to_string(),
+            guild_commander_id,
+            approval_required: self.approval_required,
+            starts_at: None,
+            due_at: None,
+            join_deadline: None,
+            reward_gold: self.reward_gold,
+            reward_xp: self.reward_xp,
+            difficulty: self.difficulty.clone(),
+            category_id: self
+                .category_id
+                .filter(|_| self.guild_commander_id == guild_commander_id),
+            max_adventurers: self.max_adventurers,
+            series_id: None,
+            series_occurrence_at: None,
+            objectives_required: self.objectives_required,
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+
+    pub fn reward(&self) -> QuestRewardModel {
+        QuestRewardModel {
+            gold: self.reward_gold,
+            xp: self.reward_xp,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Insertable, Queryable)]
@@ -39,6 +117,18 @@ pub struct AddQuestEntity {
     pub description: Option<String>,
     pub status: String,
     pub guild_commander_id: i32,
+    pub approval_required: bool,
+    pub starts_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+    pub reward_gold: i32,
+    pub reward_xp: i32,
+    pub difficulty: String,
+    pub category_id: Option<i32>,
+    pub max_adventurers: i32,
+    pub series_id: Option<i32>,
+    pub series_occurrence_at: Option<NaiveDateTime>,
+    pub objectives_required: bool,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
@@ -48,6 +138,62 @@ pub struct AddQuestEntity {
 pub struct EditQuestEntity {
     pub name: Option<String>,
     pub description: Option<String>,
-    pub guild_commander_id: i32,
+    pub approval_required: Option<bool>,
+    pub starts_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+    pub reward_gold: Option<i32>,
+    pub reward_xp: Option<i32>,
+    pub difficulty: Option<String>,
+    pub category_id: Option<i32>,
+    pub max_adventurers: Option<i32>,
+    pub objectives_required: Option<bool>,
     pub updated_at: NaiveDateTime,
 }
+
+#[derive(Debug, Clone)]
+pub struct QuestExportEntity {
+    pub quest: QuestEntity,
+    pub adventurers_count: i64,
+    pub tags: Vec<String>,
+    pub objectives: Vec<QuestObjectiveEntity>,
+    pub requirements: Option<QuestRequirementEntity>,
+    pub crew: Vec<CrewMemberModel>,
+    pub status_histories: Vec<QuestStatusHistoryEntity>,
+}
+
+impl QuestExportEntity {
+    pub fn to_model(&self) -> Result<QuestExportModel> {
+        Ok(QuestExportModel {
+            quest: self.quest.to_model(
+                self.adventurers_count,
+                self.tags.clone(),
+                objectives_progress(&self.objectives),
+            ),
+            objectives: self
+                .objectives
+                .iter()
+                .map(|objective| objective.to_model())
+                .collect(),
+            requirements: self
+                .requirements
+                .as_ref()
+                .map(|requirements| requirements.to_model())
+                .transpose()?,
+            crew: self.crew.clone(),
+            status_history: self
+                .status_histories
+                .iter()
+                .map(|history| history.to_model())
+                .collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportQuestEntity {
+    pub quest: AddQuestEntity,
+    pub tags: Vec<String>,
+    pub objectives: Vec<(i32, AddQuestObjectiveModel)>,
+    pub requirements: Option<QuestRequirementsModel>,
+}
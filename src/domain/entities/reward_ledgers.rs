@@ -0,0 +1,27 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::postgres::schema::reward_ledgers;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = reward_ledgers)]
+pub struct RewardLedgerEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub quest_status: String,
+    pub gold: i32,
+    pub xp: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = reward_ledgers)]
+pub struct AddRewardLedgerEntity {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub quest_status: String,
+    pub gold: i32,
+    pub xp: i32,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,68 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::webhook_model::WebhookDeliveryModel,
+    infrastructure::postgres::schema::webhook_deliveries,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = webhook_deliveries)]
+pub struct WebhookDeliveryEntity {
+    pub id: i32,
+    pub webhook_id: i32,
+    pub event_kind: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: NaiveDateTime,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub idempotency_key: Option<String>,
+}
+
+impl WebhookDeliveryEntity {
+    pub fn to_model(&self) -> WebhookDeliveryModel {
+        WebhookDeliveryModel {
+            id: self.id,
+            webhook_id: self.webhook_id,
+            event_kind: self.event_kind.clone(),
+            payload: self.payload.clone(),
+            status: self.status.clone(),
+            attempts: self.attempts,
+            next_attempt_at: self.next_attempt_at,
+            last_status_code: self.last_status_code,
+            last_error: self.last_error.clone(),
+            delivered_at: self.delivered_at,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = webhook_deliveries)]
+pub struct AddWebhookDeliveryEntity {
+    pub webhook_id: i32,
+    pub event_kind: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub next_attempt_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub idempotency_key: Option<String>,
+}
+
+#[derive(Debug, Clone, AsChangeset, Queryable)]
+#[diesel(table_name = webhook_deliveries, treat_none_as_null = true)]
+pub struct UpdateWebhookDeliveryEntity {
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: NaiveDateTime,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<NaiveDateTime>,
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,69 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{quest_event_model::QuestEventKinds, webhook_model::WebhookModel},
+    infrastructure::postgres::schema::webhooks,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = webhooks)]
+pub struct WebhookEntity {
+    pub id: i32,
+    pub guild_commander_id: i32,
+    pub url: String,
+    pub secret: String,
+    pub events: serde_json::Value,
+    pub active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl WebhookEntity {
+    pub fn to_model(&self) -> Result<WebhookModel> {
+        Ok(WebhookModel {
+            id: self.id,
+            guild_commander_id: self.guild_commander_id,
+            url: self.url.clone(),
+            events: self.events()?,
+            active: self.active,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+
+    pub fn events(&self) -> Result<Vec<QuestEventKinds>> {
+        let events = serde_json::from_value(self.events.clone())?;
+
+        Ok(events)
+    }
+
+    pub fn subscribes_to(&self, kind: QuestEventKinds) -> Result<bool> {
+        let events = self.events()?;
+
+        Ok(events.is_empty() || events.contains(&kind))
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = webhooks)]
+pub struct AddWebhookEntity {
+    pub guild_commander_id: i32,
+    pub url: String,
+    pub secret: String,
+    pub events: serde_json::Value,
+    pub active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, AsChangeset, Queryable)]
+#[diesel(table_name = webhooks)]
+pub struct EditWebhookEntity {
+    pub url: Option<String>,
+    pub secret: Option<String>,
+    pub events: Option<serde_json::Value>,
+    pub active: Option<bool>,
+    pub updated_at: NaiveDateTime,
+}
@@ -10,4 +10,7 @@ use crate::domain::entities::adventurers::{AdventurerEntity, RegisterAdventurerE
 pub trait AdventurersRepository {
     async fn register(&self, register_adventurer_entity: RegisterAdventurerEntity) -> Result<i32>;
     async fn find_by_username(&self, username: String) -> Result<AdventurerEntity>;
+    async fn find_by_id(&self, adventurer_id: i32) -> Result<AdventurerEntity>;
+    async fn skills(&self, adventurer_id: i32) -> Result<Vec<String>>;
+    async fn replace_skills(&self, adventurer_id: i32, skills: Vec<String>) -> Result<()>;
 }
@@ -2,11 +2,44 @@ use anyhow::Result;
 use axum::async_trait;
 use mockall::automock;
 
-use crate::domain::value_objects::quest_adventurer_junction::QuestAdventurerJunction;
+use crate::domain::{
+    entities::quest_objectives::QuestObjectiveEntity,
+    value_objects::{
+        crew_membership_statuses::CrewMembershipStatuses,
+        eligibility_model::AdventurerStandingModel,
+        quest_adventurer_junction::QuestAdventurerJunction,
+        waitlist_model::WaitlistCandidatesModel,
+    },
+};
 
 #[async_trait]
 #[automock]
 pub trait CrewSwitchboardRepository {
     async fn join(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
-    async fn leave(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
+    async fn leave(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        waitlist_candidates: WaitlistCandidatesModel,
+    ) -> Result<()>;
+    async fn request_to_join(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
+    async fn invite(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
+    async fn approve(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
+    async fn membership_status(
+        &self,
+        junction_body: QuestAdventurerJunction,
+    ) -> Result<Option<CrewMembershipStatuses>>;
+    async fn join_waitlist(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
+    async fn leave_waitlist(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
+    async fn waitlist_adventurer_ids(&self, quest_id: i32) -> Result<Vec<i32>>;
+    async fn waitlist_position(
+        &self,
+        junction_body: QuestAdventurerJunction,
+    ) -> Result<Option<i64>>;
+    async fn adventurer_standing(&self, adventurer_id: i32) -> Result<AdventurerStandingModel>;
+    async fn complete_objective(
+        &self,
+        objective_id: i32,
+        adventurer_id: i32,
+    ) -> Result<QuestObjectiveEntity>;
+    async fn reopen_objective(&self, objective_id: i32) -> Result<QuestObjectiveEntity>;
 }
@@ -2,10 +2,31 @@ use anyhow::Result;
 use axum::async_trait;
 use mockall::automock;
 
+use crate::domain::value_objects::{
+    quest_bulk_model::QuestTransitionModel, quest_reward_model::QuestRewardModel,
+};
+
 #[async_trait]
 #[automock]
 pub trait JourneyLedgerRepository {
     async fn in_journey(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32>;
-    async fn to_completed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32>;
-    async fn to_failed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32>;
+    async fn to_completed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        reward: QuestRewardModel,
+    ) -> Result<i32>;
+    async fn to_failed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        reward: QuestRewardModel,
+    ) -> Result<i32>;
+    async fn transition_many(
+        &self,
+        transitions: Vec<QuestTransitionModel>,
+        guild_commander_id: i32,
+        all_or_nothing: bool,
+    ) -> Result<Vec<Result<i32>>>;
+    async fn to_failed_by_system(&self, quest_id: i32, reward: QuestRewardModel) -> Result<i32>;
 }
@@ -0,0 +1,20 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::value_objects::{
+    leaderboard_model::LeaderboardStatsModel, leaderboard_windows::LeaderboardWindows,
+};
+
+#[async_trait]
+#[automock]
+pub trait LeaderboardsRepository {
+    async fn adventurers_stats(
+        &self,
+        window: LeaderboardWindows,
+    ) -> Result<Vec<LeaderboardStatsModel>>;
+    async fn guild_commanders_stats(
+        &self,
+        window: LeaderboardWindows,
+    ) -> Result<Vec<LeaderboardStatsModel>>;
+}
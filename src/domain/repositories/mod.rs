@@ -2,5 +2,15 @@ pub mod adventurers;
 pub mod crew_switchboard;
 pub mod guild_commanders;
 pub mod journey_ledger;
+pub mod leaderboards;
+pub mod notifications;
+pub mod outbox;
+pub mod quest_categories;
+pub mod quest_comments;
+pub mod quest_event_handler;
 pub mod quest_ops;
+pub mod quest_series;
+pub mod quest_templates;
 pub mod quest_viewing;
+pub mod webhook_sender;
+pub mod webhooks;
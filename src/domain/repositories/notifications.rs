@@ -0,0 +1,30 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::notifications::{AddNotificationEntity, NotificationEntity};
+
+#[async_trait]
+#[automock]
+pub trait NotificationsRepository {
+    async fn add(&self, add_notification_entities: Vec<AddNotificationEntity>) -> Result<usize>;
+    async fn notifications_by_recipient(
+        &self,
+        recipient_role: String,
+        recipient_id: i32,
+        unread_only: bool,
+        limit: i64,
+    ) -> Result<Vec<NotificationEntity>>;
+    async fn unread_counting_by_recipient(
+        &self,
+        recipient_role: String,
+        recipient_id: i32,
+    ) -> Result<i64>;
+    async fn mark_read(
+        &self,
+        notification_id: i32,
+        recipient_role: String,
+        recipient_id: i32,
+    ) -> Result<i32>;
+    async fn mark_all_read(&self, recipient_role: String, recipient_id: i32) -> Result<usize>;
+}
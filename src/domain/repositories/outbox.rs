@@ -0,0 +1,28 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::entities::outbox_events::OutboxEventEntity;
+
+#[async_trait]
+#[automock]
+pub trait OutboxRepository {
+    async fn pending_events(
+        &self,
+        now: NaiveDateTime,
+        claimed_until: NaiveDateTime,
+        limit: i64,
+    ) -> Result<Vec<OutboxEventEntity>>;
+    async fn handlers_by_outbox_event_id(&self, outbox_event_id: i32) -> Result<Vec<String>>;
+    async fn mark_handled(&self, outbox_event_id: i32, handler: String) -> Result<()>;
+    async fn mark_processed(&self, outbox_event_id: i32, processed_at: NaiveDateTime)
+        -> Result<()>;
+    async fn mark_failed(
+        &self,
+        outbox_event_id: i32,
+        last_error: String,
+        next_attempt_at: NaiveDateTime,
+    ) -> Result<()>;
+    async fn mark_dead_lettered(&self, outbox_event_id: i32, last_error: String) -> Result<()>;
+}
@@ -0,0 +1,21 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::quest_categories::{
+    AddQuestCategoryEntity, EditQuestCategoryEntity, QuestCategoryEntity,
+};
+
+#[async_trait]
+#[automock]
+pub trait QuestCategoriesRepository {
+    async fn add(&self, add_quest_category_entity: AddQuestCategoryEntity) -> Result<i32>;
+    async fn edit(
+        &self,
+        category_id: i32,
+        edit_quest_category_entity: EditQuestCategoryEntity,
+    ) -> Result<i32>;
+    async fn remove(&self, category_id: i32) -> Result<()>;
+    async fn view_details(&self, category_id: i32) -> Result<QuestCategoryEntity>;
+    async fn list(&self) -> Result<Vec<QuestCategoryEntity>>;
+}
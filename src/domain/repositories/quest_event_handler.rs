@@ -0,0 +1,12 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::value_objects::outbox_event_model::OutboxEventModel;
+
+#[async_trait]
+#[automock]
+pub trait QuestEventHandler {
+    fn name(&self) -> &'static str;
+    async fn handle(&self, outbox_event: &OutboxEventModel) -> Result<()>;
+}
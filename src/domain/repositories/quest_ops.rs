@@ -2,12 +2,54 @@ use anyhow::Result;
 use axum::async_trait;
 use mockall::automock;
 
-use crate::domain::entities::quests::{AddQuestEntity, EditQuestEntity};
+use crate::domain::entities::{
+    quest_dependencies::QuestDependencyEntity,
+    quest_objectives::{AddQuestObjectiveEntity, EditQuestObjectiveEntity},
+    quest_requirements::UpsertQuestRequirementEntity,
+    quests::{AddQuestEntity, EditQuestEntity, ImportQuestEntity},
+};
 
 #[async_trait]
 #[automock]
 pub trait QuestOpsRepository {
-    async fn add(&self, add_quest_entity: AddQuestEntity) -> Result<i32>;
-    async fn edit(&self, quest_id: i32, edit_quest_entity: EditQuestEntity) -> Result<i32>;
+    async fn add(&self, add_quest_entity: AddQuestEntity, tags: Vec<String>) -> Result<i32>;
+    async fn edit(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        expected_version: i32,
+        edit_quest_entity: EditQuestEntity,
+        tags: Option<Vec<String>>,
+    ) -> Result<i32>;
+    async fn add_many(
+        &self,
+        quests: Vec<(AddQuestEntity, Vec<String>)>,
+        all_or_nothing: bool,
+    ) -> Result<Vec<Result<i32>>>;
+    async fn import_many(&self, quests: Vec<ImportQuestEntity>) -> Result<Vec<Result<i32>>>;
+    async fn clone_quest(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32>;
     async fn remove(&self, quest_id: i32, guild_commander_id: i32) -> Result<()>;
+    async fn remove_many(
+        &self,
+        quest_ids: Vec<i32>,
+        guild_commander_id: i32,
+        all_or_nothing: bool,
+    ) -> Result<Vec<Result<i32>>>;
+    async fn upsert_requirements(
+        &self,
+        upsert_quest_requirement_entity: UpsertQuestRequirementEntity,
+    ) -> Result<i32>;
+    async fn remove_requirements(&self, quest_id: i32) -> Result<()>;
+    async fn add_objective(
+        &self,
+        add_quest_objective_entity: AddQuestObjectiveEntity,
+    ) -> Result<i32>;
+    async fn edit_objective(
+        &self,
+        objective_id: i32,
+        edit_quest_objective_entity: EditQuestObjectiveEntity,
+    ) -> Result<i32>;
+    async fn remove_objective(&self, objective_id: i32) -> Result<()>;
+    async fn add_dependency(&self, quest_dependency_entity: QuestDependencyEntity) -> Result<()>;
+    async fn remove_dependency(&self, quest_id: i32, prerequisite_id: i32) -> Result<()>;
 }
@@ -0,0 +1,39 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::entities::{
+    quest_series::{AddQuestSeriesEntity, EditQuestSeriesEntity, QuestSeriesEntity},
+    quests::AddQuestEntity,
+};
+
+#[async_trait]
+#[automock]
+pub trait QuestSeriesRepository {
+    async fn add(&self, add_quest_series_entity: AddQuestSeriesEntity) -> Result<i32>;
+    async fn edit(
+        &self,
+        series_id: i32,
+        expected_updated_at: NaiveDateTime,
+        edit_quest_series_entity: EditQuestSeriesEntity,
+        add_quest_entities: Vec<AddQuestEntity>,
+        materialized_until: Option<NaiveDateTime>,
+        now: NaiveDateTime,
+    ) -> Result<i32>;
+    async fn update_status(&self, series_id: i32, status: String) -> Result<i32>;
+    async fn view_details(&self, series_id: i32) -> Result<QuestSeriesEntity>;
+    async fn series_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestSeriesEntity>>;
+    async fn active_series(&self) -> Result<Vec<QuestSeriesEntity>>;
+    async fn materialize(
+        &self,
+        series_id: i32,
+        add_quest_entities: Vec<AddQuestEntity>,
+        tags: Vec<String>,
+        materialized_until: NaiveDateTime,
+    ) -> Result<Vec<i32>>;
+    async fn discard_pending_quests(&self, series_id: i32, now: NaiveDateTime) -> Result<Vec<i32>>;
+}
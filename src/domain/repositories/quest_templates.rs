@@ -0,0 +1,24 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::quest_templates::{
+    AddQuestTemplateEntity, EditQuestTemplateEntity, QuestTemplateEntity,
+};
+
+#[async_trait]
+#[automock]
+pub trait QuestTemplatesRepository {
+    async fn add(&self, add_quest_template_entity: AddQuestTemplateEntity) -> Result<i32>;
+    async fn edit(
+        &self,
+        template_id: i32,
+        edit_quest_template_entity: EditQuestTemplateEntity,
+    ) -> Result<i32>;
+    async fn remove(&self, template_id: i32) -> Result<()>;
+    async fn view_details(&self, template_id: i32) -> Result<QuestTemplateEntity>;
+    async fn templates_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestTemplateEntity>>;
+}
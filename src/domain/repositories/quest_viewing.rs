@@ -1,9 +1,20 @@
 use anyhow::Result;
 use axum::async_trait;
+use chrono::NaiveDateTime;
 use mockall::automock;
 
 use crate::domain::{
-    entities::quests::QuestEntity, value_objects::board_checking_filter::BoardCheckingFilter,
+    entities::{
+        quest_dependencies::QuestDependencyEntity,
+        quest_objectives::QuestObjectiveEntity,
+        quest_requirements::QuestRequirementEntity,
+        quest_status_histories::QuestStatusHistoryEntity,
+        quests::{QuestEntity, QuestExportEntity},
+    },
+    value_objects::{
+        board_checking_filter::BoardCheckingFilter, crew_member_model::CrewMemberModel,
+        crew_membership_statuses::CrewMembershipStatuses,
+    },
 };
 
 #[async_trait]
@@ -12,4 +23,32 @@ pub trait QuestViewingRepository {
     async fn view_details(&self, quest_id: i32) -> Result<QuestEntity>;
     async fn board_checking(&self, filter: &BoardCheckingFilter) -> Result<Vec<QuestEntity>>;
     async fn adventurers_counting_by_quest_id(&self, quest_id: i32) -> Result<i64>;
+    async fn tags_by_quest_id(&self, quest_id: i32) -> Result<Vec<String>>;
+    async fn objectives_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestObjectiveEntity>>;
+    async fn prerequisites_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestEntity>>;
+    async fn chain_dependencies(&self, quest_id: i32) -> Result<Vec<QuestDependencyEntity>>;
+    async fn quests_by_ids(&self, quest_ids: Vec<i32>) -> Result<Vec<QuestEntity>>;
+    async fn requirements_by_quest_id(
+        &self,
+        quest_id: i32,
+    ) -> Result<Option<QuestRequirementEntity>>;
+    async fn crew_by_quest_id(
+        &self,
+        quest_id: i32,
+        status: CrewMembershipStatuses,
+    ) -> Result<Vec<CrewMemberModel>>;
+    async fn status_histories_by_quest_id(
+        &self,
+        quest_id: i32,
+    ) -> Result<Vec<QuestStatusHistoryEntity>>;
+    async fn joined_quests_by_adventurer_id(&self, adventurer_id: i32) -> Result<Vec<QuestEntity>>;
+    async fn owned_quests_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestEntity>>;
+    async fn quest_exports_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestExportEntity>>;
+    async fn overdue_quests(&self, now: NaiveDateTime) -> Result<Vec<QuestEntity>>;
 }
@@ -0,0 +1,11 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::value_objects::webhook_model::WebhookRequestModel;
+
+#[async_trait]
+#[automock]
+pub trait WebhookSender {
+    async fn send(&self, webhook_request: WebhookRequestModel) -> Result<u16>;
+}
@@ -0,0 +1,45 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::entities::{
+    webhook_deliveries::{
+        AddWebhookDeliveryEntity, UpdateWebhookDeliveryEntity, WebhookDeliveryEntity,
+    },
+    webhooks::{AddWebhookEntity, EditWebhookEntity, WebhookEntity},
+};
+
+#[async_trait]
+#[automock]
+pub trait WebhooksRepository {
+    async fn add(&self, add_webhook_entity: AddWebhookEntity) -> Result<i32>;
+    async fn edit(&self, webhook_id: i32, edit_webhook_entity: EditWebhookEntity) -> Result<i32>;
+    async fn remove(&self, webhook_id: i32) -> Result<()>;
+    async fn view_details(&self, webhook_id: i32) -> Result<WebhookEntity>;
+    async fn webhooks_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<WebhookEntity>>;
+    async fn active_webhooks_by_quest_id(&self, quest_id: i32) -> Result<Vec<WebhookEntity>>;
+    async fn enqueue_deliveries(
+        &self,
+        add_webhook_delivery_entities: Vec<AddWebhookDeliveryEntity>,
+    ) -> Result<usize>;
+    async fn due_deliveries(
+        &self,
+        now: NaiveDateTime,
+        claimed_until: NaiveDateTime,
+        limit: i64,
+    ) -> Result<Vec<(WebhookDeliveryEntity, WebhookEntity)>>;
+    async fn update_delivery(
+        &self,
+        delivery_id: i32,
+        update_webhook_delivery_entity: UpdateWebhookDeliveryEntity,
+    ) -> Result<i32>;
+    async fn deliveries_by_webhook_id(
+        &self,
+        webhook_id: i32,
+        limit: i64,
+    ) -> Result<Vec<WebhookDeliveryEntity>>;
+}
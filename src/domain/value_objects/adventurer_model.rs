@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::domain::entities::adventurers::RegisterAdventurerEntity;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RegisterAdventurerModel {
     pub username: String,
     pub password: String,
@@ -18,3 +19,19 @@ impl RegisterAdventurerModel {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AdventurerProfileModel {
+    pub id: i32,
+    pub username: String,
+    pub gold: i64,
+    pub xp: i64,
+    pub level: i32,
+    pub xp_to_next_level: i64,
+    pub skills: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AdventurerSkillsModel {
+    pub skills: Vec<String>,
+}
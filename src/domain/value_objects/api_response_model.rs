@@ -0,0 +1,95 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{ValidationErrors, ValidationErrorsKind};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct MessageModel {
+    pub message: String,
+}
+
+impl MessageModel {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct CreatedModel {
+    pub id: i32,
+    pub message: String,
+}
+
+impl CreatedModel {
+    pub fn new(id: i32, message: impl Into<String>) -> Self {
+        Self {
+            id,
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ErrorModel {
+    pub error: String,
+}
+
+impl ErrorModel {
+    pub fn new(error: impl fmt::Display) -> Self {
+        Self {
+            error: error.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ValidationErrorModel {
+    pub error: String,
+    pub fields: BTreeMap<String, Vec<String>>,
+}
+
+impl From<&ValidationErrors> for ValidationErrorModel {
+    fn from(errors: &ValidationErrors) -> Self {
+        let mut fields = BTreeMap::new();
+        collect_field_errors(&mut fields, "", errors);
+
+        Self {
+            error: "Validation failed".to_string(),
+            fields,
+        }
+    }
+}
+
+fn collect_field_errors(
+    fields: &mut BTreeMap<String, Vec<String>>,
+    prefix: &str,
+    errors: &ValidationErrors,
+) {
+    for (field, kind) in errors.errors().iter() {
+        let path = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", prefix, field)
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                let messages = errors.iter().map(|error| match &error.message {
+                    Some(message) => message.to_string(),
+                    None => error.code.to_string(),
+                });
+
+                fields.entry(path).or_default().extend(messages);
+            }
+            ValidationErrorsKind::Struct(errors) => collect_field_errors(fields, &path, errors),
+            ValidationErrorsKind::List(items) => {
+                for (index, errors) in items.iter() {
+                    collect_field_errors(fields, &format!("{}[{}]", path, index), errors);
+                }
+            }
+        }
+    }
+}
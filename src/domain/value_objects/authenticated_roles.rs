@@ -0,0 +1,36 @@
+use std::fmt;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum AuthenticatedRoles {
+    Adventurer,
+    GuildCommander,
+}
+
+impl fmt::Display for AuthenticatedRoles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthenticatedRoles::Adventurer => write!(f, "Adventurer"),
+            AuthenticatedRoles::GuildCommander => write!(f, "GuildCommander"),
+        }
+    }
+}
+
+impl AuthenticatedRoles {
+    pub fn try_from(role: &str) -> Result<Self> {
+        match role {
+            "Adventurer" => Ok(AuthenticatedRoles::Adventurer),
+            "GuildCommander" => Ok(AuthenticatedRoles::GuildCommander),
+            _ => Err(anyhow::anyhow!("Invalid authenticated role")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct AuthenticatedIdentity {
+    pub role: AuthenticatedRoles,
+    pub id: i32,
+}
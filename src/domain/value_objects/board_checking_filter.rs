@@ -1,9 +1,14 @@
 use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
 
-use super::quest_statuses::QuestStatuses;
+use super::{quest_difficulties::QuestDifficulties, quest_statuses::QuestStatuses};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct BoardCheckingFilter {
     pub name: Option<String>,
     pub status: Option<QuestStatuses>,
+    pub difficulty: Option<QuestDifficulties>,
+    pub category_id: Option<i32>,
+    pub tag: Option<String>,
 }
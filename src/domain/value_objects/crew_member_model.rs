@@ -0,0 +1,20 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::crew_membership_statuses::CrewMembershipStatuses;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, ToSchema)]
+pub struct CrewMemberModel {
+    pub id: i32,
+    pub username: String,
+    pub joined_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CrewMembershipModel {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub status: Option<CrewMembershipStatuses>,
+}
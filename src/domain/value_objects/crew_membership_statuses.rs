@@ -0,0 +1,34 @@
+use std::fmt;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum CrewMembershipStatuses {
+    #[default]
+    Joined,
+    Pending,
+    Invited,
+}
+
+impl fmt::Display for CrewMembershipStatuses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrewMembershipStatuses::Joined => write!(f, "Joined"),
+            CrewMembershipStatuses::Pending => write!(f, "Pending"),
+            CrewMembershipStatuses::Invited => write!(f, "Invited"),
+        }
+    }
+}
+
+impl CrewMembershipStatuses {
+    pub fn try_from(status: &str) -> Result<Self> {
+        match status {
+            "Joined" => Ok(CrewMembershipStatuses::Joined),
+            "Pending" => Ok(CrewMembershipStatuses::Pending),
+            "Invited" => Ok(CrewMembershipStatuses::Invited),
+            _ => Err(anyhow::anyhow!("Invalid crew membership status")),
+        }
+    }
+}
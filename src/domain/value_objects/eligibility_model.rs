@@ -0,0 +1,54 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct EligibilityCandidateModel {
+    pub adventurer_id: i32,
+    pub level: i32,
+    pub completed_quests: i64,
+    pub skills: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct AdventurerStandingModel {
+    pub adventurer_id: i32,
+    pub xp: i64,
+    pub completed_quests: i64,
+    pub skills: Vec<String>,
+}
+
+impl AdventurerStandingModel {
+    pub fn to_candidate(&self, level: i32) -> EligibilityCandidateModel {
+        EligibilityCandidateModel {
+            adventurer_id: self.adventurer_id,
+            level,
+            completed_quests: self.completed_quests,
+            skills: self.skills.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct IneligibilityModel {
+    pub message: String,
+    pub failed_rules: Vec<String>,
+}
+
+impl IneligibilityModel {
+    pub fn new(failed_rules: Vec<String>) -> Self {
+        Self {
+            message: "Adventurer is not eligible for this quest".to_string(),
+            failed_rules,
+        }
+    }
+}
+
+impl fmt::Display for IneligibilityModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.message, self.failed_rules.join(", "))
+    }
+}
+
+impl std::error::Error for IneligibilityModel {}
@@ -0,0 +1,155 @@
+use std::borrow::Cow;
+
+use validator::ValidationError;
+
+pub const MAX_QUEST_NAME_LENGTH: u64 = 255;
+pub const MAX_QUEST_DESCRIPTION_LENGTH: u64 = 10_000;
+
+const STRIPPED_BLOCK_TAGS: [&str; 5] = ["script", "style", "iframe", "object", "embed"];
+const UNSAFE_LINK_SCHEMES: [&str; 3] = ["javascript:", "vbscript:", "data:"];
+
+pub trait Sanitize {
+    fn sanitize(&mut self);
+}
+
+pub fn forbid_markup_characters(value: &str) -> Result<(), ValidationError> {
+    if value
+        .chars()
+        .any(|c| c.is_control() || matches!(c, '<' | '>'))
+    {
+        return Err(
+            ValidationError::new("forbidden_characters").with_message(Cow::from(
+                "Must not contain control characters or angle brackets",
+            )),
+        );
+    }
+
+    Ok(())
+}
+
+pub fn sanitize_markdown(markdown: &str) -> String {
+    let normalized = markdown
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .chars()
+        .filter(|c| !c.is_control() || matches!(c, '\n' | '\t'))
+        .collect::<String>();
+
+    neutralize_unsafe_links(&strip_html_tags(&normalized))
+        .trim()
+        .to_string()
+}
+
+fn strip_html_tags(markdown: &str) -> String {
+    let mut sanitized = String::with_capacity(markdown.len());
+    let mut rest = markdown;
+
+    while let Some(start) = rest.find('<') {
+        sanitized.push_str(&rest[..start]);
+        let candidate = &rest[start..];
+
+        let Some(end) = candidate.find('>') else {
+            sanitized.push_str(candidate);
+            return sanitized;
+        };
+
+        let inner = &candidate[1..end];
+        let tag_name = inner
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        let is_tag = inner.starts_with('!')
+            || inner
+                .trim_start_matches('/')
+                .starts_with(|c: char| c.is_ascii_alphabetic());
+
+        if !is_tag || is_autolink(inner) {
+            sanitized.push('<');
+            rest = &candidate[1..];
+            continue;
+        }
+
+        rest = &candidate[end + 1..];
+
+        if !inner.starts_with('/') && STRIPPED_BLOCK_TAGS.contains(&tag_name.as_str()) {
+            let closing_tag = format!("</{}", tag_name);
+            rest = match rest.to_ascii_lowercase().find(&closing_tag) {
+                Some(closing) => match rest[closing..].find('>') {
+                    Some(closing_end) => &rest[closing + closing_end + 1..],
+                    None => "",
+                },
+                None => "",
+            };
+        }
+    }
+
+    sanitized.push_str(rest);
+    sanitized
+}
+
+fn is_autolink(inner: &str) -> bool {
+    if inner.is_empty() || inner.contains(char::is_whitespace) {
+        return false;
+    }
+
+    let lowercase = inner.to_ascii_lowercase();
+    if UNSAFE_LINK_SCHEMES
+        .iter()
+        .any(|scheme| lowercase.starts_with(scheme))
+    {
+        return false;
+    }
+
+    match inner.split_once(':') {
+        Some((scheme, _)) => {
+            !scheme.is_empty()
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '-'))
+        }
+        None => inner.contains('@'),
+    }
+}
+
+fn neutralize_unsafe_links(markdown: &str) -> String {
+    let mut sanitized = String::with_capacity(markdown.len());
+    let mut rest = markdown;
+
+    while let Some(start) = rest.find("](") {
+        sanitized.push_str(&rest[..start + 2]);
+        rest = &rest[start + 2..];
+
+        let target = rest.trim_start().to_ascii_lowercase();
+        if UNSAFE_LINK_SCHEMES
+            .iter()
+            .any(|scheme| target.starts_with(scheme))
+        {
+            sanitized.push('#');
+            rest = match link_target_end(rest) {
+                Some(end) => &rest[end..],
+                None => "",
+            };
+        }
+    }
+
+    sanitized.push_str(rest);
+    sanitized
+}
+
+fn link_target_end(target: &str) -> Option<usize> {
+    let mut depth = 0;
+
+    for (index, c) in target.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(index),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
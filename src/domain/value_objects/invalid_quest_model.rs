@@ -0,0 +1,22 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidQuestModel {
+    pub message: String,
+}
+
+impl InvalidQuestModel {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for InvalidQuestModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for InvalidQuestModel {}
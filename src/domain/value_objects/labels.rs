@@ -0,0 +1,13 @@
+pub fn normalize_labels(labels: Vec<String>) -> Vec<String> {
+    let mut normalized_labels: Vec<String> = Vec::new();
+
+    for label in labels.into_iter() {
+        let label = label.trim().to_lowercase();
+
+        if !label.is_empty() && !normalized_labels.contains(&label) {
+            normalized_labels.push(label);
+        }
+    }
+
+    normalized_labels
+}
@@ -0,0 +1,73 @@
+use diesel::{
+    prelude::*,
+    sql_types::{BigInt, Integer, Varchar},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::leaderboard_windows::LeaderboardWindows;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum LeaderboardSorts {
+    #[default]
+    Completed,
+    SuccessRate,
+    Xp,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LeaderboardFilter {
+    pub window: Option<LeaderboardWindows>,
+    pub sort_by: Option<LeaderboardSorts>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, QueryableByName)]
+pub struct LeaderboardStatsModel {
+    #[diesel(sql_type = Integer)]
+    pub id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub username: String,
+    #[diesel(sql_type = BigInt)]
+    pub completed_quests: i64,
+    #[diesel(sql_type = BigInt)]
+    pub failed_quests: i64,
+    #[diesel(sql_type = BigInt)]
+    pub xp: i64,
+}
+
+impl LeaderboardStatsModel {
+    pub fn success_rate(&self) -> f64 {
+        let finished_quests = self.completed_quests + self.failed_quests;
+
+        if finished_quests == 0 {
+            return 0.0;
+        }
+
+        self.completed_quests as f64 / finished_quests as f64
+    }
+
+    pub fn to_entry(&self, rank: i64) -> LeaderboardEntryModel {
+        LeaderboardEntryModel {
+            rank,
+            id: self.id,
+            username: self.username.clone(),
+            completed_quests: self.completed_quests,
+            failed_quests: self.failed_quests,
+            success_rate: self.success_rate(),
+            xp: self.xp,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct LeaderboardEntryModel {
+    pub rank: i64,
+    pub id: i32,
+    pub username: String,
+    pub completed_quests: i64,
+    pub failed_quests: i64,
+    pub success_rate: f64,
+    pub xp: i64,
+}
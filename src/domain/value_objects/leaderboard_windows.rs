@@ -0,0 +1,43 @@
+use std::fmt;
+
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum LeaderboardWindows {
+    Week,
+    Month,
+    #[default]
+    AllTime,
+}
+
+impl fmt::Display for LeaderboardWindows {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeaderboardWindows::Week => write!(f, "Week"),
+            LeaderboardWindows::Month => write!(f, "Month"),
+            LeaderboardWindows::AllTime => write!(f, "AllTime"),
+        }
+    }
+}
+
+impl LeaderboardWindows {
+    pub fn try_from(window: &str) -> Result<Self> {
+        match window {
+            "Week" => Ok(LeaderboardWindows::Week),
+            "Month" => Ok(LeaderboardWindows::Month),
+            "AllTime" => Ok(LeaderboardWindows::AllTime),
+            _ => Err(anyhow::anyhow!("Invalid leaderboard window")),
+        }
+    }
+
+    pub fn since(&self, now: NaiveDateTime) -> NaiveDateTime {
+        match self {
+            LeaderboardWindows::Week => now - Duration::days(7),
+            LeaderboardWindows::Month => now - Duration::days(30),
+            LeaderboardWindows::AllTime => NaiveDateTime::default(),
+        }
+    }
+}
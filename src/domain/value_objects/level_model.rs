@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct LevelModel {
+    pub level: i32,
+    pub xp_to_next_level: i64,
+}
+
+impl LevelModel {
+    pub fn from_xp(xp: i64, base_xp: i64, exponent: f64) -> Self {
+        let mut level = 1;
+        let mut remaining_xp = xp.max(0);
+
+        loop {
+            let required_xp = ((base_xp as f64) * (level as f64).powf(exponent))
+                .round()
+                .max(1.0) as i64;
+
+            if remaining_xp < required_xp {
+                return Self {
+                    level,
+                    xp_to_next_level: required_xp - remaining_xp,
+                };
+            }
+
+            remaining_xp -= required_xp;
+            level += 1;
+        }
+    }
+}
@@ -1,6 +1,41 @@
 pub mod adventurer_model;
+pub mod api_response_model;
+pub mod authenticated_roles;
 pub mod board_checking_filter;
+pub mod crew_member_model;
+pub mod crew_membership_statuses;
+pub mod eligibility_model;
 pub mod guild_commander_model;
+pub mod input_sanitizer;
+pub mod invalid_quest_model;
+pub mod labels;
+pub mod leaderboard_model;
+pub mod leaderboard_windows;
+pub mod level_model;
+pub mod notification_model;
+pub mod outbox_event_model;
+pub mod outbox_event_statuses;
 pub mod quest_adventurer_junction;
+pub mod quest_bulk_model;
+pub mod quest_category_model;
+pub mod quest_comment_model;
+pub mod quest_dependency_model;
+pub mod quest_difficulties;
+pub mod quest_event_model;
 pub mod quest_model;
+pub mod quest_objective_model;
+pub mod quest_recurrence_model;
+pub mod quest_requirements_model;
+pub mod quest_reward_model;
+pub mod quest_series_model;
+pub mod quest_series_statuses;
+pub mod quest_status_actors;
+pub mod quest_status_history_model;
 pub mod quest_statuses;
+pub mod quest_template_model;
+pub mod quest_transfer_model;
+pub mod quest_version_model;
+pub mod waitlist_model;
+pub mod waitlist_model_test;
+pub mod webhook_delivery_statuses;
+pub mod webhook_model;
@@ -0,0 +1,54 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::entities::notifications::AddNotificationEntity;
+
+use super::{authenticated_roles::AuthenticatedIdentity, quest_event_model::QuestEventModel};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct NotificationModel {
+    pub id: i32,
+    pub quest_id: Option<i32>,
+    pub kind: String,
+    pub message: String,
+    pub read: bool,
+    pub read_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct UnreadNotificationsModel {
+    pub unread: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct NotificationFilter {
+    pub unread_only: Option<bool>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationMessageModel {
+    pub recipient: AuthenticatedIdentity,
+    pub message: String,
+}
+
+impl NotificationMessageModel {
+    pub fn to_entity(
+        &self,
+        idempotency_key: &str,
+        event: &QuestEventModel,
+    ) -> AddNotificationEntity {
+        AddNotificationEntity {
+            recipient_role: self.recipient.role.to_string(),
+            recipient_id: self.recipient.id,
+            quest_id: Some(event.quest_id),
+            kind: event.kind.to_string(),
+            message: self.message.clone(),
+            created_at: chrono::Utc::now().naive_utc(),
+            idempotency_key: Some(idempotency_key.to_string()),
+        }
+    }
+}
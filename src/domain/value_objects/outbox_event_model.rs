@@ -0,0 +1,35 @@
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::outbox_events::AddOutboxEventEntity;
+
+use super::quest_event_model::QuestEventModel;
+
+pub const OUTBOX_RETRY_BASE_SECONDS: i64 = 5;
+pub const OUTBOX_RETRY_MAX_SECONDS: i64 = 3600;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutboxEventModel {
+    pub id: i32,
+    pub idempotency_key: String,
+    pub event: QuestEventModel,
+}
+
+pub fn to_outbox_entity(event: &QuestEventModel) -> AddOutboxEventEntity {
+    AddOutboxEventEntity {
+        idempotency_key: format!("{:032x}", rand::random::<u128>()),
+        kind: event.kind.to_string(),
+        quest_id: event.quest_id,
+        payload: serde_json::json!(event),
+        created_at: chrono::Utc::now().naive_utc(),
+    }
+}
+
+pub fn outbox_retry_backoff(attempts: i32) -> Duration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    let seconds = OUTBOX_RETRY_BASE_SECONDS
+        .saturating_mul(2_i64.pow(exponent))
+        .min(OUTBOX_RETRY_MAX_SECONDS);
+
+    Duration::seconds(seconds)
+}
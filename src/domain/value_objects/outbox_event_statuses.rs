@@ -0,0 +1,33 @@
+use std::fmt;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum OutboxEventStatuses {
+    #[default]
+    Pending,
+    Processed,
+    DeadLettered,
+}
+
+impl fmt::Display for OutboxEventStatuses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutboxEventStatuses::Pending => write!(f, "Pending"),
+            OutboxEventStatuses::Processed => write!(f, "Processed"),
+            OutboxEventStatuses::DeadLettered => write!(f, "DeadLettered"),
+        }
+    }
+}
+
+impl OutboxEventStatuses {
+    pub fn try_from(status: &str) -> Result<Self> {
+        match status {
+            "Pending" => Ok(OutboxEventStatuses::Pending),
+            "Processed" => Ok(OutboxEventStatuses::Processed),
+            "DeadLettered" => Ok(OutboxEventStatuses::DeadLettered),
+            _ => Err(anyhow::anyhow!("Invalid outbox event status")),
+        }
+    }
+}
@@ -6,7 +6,8 @@ use crate::{
     infrastructure::postgres::schema::quest_adventurer_junction,
 };
 
-pub const MAX_ADVENTURERS_PER_QUEST: i64 = 4;
+pub const DEFAULT_CREW_SIZE: i32 = 4;
+pub const MAX_CREW_SIZE: i32 = 20;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Insertable, Queryable, Associations)]
 #[diesel(belongs_to(AdventurerEntity, foreign_key = adventurer_id))]
@@ -0,0 +1,210 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use super::{
+    input_sanitizer::Sanitize, quest_model::AddQuestModel, quest_reward_model::QuestRewardModel,
+    quest_statuses::QuestStatuses,
+};
+
+pub const MAX_BULK_QUESTS: u64 = 100;
+pub const ROLLED_BACK_MESSAGE: &str = "Rolled back because another quest in the batch failed";
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+pub struct BulkAddQuestsModel {
+    #[validate(
+        length(
+            min = 1,
+            max = MAX_BULK_QUESTS,
+            message = "Must contain between 1 and 100 quests"
+        ),
+        nested
+    )]
+    pub quests: Vec<AddQuestModel>,
+    pub all_or_nothing: Option<bool>,
+}
+
+impl Sanitize for BulkAddQuestsModel {
+    fn sanitize(&mut self) {
+        self.quests.iter_mut().for_each(Sanitize::sanitize);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+pub struct BulkRemoveQuestsModel {
+    #[validate(length(
+        min = 1,
+        max = MAX_BULK_QUESTS,
+        message = "Must contain between 1 and 100 quest ids"
+    ))]
+    pub quest_ids: Vec<i32>,
+    pub all_or_nothing: Option<bool>,
+}
+
+impl Sanitize for BulkRemoveQuestsModel {
+    fn sanitize(&mut self) {
+        self.quest_ids = distinct_quest_ids(&self.quest_ids);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+pub struct BulkTransitionQuestsModel {
+    pub status: QuestStatuses,
+    #[validate(length(
+        min = 1,
+        max = MAX_BULK_QUESTS,
+        message = "Must contain between 1 and 100 quest ids"
+    ))]
+    pub quest_ids: Option<Vec<i32>>,
+    pub overdue: Option<bool>,
+    pub all_or_nothing: Option<bool>,
+}
+
+impl Sanitize for BulkTransitionQuestsModel {
+    fn sanitize(&mut self) {
+        self.quest_ids = self.quest_ids.as_deref().map(distinct_quest_ids);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuestTransitionModel {
+    pub quest_id: i32,
+    pub status: QuestStatuses,
+    pub reward: QuestRewardModel,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum BulkItemStatuses {
+    Succeeded,
+    Failed,
+    RolledBack,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct BulkItemResultModel {
+    pub index: usize,
+    pub quest_id: Option<i32>,
+    pub status: BulkItemStatuses,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct BulkResultModel {
+    pub committed: bool,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub results: Vec<BulkItemResultModel>,
+}
+
+pub struct BulkPlan<P> {
+    ready: Vec<P>,
+    checks: Vec<Result<()>>,
+    all_or_nothing: bool,
+}
+
+impl<P> BulkPlan<P> {
+    pub fn new(prepared: Vec<Result<P>>, all_or_nothing: bool) -> Self {
+        let mut ready = Vec::new();
+        let mut checks = Vec::new();
+
+        for item in prepared.into_iter() {
+            match item {
+                Ok(item) => {
+                    ready.push(item);
+                    checks.push(Ok(()));
+                }
+                Err(e) => checks.push(Err(e)),
+            }
+        }
+
+        Self {
+            ready,
+            checks,
+            all_or_nothing,
+        }
+    }
+
+    pub fn all_or_nothing(&self) -> bool {
+        self.all_or_nothing
+    }
+
+    pub fn take_executable(&mut self) -> Option<Vec<P>> {
+        let rejected = self.checks.iter().any(Result::is_err);
+
+        if self.ready.is_empty() || (self.all_or_nothing && rejected) {
+            return None;
+        }
+
+        Some(std::mem::take(&mut self.ready))
+    }
+
+    pub fn finish(
+        self,
+        requested_quest_ids: Vec<Option<i32>>,
+        executed: Vec<Result<i32>>,
+    ) -> BulkResultModel {
+        let mut executed = executed.into_iter();
+
+        let outcomes = self
+            .checks
+            .into_iter()
+            .map(|check| match check {
+                Ok(()) => executed.next().transpose(),
+                Err(e) => Err(e),
+            })
+            .collect::<Vec<_>>();
+
+        let committed = !self.all_or_nothing || outcomes.iter().all(Result::is_ok);
+
+        let results = outcomes
+            .into_iter()
+            .zip(requested_quest_ids)
+            .enumerate()
+            .map(|(index, (outcome, requested_quest_id))| match outcome {
+                Ok(Some(quest_id)) if committed => BulkItemResultModel {
+                    index,
+                    quest_id: Some(quest_id),
+                    status: BulkItemStatuses::Succeeded,
+                    error: None,
+                },
+                Ok(_) => BulkItemResultModel {
+                    index,
+                    quest_id: requested_quest_id,
+                    status: BulkItemStatuses::RolledBack,
+                    error: Some(ROLLED_BACK_MESSAGE.to_string()),
+                },
+                Err(e) => BulkItemResultModel {
+                    index,
+                    quest_id: requested_quest_id,
+                    status: BulkItemStatuses::Failed,
+                    error: Some(e.to_string()),
+                },
+            })
+            .collect::<Vec<_>>();
+
+        let succeeded = results
+            .iter()
+            .filter(|result| result.status == BulkItemStatuses::Succeeded)
+            .count();
+
+        BulkResultModel {
+            committed,
+            succeeded,
+            failed: results.len() - succeeded,
+            results,
+        }
+    }
+}
+
+fn distinct_quest_ids(quest_ids: &[i32]) -> Vec<i32> {
+    let mut distinct_quest_ids = Vec::with_capacity(quest_ids.len());
+
+    for quest_id in quest_ids.iter() {
+        if !distinct_quest_ids.contains(quest_id) {
+            distinct_quest_ids.push(*quest_id);
+        }
+    }
+
+    distinct_quest_ids
+}
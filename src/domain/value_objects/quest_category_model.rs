@@ -0,0 +1,65 @@
+use std::fmt;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::quest_categories::{AddQuestCategoryEntity, EditQuestCategoryEntity};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuestCategoryModel {
+    pub id: i32,
+    pub name: String,
+    pub guild_commander_id: i32,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddQuestCategoryModel {
+    pub name: String,
+}
+
+impl AddQuestCategoryModel {
+    pub fn to_entity(&self, guild_commander_id: i32) -> AddQuestCategoryEntity {
+        AddQuestCategoryEntity {
+            name: self.name.trim().to_string(),
+            guild_commander_id,
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EditQuestCategoryModel {
+    pub name: String,
+}
+
+impl EditQuestCategoryModel {
+    pub fn to_entity(&self) -> EditQuestCategoryEntity {
+        EditQuestCategoryEntity {
+            name: self.name.trim().to_string(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuestCategoryConflictModel {
+    pub name: String,
+}
+
+impl QuestCategoryConflictModel {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl fmt::Display for QuestCategoryConflictModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Quest category {} already exists", self.name)
+    }
+}
+
+impl std::error::Error for QuestCategoryConflictModel {}
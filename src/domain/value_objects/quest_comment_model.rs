@@ -0,0 +1,117 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::entities::quest_comments::{AddQuestCommentEntity, EditQuestCommentEntity};
+
+use super::authenticated_roles::AuthenticatedIdentity;
+
+pub const DELETED_COMMENT_BODY: &str = "[deleted]";
+pub const DEFAULT_COMMENTS_PER_PAGE: i64 = 20;
+pub const MAX_COMMENTS_PER_PAGE: i64 = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestCommentModel {
+    pub id: i32,
+    pub quest_id: i32,
+    pub parent_id: Option<i32>,
+    pub author_role: String,
+    pub author_id: i32,
+    pub body: String,
+    pub deleted: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestCommentThreadModel {
+    #[serde(flatten)]
+    pub comment: QuestCommentModel,
+    pub replies: Vec<QuestCommentModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestCommentPageModel {
+    pub page: i64,
+    pub per_page: i64,
+    pub total: i64,
+    pub threads: Vec<QuestCommentThreadModel>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QuestCommentFilter {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuestCommentPagination {
+    pub page: i64,
+    pub per_page: i64,
+    pub offset: i64,
+}
+
+impl QuestCommentFilter {
+    pub fn pagination(&self) -> Result<QuestCommentPagination> {
+        let page = self.page.unwrap_or(1);
+        if page < 1 {
+            return Err(anyhow::anyhow!("Page must be at least 1"));
+        }
+
+        let per_page = self
+            .per_page
+            .unwrap_or(DEFAULT_COMMENTS_PER_PAGE)
+            .clamp(1, MAX_COMMENTS_PER_PAGE);
+
+        let offset = (page - 1)
+            .checked_mul(per_page)
+            .ok_or_else(|| anyhow::anyhow!("Page is out of range"))?;
+
+        Ok(QuestCommentPagination {
+            page,
+            per_page,
+            offset,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddQuestCommentModel {
+    pub body: String,
+    pub parent_id: Option<i32>,
+}
+
+impl AddQuestCommentModel {
+    pub fn to_entity(
+        &self,
+        quest_id: i32,
+        parent_id: Option<i32>,
+        author: AuthenticatedIdentity,
+    ) -> AddQuestCommentEntity {
+        AddQuestCommentEntity {
+            quest_id,
+            parent_id,
+            author_role: author.role.to_string(),
+            author_id: author.id,
+            body: self.body.trim().to_string(),
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EditQuestCommentModel {
+    pub body: String,
+}
+
+impl EditQuestCommentModel {
+    pub fn to_entity(&self) -> EditQuestCommentEntity {
+        EditQuestCommentEntity {
+            body: self.body.trim().to_string(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
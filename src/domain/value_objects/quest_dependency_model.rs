@@ -0,0 +1,68 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::quests::QuestEntity;
+
+use super::quest_statuses::QuestStatuses;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestDependencyEdgeModel {
+    pub quest_id: i32,
+    pub prerequisite_id: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestDependencyNodeModel {
+    pub id: i32,
+    pub name: String,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestDependencyGraphModel {
+    pub nodes: Vec<QuestDependencyNodeModel>,
+    pub edges: Vec<QuestDependencyEdgeModel>,
+}
+
+pub fn creates_cycle(
+    edges: &[QuestDependencyEdgeModel],
+    quest_id: i32,
+    prerequisite_id: i32,
+) -> bool {
+    let mut prerequisites: HashMap<i32, Vec<i32>> = HashMap::new();
+    for edge in edges.iter() {
+        prerequisites
+            .entry(edge.quest_id)
+            .or_default()
+            .push(edge.prerequisite_id);
+    }
+
+    let mut visited = HashSet::new();
+    let mut stack = vec![prerequisite_id];
+
+    while let Some(current) = stack.pop() {
+        if current == quest_id {
+            return true;
+        }
+
+        if !visited.insert(current) {
+            continue;
+        }
+
+        if let Some(next) = prerequisites.get(&current) {
+            stack.extend(next.iter().copied());
+        }
+    }
+
+    false
+}
+
+pub fn pending_prerequisites(prerequisites: &[QuestEntity]) -> Vec<i32> {
+    prerequisites
+        .iter()
+        .filter(|quest| quest.status != QuestStatuses::Completed.to_string())
+        .map(|quest| quest.id)
+        .collect()
+}
@@ -0,0 +1,40 @@
+use std::fmt;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum QuestDifficulties {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Epic,
+    Legendary,
+}
+
+impl fmt::Display for QuestDifficulties {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuestDifficulties::Easy => write!(f, "Easy"),
+            QuestDifficulties::Normal => write!(f, "Normal"),
+            QuestDifficulties::Hard => write!(f, "Hard"),
+            QuestDifficulties::Epic => write!(f, "Epic"),
+            QuestDifficulties::Legendary => write!(f, "Legendary"),
+        }
+    }
+}
+
+impl QuestDifficulties {
+    pub fn try_from(difficulty: &str) -> Result<Self> {
+        match difficulty {
+            "Easy" => Ok(QuestDifficulties::Easy),
+            "Normal" => Ok(QuestDifficulties::Normal),
+            "Hard" => Ok(QuestDifficulties::Hard),
+            "Epic" => Ok(QuestDifficulties::Epic),
+            "Legendary" => Ok(QuestDifficulties::Legendary),
+            _ => Err(anyhow::anyhow!("Invalid quest difficulty")),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::quest_statuses::QuestStatuses;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum QuestEventKinds {
+    QuestAdded,
+    QuestEdited,
+    QuestRemoved,
+    CrewJoined,
+    CrewLeft,
+    StatusChanged,
+}
+
+impl fmt::Display for QuestEventKinds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuestEventKinds::QuestAdded => write!(f, "QuestAdded"),
+            QuestEventKinds::QuestEdited => write!(f, "QuestEdited"),
+            QuestEventKinds::QuestRemoved => write!(f, "QuestRemoved"),
+            QuestEventKinds::CrewJoined => write!(f, "CrewJoined"),
+            QuestEventKinds::CrewLeft => write!(f, "CrewLeft"),
+            QuestEventKinds::StatusChanged => write!(f, "StatusChanged"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestEventModel {
+    pub kind: QuestEventKinds,
+    pub quest_id: i32,
+    pub adventurer_id: Option<i32>,
+    pub status: Option<QuestStatuses>,
+    #[serde(default)]
+    pub recipient_ids: Vec<i32>,
+    pub occurred_at: NaiveDateTime,
+}
+
+impl QuestEventModel {
+    pub fn quest(kind: QuestEventKinds, quest_id: i32) -> Self {
+        Self {
+            kind,
+            quest_id,
+            adventurer_id: None,
+            status: None,
+            recipient_ids: Vec::new(),
+            occurred_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+
+    pub fn crew(kind: QuestEventKinds, quest_id: i32, adventurer_id: i32) -> Self {
+        Self {
+            adventurer_id: Some(adventurer_id),
+            ..Self::quest(kind, quest_id)
+        }
+    }
+
+    pub fn status_changed(quest_id: i32, status: QuestStatuses, crew_ids: Vec<i32>) -> Self {
+        Self {
+            status: Some(status),
+            recipient_ids: crew_ids,
+            ..Self::quest(QuestEventKinds::StatusChanged, quest_id)
+        }
+    }
+
+    pub fn to_public(&self) -> PublicQuestEventModel {
+        PublicQuestEventModel {
+            kind: self.kind,
+            quest_id: self.quest_id,
+            adventurer_id: self.adventurer_id,
+            status: self.status.clone(),
+            occurred_at: self.occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct PublicQuestEventModel {
+    pub kind: QuestEventKinds,
+    pub quest_id: i32,
+    pub adventurer_id: Option<i32>,
+    pub status: Option<QuestStatuses>,
+    pub occurred_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QuestEventFilter {
+    pub quest_ids: Option<String>,
+}
+
+impl QuestEventFilter {
+    pub fn quest_ids(&self) -> Vec<i32> {
+        self.quest_ids
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|quest_id| quest_id.trim().parse::<i32>().ok())
+            .collect()
+    }
+}
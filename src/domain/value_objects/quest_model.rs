@@ -1,26 +1,107 @@
+use anyhow::Result;
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 
 use crate::domain::entities::quests::{AddQuestEntity, EditQuestEntity};
 
-use super::quest_statuses::QuestStatuses;
+use super::{
+    input_sanitizer::{
+        forbid_markup_characters, sanitize_markdown, Sanitize, MAX_QUEST_DESCRIPTION_LENGTH,
+        MAX_QUEST_NAME_LENGTH,
+    },
+    quest_adventurer_junction::DEFAULT_CREW_SIZE,
+    quest_difficulties::QuestDifficulties,
+    quest_reward_model::QuestRewardModel,
+    quest_statuses::QuestStatuses,
+};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct QuestModel {
     pub id: i32,
     pub name: String,
     pub description: Option<String>,
     pub status: String,
     pub guild_commander_id: i32,
+    pub approval_required: bool,
+    pub starts_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+    pub reward: QuestRewardModel,
+    pub difficulty: String,
+    pub category_id: Option<i32>,
+    pub max_adventurers: i32,
+    pub series_id: Option<i32>,
+    pub objectives_required: bool,
+    pub version: i32,
+    pub progress: Option<i32>,
+    pub tags: Vec<String>,
     pub adventurers_count: i64,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct QuestsByStatusModel {
+    pub open: Vec<QuestModel>,
+    pub in_journey: Vec<QuestModel>,
+    pub completed: Vec<QuestModel>,
+    pub failed: Vec<QuestModel>,
+}
+
+impl QuestsByStatusModel {
+    pub fn push(&mut self, quest_model: QuestModel) -> Result<()> {
+        match QuestStatuses::try_from(&quest_model.status)? {
+            QuestStatuses::Open => self.open.push(quest_model),
+            QuestStatuses::InJourney => self.in_journey.push(quest_model),
+            QuestStatuses::Completed => self.completed.push(quest_model),
+            QuestStatuses::Failed => self.failed.push(quest_model),
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuestStatusModel {
+    pub quest_id: i32,
+    pub status: QuestStatuses,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RemovedQuestModel {
+    pub quest_id: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
 pub struct AddQuestModel {
+    #[validate(
+        length(
+            min = 1,
+            max = MAX_QUEST_NAME_LENGTH,
+            message = "Must be between 1 and 255 characters"
+        ),
+        custom(function = "forbid_markup_characters")
+    )]
+    #[schema(min_length = 1, max_length = 255)]
     pub name: String,
+    #[validate(length(
+        max = MAX_QUEST_DESCRIPTION_LENGTH,
+        message = "Must be at most 10000 characters"
+    ))]
+    #[schema(max_length = 10000)]
     pub description: Option<String>,
+    pub approval_required: Option<bool>,
+    pub starts_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+    pub reward: Option<QuestRewardModel>,
+    pub difficulty: Option<QuestDifficulties>,
+    pub category_id: Option<i32>,
+    pub max_adventurers: Option<i32>,
+    pub objectives_required: Option<bool>,
+    pub tags: Option<Vec<String>>,
 }
 
 impl AddQuestModel {
@@ -29,6 +110,18 @@ impl AddQuestModel {
             name: self.name.clone(),
             description: self.description.clone(),
             guild_commander_id,
+            approval_required: self.approval_required.unwrap_or(false),
+            starts_at: self.starts_at,
+            due_at: self.due_at,
+            join_deadline: self.join_deadline,
+            reward_gold: self.reward.as_ref().map_or(0, |reward| reward.gold),
+            reward_xp: self.reward.as_ref().map_or(0, |reward| reward.xp),
+            difficulty: self.difficulty.unwrap_or_default().to_string(),
+            category_id: self.category_id,
+            max_adventurers: self.max_adventurers.unwrap_or(DEFAULT_CREW_SIZE),
+            series_id: None,
+            series_occurrence_at: None,
+            objectives_required: self.objectives_required.unwrap_or(false),
             status: QuestStatuses::Open.to_string(),
             created_at: chrono::Utc::now().naive_utc(),
             updated_at: chrono::Utc::now().naive_utc(),
@@ -36,19 +129,71 @@ impl AddQuestModel {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
 pub struct EditQuestModel {
+    #[validate(
+        length(
+            min = 1,
+            max = MAX_QUEST_NAME_LENGTH,
+            message = "Must be between 1 and 255 characters"
+        ),
+        custom(function = "forbid_markup_characters")
+    )]
+    #[schema(min_length = 1, max_length = 255)]
     pub name: Option<String>,
+    #[validate(length(
+        max = MAX_QUEST_DESCRIPTION_LENGTH,
+        message = "Must be at most 10000 characters"
+    ))]
+    #[schema(max_length = 10000)]
     pub description: Option<String>,
+    pub approval_required: Option<bool>,
+    pub starts_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+    pub reward: Option<QuestRewardModel>,
+    pub difficulty: Option<QuestDifficulties>,
+    pub category_id: Option<i32>,
+    pub max_adventurers: Option<i32>,
+    pub objectives_required: Option<bool>,
+    pub tags: Option<Vec<String>>,
+    pub version: Option<i32>,
+}
+
+impl Sanitize for AddQuestModel {
+    fn sanitize(&mut self) {
+        self.name = self.name.trim().to_string();
+        self.description = self
+            .description
+            .as_deref()
+            .map(sanitize_markdown)
+            .filter(|description| !description.is_empty());
+    }
 }
 
 impl EditQuestModel {
-    pub fn to_entity(&self, guild_commander_id: i32) -> EditQuestEntity {
+    pub fn to_entity(&self) -> EditQuestEntity {
         EditQuestEntity {
             name: self.name.clone(),
             description: self.description.clone(),
-            guild_commander_id,
+            approval_required: self.approval_required,
+            starts_at: self.starts_at,
+            due_at: self.due_at,
+            join_deadline: self.join_deadline,
+            reward_gold: self.reward.as_ref().map(|reward| reward.gold),
+            reward_xp: self.reward.as_ref().map(|reward| reward.xp),
+            difficulty: self.difficulty.map(|difficulty| difficulty.to_string()),
+            category_id: self.category_id,
+            max_adventurers: self.max_adventurers,
+            objectives_required: self.objectives_required,
             updated_at: chrono::Utc::now().naive_utc(),
         }
     }
 }
+
+impl Sanitize for EditQuestModel {
+    fn sanitize(&mut self) {
+        self.name = self.name.as_deref().map(|name| name.trim().to_string());
+        self.description = self.description.as_deref().map(sanitize_markdown);
+    }
+}
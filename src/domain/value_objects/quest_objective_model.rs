@@ -0,0 +1,67 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::quest_objectives::{
+    AddQuestObjectiveEntity, EditQuestObjectiveEntity, QuestObjectiveEntity,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuestObjectiveModel {
+    pub id: i32,
+    pub quest_id: i32,
+    pub position: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub done: bool,
+    pub completed_by: Option<i32>,
+    pub completed_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddQuestObjectiveModel {
+    pub title: String,
+    pub description: Option<String>,
+    pub position: Option<i32>,
+}
+
+impl AddQuestObjectiveModel {
+    pub fn to_entity(&self, quest_id: i32, position: i32) -> AddQuestObjectiveEntity {
+        AddQuestObjectiveEntity {
+            quest_id,
+            position,
+            title: self.title.trim().to_string(),
+            description: self.description.clone(),
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EditQuestObjectiveModel {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub position: Option<i32>,
+}
+
+impl EditQuestObjectiveModel {
+    pub fn to_entity(&self) -> EditQuestObjectiveEntity {
+        EditQuestObjectiveEntity {
+            position: self.position,
+            title: self.title.as_ref().map(|title| title.trim().to_string()),
+            description: self.description.clone(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+pub fn objectives_progress(objectives: &[QuestObjectiveEntity]) -> Option<i32> {
+    if objectives.is_empty() {
+        return None;
+    }
+
+    let done = objectives.iter().filter(|objective| objective.done).count();
+
+    Some((done * 100 / objectives.len()) as i32)
+}
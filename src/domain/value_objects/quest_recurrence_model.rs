@@ -0,0 +1,152 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::Result;
+use chrono::{Datelike, Duration, Months, NaiveDateTime, TimeZone, Utc, Weekday};
+use cron::Schedule;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum QuestRecurrenceFrequencies {
+    #[default]
+    Daily,
+    Weekly,
+    Monthly,
+    Cron,
+}
+
+impl fmt::Display for QuestRecurrenceFrequencies {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuestRecurrenceFrequencies::Daily => write!(f, "Daily"),
+            QuestRecurrenceFrequencies::Weekly => write!(f, "Weekly"),
+            QuestRecurrenceFrequencies::Monthly => write!(f, "Monthly"),
+            QuestRecurrenceFrequencies::Cron => write!(f, "Cron"),
+        }
+    }
+}
+
+impl QuestRecurrenceFrequencies {
+    pub fn try_from(frequency: &str) -> Result<Self> {
+        match frequency {
+            "Daily" => Ok(QuestRecurrenceFrequencies::Daily),
+            "Weekly" => Ok(QuestRecurrenceFrequencies::Weekly),
+            "Monthly" => Ok(QuestRecurrenceFrequencies::Monthly),
+            "Cron" => Ok(QuestRecurrenceFrequencies::Cron),
+            _ => Err(anyhow::anyhow!("Invalid quest recurrence frequency")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestRecurrenceModel {
+    pub frequency: QuestRecurrenceFrequencies,
+    #[serde(default)]
+    #[schema(value_type = Vec<String>)]
+    pub weekdays: Vec<Weekday>,
+    pub cron_expression: Option<String>,
+}
+
+impl QuestRecurrenceModel {
+    pub fn validate(&self) -> Result<()> {
+        if self.frequency == QuestRecurrenceFrequencies::Cron {
+            self.schedule()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn occurrences(
+        &self,
+        anchor: NaiveDateTime,
+        from: NaiveDateTime,
+        until: NaiveDateTime,
+        limit: usize,
+    ) -> Result<Vec<NaiveDateTime>> {
+        let from = from.max(anchor);
+        let mut occurrences = Vec::new();
+
+        if until < from {
+            return Ok(occurrences);
+        }
+
+        match self.frequency {
+            QuestRecurrenceFrequencies::Daily | QuestRecurrenceFrequencies::Weekly => {
+                let mut day = from.date();
+
+                while day <= until.date() && occurrences.len() < limit {
+                    let occurrence = day.and_time(anchor.time());
+
+                    if occurrence >= from
+                        && occurrence <= until
+                        && self.runs_on(day.weekday(), anchor)
+                    {
+                        occurrences.push(occurrence);
+                    }
+
+                    day += Duration::days(1);
+                }
+            }
+            QuestRecurrenceFrequencies::Monthly => {
+                let mut months = 0;
+
+                while occurrences.len() < limit {
+                    let occurrence = anchor
+                        .checked_add_months(Months::new(months))
+                        .ok_or_else(|| anyhow::anyhow!("Quest recurrence is out of range"))?;
+
+                    if occurrence > until {
+                        break;
+                    }
+
+                    if occurrence >= from {
+                        occurrences.push(occurrence);
+                    }
+
+                    months += 1;
+                }
+            }
+            QuestRecurrenceFrequencies::Cron => {
+                let after = Utc.from_utc_datetime(&(from - Duration::seconds(1)));
+
+                occurrences = self
+                    .schedule()?
+                    .after(&after)
+                    .map(|occurrence| occurrence.naive_utc())
+                    .take_while(|occurrence| *occurrence <= until)
+                    .take(limit)
+                    .collect();
+            }
+        }
+
+        Ok(occurrences)
+    }
+
+    fn runs_on(&self, weekday: Weekday, anchor: NaiveDateTime) -> bool {
+        match self.frequency {
+            QuestRecurrenceFrequencies::Weekly if self.weekdays.is_empty() => {
+                weekday == anchor.weekday()
+            }
+            QuestRecurrenceFrequencies::Weekly => self.weekdays.contains(&weekday),
+            _ => true,
+        }
+    }
+
+    fn schedule(&self) -> Result<Schedule> {
+        let expression = self
+            .cron_expression
+            .as_deref()
+            .map(str::trim)
+            .filter(|expression| !expression.is_empty())
+            .ok_or_else(|| anyhow::anyhow!("Quest recurrence cron expression is required"))?;
+
+        let expression = if expression.split_whitespace().count() == 5 {
+            format!("0 {}", expression)
+        } else {
+            expression.to_string()
+        };
+
+        Schedule::from_str(&expression)
+            .map_err(|_| anyhow::anyhow!("Quest recurrence cron expression is invalid"))
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::quest_requirements::UpsertQuestRequirementEntity;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestRequirementsModel {
+    pub min_level: Option<i32>,
+    pub min_completed_quests: Option<i32>,
+    #[serde(default)]
+    pub required_skills: Vec<String>,
+    #[serde(default)]
+    pub allowed_adventurer_ids: Vec<i32>,
+}
+
+impl QuestRequirementsModel {
+    pub fn to_entity(&self, quest_id: i32) -> UpsertQuestRequirementEntity {
+        UpsertQuestRequirementEntity {
+            quest_id,
+            min_level: self.min_level,
+            min_completed_quests: self.min_completed_quests,
+            required_skills: serde_json::json!(self.required_skills),
+            allowed_adventurer_ids: serde_json::json!(self.allowed_adventurer_ids),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
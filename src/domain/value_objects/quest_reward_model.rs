@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub const MAX_QUEST_REWARD: i32 = 1_000_000_000;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestRewardModel {
+    pub gold: i32,
+    pub xp: i32,
+}
+
+impl QuestRewardModel {
+    pub fn partial(&self, gold_percent: i32, xp_percent: i32) -> Self {
+        Self {
+            gold: Self::percent_of(self.gold, gold_percent),
+            xp: Self::percent_of(self.xp, xp_percent),
+        }
+    }
+
+    fn percent_of(amount: i32, percent: i32) -> i32 {
+        let partial = i64::from(amount) * i64::from(percent.clamp(0, 100)) / 100;
+
+        partial as i32
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::quest_series::{AddQuestSeriesEntity, EditQuestSeriesEntity};
+
+use super::{
+    quest_adventurer_junction::DEFAULT_CREW_SIZE, quest_difficulties::QuestDifficulties,
+    quest_recurrence_model::QuestRecurrenceModel, quest_reward_model::QuestRewardModel,
+    quest_series_statuses::QuestSeriesStatuses,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuestSeriesModel {
+    pub id: i32,
+    pub guild_commander_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub approval_required: bool,
+    pub max_adventurers: i32,
+    pub difficulty: String,
+    pub category_id: Option<i32>,
+    pub tags: Vec<String>,
+    pub reward: QuestRewardModel,
+    pub recurrence: QuestRecurrenceModel,
+    pub starts_at: NaiveDateTime,
+    pub duration_minutes: Option<i32>,
+    pub ends_at: Option<NaiveDateTime>,
+    pub status: String,
+    pub materialized_until: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddQuestSeriesModel {
+    pub name: String,
+    pub description: Option<String>,
+    pub approval_required: Option<bool>,
+    pub max_adventurers: Option<i32>,
+    pub difficulty: Option<QuestDifficulties>,
+    pub category_id: Option<i32>,
+    pub tags: Option<Vec<String>>,
+    pub reward: Option<QuestRewardModel>,
+    pub recurrence: QuestRecurrenceModel,
+    pub starts_at: NaiveDateTime,
+    pub duration_minutes: Option<i32>,
+    pub ends_at: Option<NaiveDateTime>,
+}
+
+impl AddQuestSeriesModel {
+    pub fn to_entity(&self, guild_commander_id: i32, tags: Vec<String>) -> AddQuestSeriesEntity {
+        AddQuestSeriesEntity {
+            guild_commander_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            approval_required: self.approval_required.unwrap_or(false),
+            max_adventurers: self.max_adventurers.unwrap_or(DEFAULT_CREW_SIZE),
+            difficulty: self.difficulty.unwrap_or_default().to_string(),
+            category_id: self.category_id,
+            tags: serde_json::json!(tags),
+            reward_gold: self.reward.as_ref().map_or(0, |reward| reward.gold),
+            reward_xp: self.reward.as_ref().map_or(0, |reward| reward.xp),
+            frequency: self.recurrence.frequency.to_string(),
+            weekdays: serde_json::json!(self.recurrence.weekdays),
+            cron_expression: self.recurrence.cron_expression.clone(),
+            starts_at: self.starts_at,
+            duration_minutes: self.duration_minutes,
+            ends_at: self.ends_at,
+            status: QuestSeriesStatuses::Active.to_string(),
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EditQuestSeriesModel {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub approval_required: Option<bool>,
+    pub max_adventurers: Option<i32>,
+    pub difficulty: Option<QuestDifficulties>,
+    pub category_id: Option<i32>,
+    pub tags: Option<Vec<String>>,
+    pub reward: Option<QuestRewardModel>,
+    pub recurrence: Option<QuestRecurrenceModel>,
+    pub starts_at: Option<NaiveDateTime>,
+    pub duration_minutes: Option<i32>,
+    pub ends_at: Option<NaiveDateTime>,
+}
+
+impl EditQuestSeriesModel {
+    pub fn to_entity(&self, tags: Option<Vec<String>>) -> EditQuestSeriesEntity {
+        EditQuestSeriesEntity {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            approval_required: self.approval_required,
+            max_adventurers: self.max_adventurers,
+            difficulty: self.difficulty.map(|difficulty| difficulty.to_string()),
+            category_id: self.category_id,
+            tags: tags.map(|tags| serde_json::json!(tags)),
+            reward_gold: self.reward.as_ref().map(|reward| reward.gold),
+            reward_xp: self.reward.as_ref().map(|reward| reward.xp),
+            frequency: self
+                .recurrence
+                .as_ref()
+                .map(|recurrence| recurrence.frequency.to_string()),
+            weekdays: self
+                .recurrence
+                .as_ref()
+                .map(|recurrence| serde_json::json!(recurrence.weekdays)),
+            cron_expression: self
+                .recurrence
+                .as_ref()
+                .and_then(|recurrence| recurrence.cron_expression.clone()),
+            starts_at: self.starts_at,
+            duration_minutes: self.duration_minutes,
+            ends_at: self.ends_at,
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
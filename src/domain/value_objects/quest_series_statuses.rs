@@ -0,0 +1,34 @@
+use std::fmt;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum QuestSeriesStatuses {
+    #[default]
+    Active,
+    Paused,
+    Ended,
+}
+
+impl fmt::Display for QuestSeriesStatuses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuestSeriesStatuses::Active => write!(f, "Active"),
+            QuestSeriesStatuses::Paused => write!(f, "Paused"),
+            QuestSeriesStatuses::Ended => write!(f, "Ended"),
+        }
+    }
+}
+
+impl QuestSeriesStatuses {
+    pub fn try_from(status: &str) -> Result<Self> {
+        match status {
+            "Active" => Ok(QuestSeriesStatuses::Active),
+            "Paused" => Ok(QuestSeriesStatuses::Paused),
+            "Ended" => Ok(QuestSeriesStatuses::Ended),
+            _ => Err(anyhow::anyhow!("Invalid quest series status")),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum QuestStatusActors {
+    GuildCommander,
+    System,
+}
+
+impl fmt::Display for QuestStatusActors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuestStatusActors::GuildCommander => write!(f, "GuildCommander"),
+            QuestStatusActors::System => write!(f, "System"),
+        }
+    }
+}
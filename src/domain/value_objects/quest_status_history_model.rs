@@ -0,0 +1,11 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestStatusHistoryModel {
+    pub status: String,
+    pub actor: String,
+    pub guild_commander_id: Option<i32>,
+    pub created_at: NaiveDateTime,
+}
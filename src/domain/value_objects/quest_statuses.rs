@@ -1,8 +1,10 @@
 use std::fmt;
 
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 pub enum QuestStatuses {
     #[default]
     Open,
@@ -21,3 +23,15 @@ impl fmt::Display for QuestStatuses {
         }
     }
 }
+
+impl QuestStatuses {
+    pub fn try_from(status: &str) -> Result<Self> {
+        match status {
+            "Open" => Ok(QuestStatuses::Open),
+            "InJourney" => Ok(QuestStatuses::InJourney),
+            "Completed" => Ok(QuestStatuses::Completed),
+            "Failed" => Ok(QuestStatuses::Failed),
+            _ => Err(anyhow::anyhow!("Invalid quest status")),
+        }
+    }
+}
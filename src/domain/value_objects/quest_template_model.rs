@@ -0,0 +1,94 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::quest_templates::{AddQuestTemplateEntity, EditQuestTemplateEntity};
+
+use super::{
+    quest_adventurer_junction::DEFAULT_CREW_SIZE, quest_difficulties::QuestDifficulties,
+    quest_reward_model::QuestRewardModel,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuestTemplateModel {
+    pub id: i32,
+    pub guild_commander_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub approval_required: bool,
+    pub max_adventurers: i32,
+    pub difficulty: String,
+    pub category_id: Option<i32>,
+    pub tags: Vec<String>,
+    pub reward: QuestRewardModel,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddQuestTemplateModel {
+    pub name: String,
+    pub description: Option<String>,
+    pub approval_required: Option<bool>,
+    pub max_adventurers: Option<i32>,
+    pub difficulty: Option<QuestDifficulties>,
+    pub category_id: Option<i32>,
+    pub tags: Option<Vec<String>>,
+    pub reward: Option<QuestRewardModel>,
+}
+
+impl AddQuestTemplateModel {
+    pub fn to_entity(&self, guild_commander_id: i32, tags: Vec<String>) -> AddQuestTemplateEntity {
+        AddQuestTemplateEntity {
+            guild_commander_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            approval_required: self.approval_required.unwrap_or(false),
+            max_adventurers: self.max_adventurers.unwrap_or(DEFAULT_CREW_SIZE),
+            difficulty: self.difficulty.unwrap_or_default().to_string(),
+            category_id: self.category_id,
+            tags: serde_json::json!(tags),
+            reward_gold: self.reward.as_ref().map_or(0, |reward| reward.gold),
+            reward_xp: self.reward.as_ref().map_or(0, |reward| reward.xp),
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EditQuestTemplateModel {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub approval_required: Option<bool>,
+    pub max_adventurers: Option<i32>,
+    pub difficulty: Option<QuestDifficulties>,
+    pub category_id: Option<i32>,
+    pub tags: Option<Vec<String>>,
+    pub reward: Option<QuestRewardModel>,
+}
+
+impl EditQuestTemplateModel {
+    pub fn to_entity(&self, tags: Option<Vec<String>>) -> EditQuestTemplateEntity {
+        EditQuestTemplateEntity {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            approval_required: self.approval_required,
+            max_adventurers: self.max_adventurers,
+            difficulty: self.difficulty.map(|difficulty| difficulty.to_string()),
+            category_id: self.category_id,
+            tags: tags.map(|tags| serde_json::json!(tags)),
+            reward_gold: self.reward.as_ref().map(|reward| reward.gold),
+            reward_xp: self.reward.as_ref().map(|reward| reward.xp),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct QuestFromTemplateModel {
+    pub name: Option<String>,
+    pub starts_at: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+}
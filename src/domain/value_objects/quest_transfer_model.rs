@@ -0,0 +1,279 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::{
+    crew_member_model::CrewMemberModel,
+    quest_difficulties::QuestDifficulties,
+    quest_model::{AddQuestModel, QuestModel},
+    quest_objective_model::{AddQuestObjectiveModel, QuestObjectiveModel},
+    quest_requirements_model::QuestRequirementsModel,
+    quest_reward_model::QuestRewardModel,
+    quest_status_history_model::QuestStatusHistoryModel,
+};
+
+pub const MAX_IMPORTED_QUESTS: usize = 500;
+
+const LIST_SEPARATOR: &str = ";";
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum TransferFormats {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QuestTransferFilter {
+    pub format: Option<TransferFormats>,
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuestExportModel {
+    #[serde(flatten)]
+    pub quest: QuestModel,
+    pub objectives: Vec<QuestObjectiveModel>,
+    pub requirements: Option<QuestRequirementsModel>,
+    pub crew: Vec<CrewMemberModel>,
+    pub status_history: Vec<QuestStatusHistoryModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuestImportModel {
+    #[serde(flatten)]
+    pub quest: AddQuestModel,
+    #[serde(default)]
+    pub objectives: Vec<AddQuestObjectiveModel>,
+    pub requirements: Option<QuestRequirementsModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestImportRowModel {
+    pub row: usize,
+    pub quest_id: Option<i32>,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestImportReportModel {
+    pub dry_run: bool,
+    pub committed: bool,
+    pub valid: usize,
+    pub invalid: usize,
+    pub rows: Vec<QuestImportRowModel>,
+}
+
+impl QuestImportReportModel {
+    pub fn new(dry_run: bool, committed: bool, rows: Vec<QuestImportRowModel>) -> Self {
+        let invalid = rows.iter().filter(|row| !row.errors.is_empty()).count();
+
+        Self {
+            dry_run,
+            committed,
+            valid: rows.len() - invalid,
+            invalid,
+            rows,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct QuestCsvRow {
+    id: Option<i32>,
+    name: String,
+    description: Option<String>,
+    status: Option<String>,
+    approval_required: Option<bool>,
+    starts_at: Option<NaiveDateTime>,
+    due_at: Option<NaiveDateTime>,
+    join_deadline: Option<NaiveDateTime>,
+    reward_gold: Option<i32>,
+    reward_xp: Option<i32>,
+    difficulty: Option<QuestDifficulties>,
+    category_id: Option<i32>,
+    max_adventurers: Option<i32>,
+    objectives_required: Option<bool>,
+    tags: Option<String>,
+    objectives: Option<String>,
+    requirements: Option<String>,
+    crew: Option<String>,
+    status_history: Option<String>,
+    created_at: Option<NaiveDateTime>,
+    updated_at: Option<NaiveDateTime>,
+}
+
+impl QuestCsvRow {
+    fn from_export(quest_export_model: &QuestExportModel) -> Result<Self> {
+        let quest = &quest_export_model.quest;
+
+        let objectives = quest_export_model
+            .objectives
+            .iter()
+            .map(|objective| AddQuestObjectiveModel {
+                title: objective.title.clone(),
+                description: objective.description.clone(),
+                position: Some(objective.position),
+            })
+            .collect::<Vec<_>>();
+
+        let requirements = quest_export_model
+            .requirements
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
+
+        let crew = quest_export_model
+            .crew
+            .iter()
+            .map(|crew_member| crew_member.username.clone())
+            .collect::<Vec<_>>();
+
+        let status_history = quest_export_model
+            .status_history
+            .iter()
+            .map(|history| {
+                format!(
+                    "{}@{}",
+                    history.status,
+                    history.created_at.format(TIMESTAMP_FORMAT)
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Self {
+            id: Some(quest.id),
+            name: quest.name.clone(),
+            description: quest.description.clone(),
+            status: Some(quest.status.clone()),
+            approval_required: Some(quest.approval_required),
+            starts_at: quest.starts_at,
+            due_at: quest.due_at,
+            join_deadline: quest.join_deadline,
+            reward_gold: Some(quest.reward.gold),
+            reward_xp: Some(quest.reward.xp),
+            difficulty: QuestDifficulties::try_from(&quest.difficulty).ok(),
+            category_id: quest.category_id,
+            max_adventurers: Some(quest.max_adventurers),
+            objectives_required: Some(quest.objectives_required),
+            tags: Some(quest.tags.join(LIST_SEPARATOR)),
+            objectives: Some(serde_json::to_string(&objectives)?),
+            requirements,
+            crew: Some(crew.join(LIST_SEPARATOR)),
+            status_history: Some(status_history.join(LIST_SEPARATOR)),
+            created_at: Some(quest.created_at),
+            updated_at: Some(quest.updated_at),
+        })
+    }
+
+    fn into_import_model(self) -> Result<QuestImportModel> {
+        let reward = match (self.reward_gold, self.reward_xp) {
+            (None, None) => None,
+            (gold, xp) => Some(QuestRewardModel {
+                gold: gold.unwrap_or(0),
+                xp: xp.unwrap_or(0),
+            }),
+        };
+
+        let tags = self.tags.map(|tags| {
+            tags.split(LIST_SEPARATOR)
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        });
+
+        let objectives = match self.objectives.as_deref() {
+            None | Some("") => Vec::new(),
+            Some(objectives) => serde_json::from_str(objectives)
+                .map_err(|e| anyhow::anyhow!("objectives: {}", e))?,
+        };
+
+        let requirements = match self.requirements.as_deref() {
+            None | Some("") => None,
+            Some(requirements) => Some(
+                serde_json::from_str(requirements)
+                    .map_err(|e| anyhow::anyhow!("requirements: {}", e))?,
+            ),
+        };
+
+        let quest = AddQuestModel {
+            name: self.name,
+            description: self.description,
+            approval_required: self.approval_required,
+            starts_at: self.starts_at,
+            due_at: self.due_at,
+            join_deadline: self.join_deadline,
+            reward,
+            difficulty: self.difficulty,
+            category_id: self.category_id,
+            max_adventurers: self.max_adventurers,
+            objectives_required: self.objectives_required,
+            tags,
+        };
+
+        Ok(QuestImportModel {
+            quest,
+            objectives,
+            requirements,
+        })
+    }
+}
+
+pub fn parse_quest_import(
+    format: TransferFormats,
+    body: &str,
+) -> Result<Vec<Result<QuestImportModel>>> {
+    let rows = match format {
+        TransferFormats::Json => {
+            let values = serde_json::from_str::<Vec<serde_json::Value>>(body)
+                .map_err(|e| anyhow::anyhow!("Import must be a JSON array of quests: {}", e))?;
+
+            values
+                .into_iter()
+                .map(|value| serde_json::from_value::<QuestImportModel>(value).map_err(Into::into))
+                .collect::<Vec<_>>()
+        }
+        TransferFormats::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::Headers)
+                .from_reader(body.as_bytes());
+
+            if !reader.headers()?.iter().any(|header| header == "name") {
+                return Err(anyhow::anyhow!("Import CSV must have a name column"));
+            }
+
+            reader
+                .deserialize::<QuestCsvRow>()
+                .map(|row| {
+                    row.map_err(Into::into)
+                        .and_then(QuestCsvRow::into_import_model)
+                })
+                .collect::<Vec<_>>()
+        }
+    };
+
+    if rows.is_empty() {
+        return Err(anyhow::anyhow!("Import must contain at least one quest"));
+    }
+
+    if rows.len() > MAX_IMPORTED_QUESTS {
+        return Err(anyhow::anyhow!(
+            "Import must not contain more than {} quests",
+            MAX_IMPORTED_QUESTS
+        ));
+    }
+
+    Ok(rows)
+}
+
+pub fn quests_to_csv(quest_export_models: &[QuestExportModel]) -> Result<String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+
+    for quest_export_model in quest_export_models.iter() {
+        writer.serialize(QuestCsvRow::from_export(quest_export_model)?)?;
+    }
+
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
@@ -0,0 +1,47 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct QuestVersionConflictModel {
+    pub message: String,
+    pub quest_id: i32,
+    pub expected_version: i32,
+    pub current_version: i32,
+}
+
+impl QuestVersionConflictModel {
+    pub fn new(quest_id: i32, expected_version: i32, current_version: i32) -> Self {
+        Self {
+            message: "Quest has been modified since it was last read".to_string(),
+            quest_id,
+            expected_version,
+            current_version,
+        }
+    }
+}
+
+impl fmt::Display for QuestVersionConflictModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: expected version {}, current version {}",
+            self.message, self.expected_version, self.current_version
+        )
+    }
+}
+
+impl std::error::Error for QuestVersionConflictModel {}
+
+pub fn to_etag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
+pub fn from_etag(etag: &str) -> Option<i32> {
+    etag.trim()
+        .trim_start_matches("W/")
+        .trim_matches('"')
+        .parse::<i32>()
+        .ok()
+}
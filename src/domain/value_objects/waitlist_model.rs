@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WaitlistPositionModel {
+    pub quest_id: i32,
+    pub position: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaitlistPromotionModel {
+    pub promoted: Option<(i32, i32)>,
+    pub cleared_waitlist_ids: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WaitlistCandidatesModel {
+    pub eligible_adventurer_ids: Vec<i32>,
+    pub ineligible_adventurer_ids: Vec<i32>,
+}
+
+pub fn next_in_line(
+    waitlist: &[(i32, i32)],
+    crew_adventurer_ids: &[i32],
+    waitlist_candidates: &WaitlistCandidatesModel,
+) -> WaitlistPromotionModel {
+    let mut cleared_waitlist_ids = Vec::new();
+
+    for &(waitlist_id, adventurer_id) in waitlist.iter() {
+        if crew_adventurer_ids.contains(&adventurer_id)
+            || waitlist_candidates
+                .ineligible_adventurer_ids
+                .contains(&adventurer_id)
+        {
+            cleared_waitlist_ids.push(waitlist_id);
+            continue;
+        }
+
+        if waitlist_candidates
+            .eligible_adventurer_ids
+            .contains(&adventurer_id)
+        {
+            cleared_waitlist_ids.push(waitlist_id);
+
+            return WaitlistPromotionModel {
+                promoted: Some((waitlist_id, adventurer_id)),
+                cleared_waitlist_ids,
+            };
+        }
+    }
+
+    WaitlistPromotionModel {
+        promoted: None,
+        cleared_waitlist_ids,
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::value_objects::waitlist_model::{
+        next_in_line, WaitlistCandidatesModel, WaitlistPromotionModel,
+    };
+
+    fn eligible(adventurer_ids: &[i32]) -> WaitlistCandidatesModel {
+        WaitlistCandidatesModel {
+            eligible_adventurer_ids: adventurer_ids.to_vec(),
+            ineligible_adventurer_ids: vec![],
+        }
+    }
+
+    #[test]
+    fn test_next_in_line_promotes_first_waiting_adventurer() {
+        let promotion = next_in_line(&[(1, 10), (2, 20)], &[30], &eligible(&[10, 20]));
+
+        assert_eq!(
+            promotion,
+            WaitlistPromotionModel {
+                promoted: Some((1, 10)),
+                cleared_waitlist_ids: vec![1],
+            }
+        );
+    }
+
+    #[test]
+    fn test_next_in_line_skips_adventurers_already_in_crew() {
+        let promotion = next_in_line(
+            &[(1, 10), (2, 20), (3, 30)],
+            &[10, 20],
+            &eligible(&[10, 20, 30]),
+        );
+
+        assert_eq!(
+            promotion,
+            WaitlistPromotionModel {
+                promoted: Some((3, 30)),
+                cleared_waitlist_ids: vec![1, 2, 3],
+            }
+        );
+    }
+
+    #[test]
+    fn test_next_in_line_clears_stale_entries_when_nobody_can_be_promoted() {
+        let promotion = next_in_line(&[(1, 10), (2, 20)], &[10, 20], &eligible(&[10, 20]));
+
+        assert_eq!(
+            promotion,
+            WaitlistPromotionModel {
+                promoted: None,
+                cleared_waitlist_ids: vec![1, 2],
+            }
+        );
+    }
+
+    #[test]
+    fn test_next_in_line_clears_ineligible_adventurers_and_promotes_the_next() {
+        let promotion = next_in_line(
+            &[(1, 10), (2, 20), (3, 30)],
+            &[],
+            &WaitlistCandidatesModel {
+                eligible_adventurer_ids: vec![20, 30],
+                ineligible_adventurer_ids: vec![10],
+            },
+        );
+
+        assert_eq!(
+            promotion,
+            WaitlistPromotionModel {
+                promoted: Some((2, 20)),
+                cleared_waitlist_ids: vec![1, 2],
+            }
+        );
+    }
+
+    #[test]
+    fn test_next_in_line_keeps_eligible_adventurers_when_no_seat_is_free() {
+        let promotion = next_in_line(
+            &[(1, 10), (2, 20)],
+            &[],
+            &WaitlistCandidatesModel {
+                eligible_adventurer_ids: vec![],
+                ineligible_adventurer_ids: vec![10],
+            },
+        );
+
+        assert_eq!(
+            promotion,
+            WaitlistPromotionModel {
+                promoted: None,
+                cleared_waitlist_ids: vec![1],
+            }
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum WebhookDeliveryStatuses {
+    #[default]
+    Pending,
+    Delivered,
+    Failed,
+}
+
+impl fmt::Display for WebhookDeliveryStatuses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookDeliveryStatuses::Pending => write!(f, "Pending"),
+            WebhookDeliveryStatuses::Delivered => write!(f, "Delivered"),
+            WebhookDeliveryStatuses::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+impl WebhookDeliveryStatuses {
+    pub fn try_from(status: &str) -> Result<Self> {
+        match status {
+            "Pending" => Ok(WebhookDeliveryStatuses::Pending),
+            "Delivered" => Ok(WebhookDeliveryStatuses::Delivered),
+            "Failed" => Ok(WebhookDeliveryStatuses::Failed),
+            _ => Err(anyhow::anyhow!("Invalid webhook delivery status")),
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime};
+use ring::hmac;
+use serde::{Deserialize, Serialize};
+use url::{Host, Url};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::entities::{
+    webhook_deliveries::AddWebhookDeliveryEntity,
+    webhooks::{AddWebhookEntity, EditWebhookEntity},
+};
+
+use super::{
+    quest_event_model::{QuestEventKinds, QuestEventModel},
+    webhook_delivery_statuses::WebhookDeliveryStatuses,
+};
+
+pub const WEBHOOK_RETRY_BASE_SECONDS: i64 = 30;
+pub const WEBHOOK_RETRY_MAX_SECONDS: i64 = 3600;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct WebhookModel {
+    pub id: i32,
+    pub guild_commander_id: i32,
+    pub url: String,
+    pub events: Vec<QuestEventKinds>,
+    pub active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddWebhookModel {
+    pub url: String,
+    pub secret: String,
+    pub events: Option<Vec<QuestEventKinds>>,
+}
+
+impl AddWebhookModel {
+    pub fn to_entity(&self, guild_commander_id: i32) -> AddWebhookEntity {
+        AddWebhookEntity {
+            guild_commander_id,
+            url: self.url.trim().to_string(),
+            secret: self.secret.clone(),
+            events: serde_json::json!(self.events.clone().unwrap_or_default()),
+            active: true,
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EditWebhookModel {
+    pub url: Option<String>,
+    pub secret: Option<String>,
+    pub events: Option<Vec<QuestEventKinds>>,
+    pub active: Option<bool>,
+}
+
+impl EditWebhookModel {
+    pub fn to_entity(&self) -> EditWebhookEntity {
+        EditWebhookEntity {
+            url: self.url.as_ref().map(|url| url.trim().to_string()),
+            secret: self.secret.clone(),
+            events: self.events.as_ref().map(|events| serde_json::json!(events)),
+            active: self.active,
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct WebhookDeliveryModel {
+    pub id: i32,
+    pub webhook_id: i32,
+    pub event_kind: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: NaiveDateTime,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct WebhookDeliveryFilter {
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookRequestModel {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+pub fn to_delivery_entity(
+    webhook_id: i32,
+    idempotency_key: &str,
+    event: &QuestEventModel,
+) -> AddWebhookDeliveryEntity {
+    AddWebhookDeliveryEntity {
+        webhook_id,
+        event_kind: event.kind.to_string(),
+        payload: serde_json::json!(event.to_public()),
+        status: WebhookDeliveryStatuses::Pending.to_string(),
+        next_attempt_at: chrono::Utc::now().naive_utc(),
+        created_at: chrono::Utc::now().naive_utc(),
+        updated_at: chrono::Utc::now().naive_utc(),
+        idempotency_key: Some(idempotency_key.to_string()),
+    }
+}
+
+pub fn sign_payload(secret: &str, body: &str) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let tag = hmac::sign(&key, body.as_bytes());
+
+    let signature = tag
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+
+    format!("sha256={}", signature)
+}
+
+pub fn retry_backoff(attempts: i32) -> Duration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    let seconds = WEBHOOK_RETRY_BASE_SECONDS
+        .saturating_mul(2_i64.pow(exponent))
+        .min(WEBHOOK_RETRY_MAX_SECONDS);
+
+    Duration::seconds(seconds)
+}
+
+pub fn parse_webhook_url(url: &str) -> Result<Url> {
+    let url = Url::parse(url.trim()).map_err(|_| anyhow::anyhow!("Webhook URL is invalid"))?;
+
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err(anyhow::anyhow!("Webhook URL must be an http(s) URL"));
+    }
+
+    if !url.username().is_empty() || url.password().is_some() {
+        return Err(anyhow::anyhow!("Webhook URL must not contain credentials"));
+    }
+
+    if url.host().is_none() {
+        return Err(anyhow::anyhow!("Webhook URL must have a host"));
+    }
+
+    Ok(url)
+}
+
+pub fn ensure_public_host(url: &Url) -> Result<()> {
+    let is_public = match url.host() {
+        Some(Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain != "localhost" && !domain.ends_with(".localhost")
+        }
+        Some(Host::Ipv4(ip)) => is_public_ip(IpAddr::V4(ip)),
+        Some(Host::Ipv6(ip)) => is_public_ip(IpAddr::V6(ip)),
+        None => false,
+    };
+
+    if !is_public {
+        return Err(anyhow::anyhow!(
+            "Webhook URL must not target a private address"
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ipv4(ip),
+            None => is_public_ipv6(ip),
+        },
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        || first == 0
+        || first >= 240
+        || (first == 100 && (64..128).contains(&second))
+        || (first == 198 && (18..20).contains(&second)))
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let first_segment = ip.segments()[0];
+
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || (first_segment & 0xfe00) == 0xfc00
+        || (first_segment & 0xffc0) == 0xfe80
+        || first_segment == 0x2001 && ip.segments()[1] == 0x0db8)
+}
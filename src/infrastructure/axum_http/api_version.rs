@@ -0,0 +1,5 @@
+pub const API_V1: &str = "/api/v1";
+
+pub fn v1_location(path: &str) -> String {
+    format!("{}{}", API_V1, path)
+}
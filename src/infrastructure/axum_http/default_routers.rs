@@ -1,9 +1,17 @@
-use axum::{http::StatusCode, response::IntoResponse};
+use axum::{http::StatusCode, response::IntoResponse, Json};
+
+use crate::domain::value_objects::api_response_model::{ErrorModel, MessageModel};
 
 pub async fn not_found() -> impl IntoResponse {
-    (StatusCode::NOT_FOUND, "Not Found").into_response()
+    (StatusCode::NOT_FOUND, Json(ErrorModel::new("Not Found"))).into_response()
 }
 
+#[utoipa::path(
+    get,
+    path = "/health-check",
+    tag = "health-check",
+    responses((status = 200, description = "Server is up", body = MessageModel))
+)]
 pub async fn health_check() -> impl IntoResponse {
-    (StatusCode::OK, "OK").into_response()
+    (StatusCode::OK, Json(MessageModel::new("OK"))).into_response()
 }
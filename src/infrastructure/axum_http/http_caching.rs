@@ -0,0 +1,89 @@
+use axum::{
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use chrono::{NaiveDateTime, SubsecRound};
+use ring::digest;
+
+use crate::domain::value_objects::quest_model::QuestModel;
+
+pub const CACHE_CONTROL: &str = "public, max-age=0, must-revalidate";
+pub const HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+pub fn collection_etag(quests: &[QuestModel]) -> String {
+    let fingerprint = quests
+        .iter()
+        .map(|quest| format!("{}:{}", quest.id, quest.version))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let hash = digest::digest(&digest::SHA256, fingerprint.as_bytes())
+        .as_ref()
+        .iter()
+        .take(16)
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+
+    format!("W/\"{}\"", hash)
+}
+
+pub fn caching_headers(etag: &str, last_modified: Option<NaiveDateTime>) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static(CACHE_CONTROL),
+    );
+
+    if let Ok(etag) = HeaderValue::from_str(etag) {
+        headers.insert(header::ETAG, etag);
+    }
+
+    if let Some(last_modified) = last_modified {
+        if let Ok(last_modified) =
+            HeaderValue::from_str(&last_modified.format(HTTP_DATE_FORMAT).to_string())
+        {
+            headers.insert(header::LAST_MODIFIED, last_modified);
+        }
+    }
+
+    headers
+}
+
+pub fn is_not_modified(
+    request_headers: &HeaderMap,
+    etag: &str,
+    last_modified: Option<NaiveDateTime>,
+) -> bool {
+    if let Some(if_none_match) = request_headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|if_none_match| if_none_match.to_str().ok())
+    {
+        return if_none_match
+            .split(',')
+            .map(str::trim)
+            .any(|tag| tag == "*" || opaque_tag(tag) == opaque_tag(etag));
+    }
+
+    let if_modified_since = request_headers
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|if_modified_since| if_modified_since.to_str().ok())
+        .and_then(|if_modified_since| {
+            NaiveDateTime::parse_from_str(if_modified_since, HTTP_DATE_FORMAT).ok()
+        });
+
+    match (if_modified_since, last_modified) {
+        (Some(if_modified_since), Some(last_modified)) => {
+            last_modified.trunc_subsecs(0) <= if_modified_since
+        }
+        _ => false,
+    }
+}
+
+pub fn not_modified(headers: HeaderMap) -> Response {
+    (StatusCode::NOT_MODIFIED, headers).into_response()
+}
+
+fn opaque_tag(tag: &str) -> &str {
+    tag.trim_start_matches("W/")
+}
@@ -1,7 +1,12 @@
 use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use anyhow::Result;
-use axum::{http::Method, routing::get, Router};
+use axum::{
+    http::{Method, StatusCode},
+    middleware,
+    routing::get,
+    Router,
+};
 use tokio::net::TcpListener;
 use tower_http::{
     cors::{Any, CorsLayer},
@@ -12,15 +17,84 @@ use tower_http::{
 use tracing::info;
 
 use crate::{
+    application::quest_events::QuestEventBus,
     config::config_model::DotEnvyConfig,
-    infrastructure::{axum_http::routers, postgres::postgres_connection::PgPoolSquad},
+    infrastructure::{
+        axum_http::routers, background_jobs, postgres::postgres_connection::PgPoolSquad,
+    },
 };
 
-use super::default_routers;
+use super::{api_version::API_V1, default_routers, middlewares, openapi};
 
 pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
-    let app = Router::new()
-        .fallback(default_routers::not_found)
+    let quest_event_bus = Arc::new(QuestEventBus::default());
+
+    background_jobs::outbox_relay::spawn(
+        Arc::clone(&db_pool),
+        Arc::clone(&quest_event_bus),
+        Duration::from_secs(config.scheduler.outbox_relay_interval),
+    );
+
+    background_jobs::quest_expiry::spawn(
+        Arc::clone(&db_pool),
+        Duration::from_secs(config.scheduler.quest_expiry_interval),
+    );
+
+    background_jobs::quest_recurrence::spawn(
+        Arc::clone(&db_pool),
+        Duration::from_secs(config.scheduler.quest_recurrence_interval),
+    );
+
+    background_jobs::webhook_delivery::spawn(
+        Arc::clone(&db_pool),
+        Duration::from_secs(config.scheduler.webhook_delivery_interval),
+        config.webhooks.allow_private_targets,
+    );
+
+    let app = routes(
+        db_pool,
+        quest_event_bus,
+        Duration::from_secs(config.cache.quest_viewing_ttl),
+    )
+    .layer(TimeoutLayer::with_status_code(
+        StatusCode::REQUEST_TIMEOUT,
+        Duration::from_secs(config.server.timeout),
+    ))
+    .layer(RequestBodyLimitLayer::new(
+        (config.server.body_limit * 1024 * 1024).try_into()?,
+    ))
+    .layer(
+        CorsLayer::new()
+            .allow_methods([
+                Method::GET,
+                Method::POST,
+                Method::PUT,
+                Method::PATCH,
+                Method::DELETE,
+            ])
+            .allow_origin(Any),
+    )
+    .layer(TraceLayer::new_for_http());
+
+    let addr = SocketAddr::from(([0, 0, 0, 0], config.server.port));
+
+    let listener = TcpListener::bind(addr).await?;
+
+    info!("Server is running on port {}", config.server.port);
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
+
+    Ok(())
+}
+
+pub fn routes(
+    db_pool: Arc<PgPoolSquad>,
+    quest_event_bus: Arc<QuestEventBus>,
+    quest_viewing_ttl: Duration,
+) -> Router {
+    let api_routes = Router::new()
         .nest(
             "/journey-ledger",
             routers::journey_ledger::routes(Arc::clone(&db_pool)),
@@ -29,10 +103,30 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
             "/quest-ops",
             routers::quest_ops::routes(Arc::clone(&db_pool)),
         )
+        .nest(
+            "/quest-templates",
+            routers::quest_templates::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/quest-series",
+            routers::quest_series::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/quest-categories",
+            routers::quest_categories::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/quest-comments",
+            routers::quest_comments::routes(Arc::clone(&db_pool)),
+        )
         .nest(
             "/crew-switchboard",
             routers::crew_switchboard::routes(Arc::clone(&db_pool)),
         )
+        .nest(
+            "/crew-management",
+            routers::crew_management::routes(Arc::clone(&db_pool)),
+        )
         .nest(
             "/guild-commanders",
             routers::guild_commanders::routes(Arc::clone(&db_pool)),
@@ -43,43 +137,29 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
         )
         .nest(
             "/quest-viewing",
-            routers::quest_viewing::routes(Arc::clone(&db_pool)),
+            routers::quest_viewing::routes(Arc::clone(&db_pool), quest_viewing_ttl),
+        )
+        .nest(
+            "/leaderboards",
+            routers::leaderboards::routes(Arc::clone(&db_pool)),
         )
+        .nest("/webhooks", routers::webhooks::routes(Arc::clone(&db_pool)))
+        .nest(
+            "/notifications",
+            routers::notifications::routes(Arc::clone(&db_pool)),
+        )
+        .nest("/events", routers::events::routes(quest_event_bus))
         .nest(
             "/authentication",
             routers::authentication::routes(Arc::clone(&db_pool)),
         )
-        .route("/health-check", get(default_routers::health_check))
-        .layer(TimeoutLayer::new(Duration::from_secs(
-            config.server.timeout,
-        )))
-        .layer(RequestBodyLimitLayer::new(
-            (config.server.body_limit * 1024 * 1024).try_into()?,
-        ))
-        .layer(
-            CorsLayer::new()
-                .allow_methods([
-                    Method::GET,
-                    Method::POST,
-                    Method::PUT,
-                    Method::PATCH,
-                    Method::DELETE,
-                ])
-                .allow_origin(Any),
-        )
-        .layer(TraceLayer::new_for_http());
+        .route("/health-check", get(default_routers::health_check));
 
-    let addr = SocketAddr::from(([0, 0, 0, 0], config.server.port));
-
-    let listener = TcpListener::bind(addr).await?;
-
-    info!("Server is running on port {}", config.server.port);
-
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
-        .await?;
-
-    Ok(())
+    Router::new()
+        .fallback(default_routers::not_found)
+        .nest(API_V1, api_routes.clone())
+        .merge(api_routes.layer(middleware::from_fn(middlewares::deprecated_alias)))
+        .merge(openapi::routes())
 }
 
 async fn shutdown_signal() {
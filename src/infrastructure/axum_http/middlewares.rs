@@ -1,19 +1,21 @@
 use axum::{
     extract::Request,
-    http::{header, StatusCode},
+    http::{header, HeaderName, HeaderValue, StatusCode},
     middleware::Next,
-    response::Response,
+    response::{IntoResponse, Response},
+    Json,
 };
 
 use crate::{
     config::config_loader::{get_adventurers_secret_env, get_guild_commanders_secret_env},
-    infrastructure::jwt_authentication,
+    domain::value_objects::{
+        api_response_model::ErrorModel,
+        authenticated_roles::{AuthenticatedIdentity, AuthenticatedRoles},
+    },
+    infrastructure::{axum_http::api_version::v1_location, jwt_authentication},
 };
 
-pub async fn adventurers_authorization(
-    mut req: Request,
-    next: Next,
-) -> Result<Response, StatusCode> {
+pub async fn adventurers_authorization(mut req: Request, next: Next) -> Result<Response, Response> {
     if let Some(cookie_header) = req.headers().get(header::COOKIE) {
         if let Ok(cookie_str) = cookie_header.to_str() {
             let access_token = get_cookie_value(cookie_str, "act");
@@ -31,13 +33,13 @@ pub async fn adventurers_authorization(
         }
     }
 
-    Err(StatusCode::UNAUTHORIZED)
+    Err(unauthorized())
 }
 
 pub async fn guild_commanders_authorization(
     mut req: Request,
     next: Next,
-) -> Result<Response, StatusCode> {
+) -> Result<Response, Response> {
     if let Some(cookie_header) = req.headers().get(header::COOKIE) {
         if let Ok(cookie_str) = cookie_header.to_str() {
             let access_token = get_cookie_value(cookie_str, "act");
@@ -55,7 +57,65 @@ pub async fn guild_commanders_authorization(
         }
     }
 
-    Err(StatusCode::UNAUTHORIZED)
+    Err(unauthorized())
+}
+
+pub async fn authenticated_authorization(
+    mut req: Request,
+    next: Next,
+) -> Result<Response, Response> {
+    if let Some(cookie_header) = req.headers().get(header::COOKIE) {
+        if let Ok(cookie_str) = cookie_header.to_str() {
+            let access_token = get_cookie_value(cookie_str, "act");
+
+            if let Some(token) = access_token {
+                let adventurers_secret = get_adventurers_secret_env()
+                    .map(|secret_env| (AuthenticatedRoles::Adventurer, secret_env.secret));
+                let guild_commanders_secret = get_guild_commanders_secret_env()
+                    .map(|secret_env| (AuthenticatedRoles::GuildCommander, secret_env.secret));
+
+                for (role, secret) in [adventurers_secret, guild_commanders_secret]
+                    .into_iter()
+                    .flatten()
+                {
+                    if let Ok(claims) = jwt_authentication::verify_token(secret, token.clone()) {
+                        if let Ok(id) = claims.sub.parse::<i32>() {
+                            req.extensions_mut()
+                                .insert(AuthenticatedIdentity { role, id });
+                            return Ok(next.run(req).await);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Err(unauthorized())
+}
+
+pub async fn deprecated_alias(req: Request, next: Next) -> Response {
+    let successor = v1_location(req.uri().path());
+    let mut response = next.run(req).await;
+
+    let headers = response.headers_mut();
+    headers.insert(
+        HeaderName::from_static("deprecation"),
+        HeaderValue::from_static("true"),
+    );
+    if let Ok(link) = HeaderValue::from_str(&format!("<{}>; rel=\"successor-version\"", successor))
+    {
+        headers.insert(header::LINK, link);
+    }
+
+    response
+}
+
+fn unauthorized() -> Response {
+    (
+        StatusCode::UNAUTHORIZED,
+        Json(ErrorModel::new("Unauthorized")),
+    )
+        .into_response()
 }
 
 fn get_cookie_value(cookie_header: &str, key: &str) -> Option<String> {
@@ -1,4 +1,10 @@
+pub mod api_version;
 pub mod default_routers;
+pub mod http_caching;
 pub mod http_serve;
 pub mod middlewares;
+pub mod openapi;
+pub mod openapi_test;
 pub mod routers;
+pub mod validated_json;
+pub mod validated_json_test;
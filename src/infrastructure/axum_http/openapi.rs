@@ -0,0 +1,119 @@
+use axum::Router;
+use utoipa::{
+    openapi::security::{ApiKey, ApiKeyValue, SecurityScheme},
+    Modify, OpenApi,
+};
+use utoipa_swagger_ui::SwaggerUi;
+
+use super::{default_routers, routers};
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Quests Tracker API",
+        description = "Unversioned paths remain available as deprecated aliases of /api/v1."
+    ),
+    servers((url = "/api/v1")),
+    paths(default_routers::health_check),
+    nest(
+        (
+            path = "/journey-ledger",
+            api = routers::journey_ledger::JourneyLedgerApi,
+            tags = ["journey-ledger"]
+        ),
+        (
+            path = "/quest-ops",
+            api = routers::quest_ops::QuestOpsApi,
+            tags = ["quest-ops"]
+        ),
+        (
+            path = "/quest-templates",
+            api = routers::quest_templates::QuestTemplatesApi,
+            tags = ["quest-templates"]
+        ),
+        (
+            path = "/quest-series",
+            api = routers::quest_series::QuestSeriesApi,
+            tags = ["quest-series"]
+        ),
+        (
+            path = "/quest-categories",
+            api = routers::quest_categories::QuestCategoriesApi,
+            tags = ["quest-categories"]
+        ),
+        (
+            path = "/quest-comments",
+            api = routers::quest_comments::QuestCommentsApi,
+            tags = ["quest-comments"]
+        ),
+        (
+            path = "/crew-switchboard",
+            api = routers::crew_switchboard::CrewSwitchboardApi,
+            tags = ["crew-switchboard"]
+        ),
+        (
+            path = "/crew-management",
+            api = routers::crew_management::CrewManagementApi,
+            tags = ["crew-management"]
+        ),
+        (
+            path = "/guild-commanders",
+            api = routers::guild_commanders::GuildCommandersApi,
+            tags = ["guild-commanders"]
+        ),
+        (
+            path = "/adventurers",
+            api = routers::adventurers::AdventurersApi,
+            tags = ["adventurers"]
+        ),
+        (
+            path = "/quest-viewing",
+            api = routers::quest_viewing::QuestViewingApi,
+            tags = ["quest-viewing"]
+        ),
+        (
+            path = "/leaderboards",
+            api = routers::leaderboards::LeaderboardsApi,
+            tags = ["leaderboards"]
+        ),
+        (
+            path = "/webhooks",
+            api = routers::webhooks::WebhooksApi,
+            tags = ["webhooks"]
+        ),
+        (
+            path = "/notifications",
+            api = routers::notifications::NotificationsApi,
+            tags = ["notifications"]
+        ),
+        (
+            path = "/events",
+            api = routers::events::EventsApi,
+            tags = ["events"]
+        ),
+        (
+            path = "/authentication",
+            api = routers::authentication::AuthenticationApi,
+            tags = ["authentication"]
+        )
+    ),
+    modifiers(&AccessTokenCookie)
+)]
+pub struct ApiDoc;
+
+struct AccessTokenCookie;
+
+impl Modify for AccessTokenCookie {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "act",
+                SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new("act"))),
+            );
+        }
+    }
+}
+
+pub fn routes() -> Router {
+    Router::new().merge(SwaggerUi::new("/docs").url("/openapi.json", ApiDoc::openapi()))
+}
@@ -0,0 +1,172 @@
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use axum::{
+        body::Body,
+        http::{header, Method, Request},
+        Router,
+    };
+    use diesel::{
+        r2d2::{ConnectionManager, Pool},
+        PgConnection,
+    };
+    use tower::util::ServiceExt;
+    use utoipa::{openapi::path::PathItem, OpenApi};
+
+    use crate::{
+        application::quest_events::QuestEventBus,
+        infrastructure::axum_http::{api_version::API_V1, http_serve, openapi::ApiDoc},
+    };
+
+    fn router() -> Router {
+        let db_pool = Pool::builder().build_unchecked(ConnectionManager::<PgConnection>::new(
+            "postgres://localhost/unused",
+        ));
+
+        http_serve::routes(
+            Arc::new(db_pool),
+            Arc::new(QuestEventBus::default()),
+            Duration::from_secs(1),
+        )
+    }
+
+    fn routed_paths(router: &Router) -> Vec<String> {
+        let router = format!("{:?}", router);
+        let (path_router, _) = router
+            .split_once("fallback_router")
+            .expect("Router debug output lists its fallback router");
+
+        let mut paths = path_router
+            .split("): \"")
+            .skip(1)
+            .filter_map(|route| route.split_once('"'))
+            .filter_map(|(path, _)| path.strip_prefix(API_V1))
+            .map(|path| {
+                path.split('/')
+                    .map(|segment| match segment.strip_prefix(':') {
+                        Some(param) => format!("{{{}}}", param),
+                        None => segment.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("/")
+            })
+            .collect::<Vec<_>>();
+
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+
+    async fn routed_methods(router: &Router, path: &str) -> Vec<String> {
+        let uri = path
+            .split('/')
+            .map(|segment| match segment.starts_with('{') {
+                true => "1",
+                false => segment,
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let response = router
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(Method::TRACE)
+                    .uri(format!("{}{}", API_V1, uri))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        response
+            .headers()
+            .get(header::ALLOW)
+            .and_then(|allow| allow.to_str().ok())
+            .unwrap_or_default()
+            .split(',')
+            .map(|method| method.trim().to_lowercase())
+            .filter(|method| !method.is_empty() && method != "head")
+            .collect()
+    }
+
+    fn documents(path_item: &PathItem, method: &str) -> bool {
+        match method {
+            "get" => path_item.get.is_some(),
+            "post" => path_item.post.is_some(),
+            "put" => path_item.put.is_some(),
+            "patch" => path_item.patch.is_some(),
+            "delete" => path_item.delete.is_some(),
+            _ => false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_routes_are_served_under_api_v1() {
+        let openapi = ApiDoc::openapi();
+        let router = router();
+
+        assert!(routed_paths(&router).contains(&"/health-check".to_string()));
+        assert_eq!(routed_methods(&router, "/health-check").await, vec!["get"]);
+        assert!(openapi
+            .servers
+            .unwrap_or_default()
+            .iter()
+            .any(|server| server.url == API_V1));
+    }
+
+    #[tokio::test]
+    async fn test_every_route_is_documented() {
+        let openapi = ApiDoc::openapi();
+        let router = router();
+
+        let mut routes = Vec::new();
+        for path in routed_paths(&router) {
+            for method in routed_methods(&router, &path).await {
+                routes.push((method, path.clone()));
+            }
+        }
+
+        let undocumented = routes
+            .iter()
+            .filter(|(method, path)| {
+                !openapi
+                    .paths
+                    .paths
+                    .get(path)
+                    .is_some_and(|path_item| documents(path_item, method))
+            })
+            .collect::<Vec<_>>();
+
+        assert!(routes.len() > 1);
+        assert!(
+            undocumented.is_empty(),
+            "Undocumented routes: {:?}",
+            undocumented
+        );
+    }
+
+    #[tokio::test]
+    async fn test_every_documented_path_is_routed() {
+        let openapi = ApiDoc::openapi();
+        let router = router();
+
+        let mut unrouted = Vec::new();
+        for (documented_path, path_item) in openapi.paths.paths.iter() {
+            let routed_methods = routed_methods(&router, documented_path).await;
+
+            for method in ["get", "post", "put", "patch", "delete"] {
+                if documents(path_item, method) && !routed_methods.iter().any(|m| m == method) {
+                    unrouted.push((method, documented_path.clone()));
+                }
+            }
+        }
+
+        assert!(
+            unrouted.is_empty(),
+            "Unrouted documented paths: {:?}",
+            unrouted
+        );
+    }
+}
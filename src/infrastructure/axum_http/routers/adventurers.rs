@@ -1,27 +1,59 @@
 use std::sync::Arc;
 
-use axum::{extract::State, http::StatusCode, response::IntoResponse, routing::post, Json, Router};
+use axum::{
+    extract::State,
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post, put},
+    Extension, Json, Router,
+};
+use utoipa::OpenApi;
 
 use crate::{
     application::use_cases::adventurers::AdventurersUseCase,
     domain::{
         repositories::adventurers::AdventurersRepository,
-        value_objects::adventurer_model::RegisterAdventurerModel,
+        value_objects::{
+            adventurer_model::{
+                AdventurerProfileModel, AdventurerSkillsModel, RegisterAdventurerModel,
+            },
+            api_response_model::{CreatedModel, ErrorModel},
+        },
     },
-    infrastructure::postgres::{
-        postgres_connection::PgPoolSquad, repositories::adventurers::AdventurerPostgres,
+    infrastructure::{
+        axum_http::middlewares::adventurers_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad, repositories::adventurers::AdventurerPostgres,
+        },
     },
 };
 
+#[derive(OpenApi)]
+#[openapi(paths(register, profile, set_skills))]
+pub struct AdventurersApi;
+
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let adventurers_repository = AdventurerPostgres::new(db_pool);
     let adventurers_use_case = AdventurersUseCase::new(Arc::new(adventurers_repository));
 
     Router::new()
+        .route("/profile", get(profile))
+        .route("/skills", put(set_skills))
+        .route_layer(middleware::from_fn(adventurers_authorization))
         .route("/", post(register))
         .with_state(Arc::new(adventurers_use_case))
 }
 
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = RegisterAdventurerModel,
+    responses(
+        (status = 201, description = "Adventurer registered", body = CreatedModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    )
+)]
 pub async fn register<T>(
     State(adventurers_use_case): State<Arc<AdventurersUseCase<T>>>,
     Json(register_adventurer_model): Json<RegisterAdventurerModel>,
@@ -35,9 +67,63 @@ where
     {
         Ok(adventurer_id) => (
             StatusCode::CREATED,
-            format!("Registered adventurer id: {} successfully", adventurer_id),
-        ),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+            Json(CreatedModel::new(
+                adventurer_id,
+                format!("Registered adventurer id: {} successfully", adventurer_id),
+            )),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/profile",
+    responses(
+        (status = 200, description = "Adventurer profile", body = AdventurerProfileModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn profile<T>(
+    State(adventurers_use_case): State<Arc<AdventurersUseCase<T>>>,
+    Extension(adventurer_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: AdventurersRepository + Send + Sync,
+{
+    match adventurers_use_case.profile(adventurer_id).await {
+        Ok(profile) => (StatusCode::OK, Json(profile)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/skills",
+    request_body = AdventurerSkillsModel,
+    responses(
+        (status = 200, description = "Adventurer skills replaced", body = AdventurerSkillsModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn set_skills<T>(
+    State(adventurers_use_case): State<Arc<AdventurersUseCase<T>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Json(adventurer_skills_model): Json<AdventurerSkillsModel>,
+) -> impl IntoResponse
+where
+    T: AdventurersRepository + Send + Sync,
+{
+    match adventurers_use_case
+        .set_skills(adventurer_id, adventurer_skills_model)
+        .await
+    {
+        Ok(skills) => (StatusCode::OK, Json(AdventurerSkillsModel { skills })).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
     }
-    .into_response()
 }
@@ -9,12 +9,16 @@ use axum::{
 };
 use axum_extra::extract::cookie::{Cookie, CookieJar};
 use cookie::time::Duration;
+use utoipa::OpenApi;
 
 use crate::{
     application::use_cases::authentication::AuthenticationUseCase,
     config::{config_loader::get_stage, stage::Stage},
-    domain::repositories::{
-        adventurers::AdventurersRepository, guild_commanders::GuildCommandersRepository,
+    domain::{
+        repositories::{
+            adventurers::AdventurersRepository, guild_commanders::GuildCommandersRepository,
+        },
+        value_objects::api_response_model::{ErrorModel, MessageModel},
     },
     infrastructure::{
         jwt_authentication::authentication_model::LoginModel,
@@ -27,6 +31,15 @@ use crate::{
     },
 };
 
+#[derive(OpenApi)]
+#[openapi(paths(
+    adventurers_login,
+    adventurers_refresh_token,
+    guild_commanders_login,
+    guild_commanders_refresh_token,
+))]
+pub struct AuthenticationApi;
+
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let adventurers_repository = AdventurerPostgres::new(Arc::clone(&db_pool));
     let guild_commanders_repository = GuildCommanderPostgres::new(Arc::clone(&db_pool));
@@ -49,6 +62,15 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .with_state(Arc::new(authentication_use_case))
 }
 
+#[utoipa::path(
+    post,
+    path = "/adventurers/login",
+    request_body = LoginModel,
+    responses(
+        (status = 200, description = "Access and refresh token cookies set", body = MessageModel),
+        (status = 401, description = "Invalid credentials", body = ErrorModel)
+    )
+)]
 pub async fn adventurers_login<T1, T2>(
     State(authentication_use_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
     Json(login_model): Json<LoginModel>,
@@ -88,12 +110,30 @@ where
                 HeaderValue::from_str(&rft_cookie.to_string()).unwrap(),
             );
 
-            (StatusCode::OK, headers, "Login successfully").into_response()
+            (
+                StatusCode::OK,
+                headers,
+                Json(MessageModel::new("Login successfully")),
+            )
+                .into_response()
         }
-        Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
+        Err(e) => (StatusCode::UNAUTHORIZED, Json(ErrorModel::new(e))).into_response(),
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/adventurers/refresh-token",
+    responses(
+        (
+            status = 200,
+            description = "Access and refresh token cookies renewed",
+            body = MessageModel
+        ),
+        (status = 400, description = "Refresh token cookie is missing", body = ErrorModel),
+        (status = 401, description = "Invalid refresh token", body = ErrorModel)
+    )
+)]
 pub async fn adventurers_refresh_token<T1, T2>(
     State(authentication_use_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
     jar: CookieJar,
@@ -138,16 +178,34 @@ where
                     HeaderValue::from_str(&rft_cookie.to_string()).unwrap(),
                 );
 
-                (StatusCode::OK, headers, "Login successfully").into_response()
+                (
+                    StatusCode::OK,
+                    headers,
+                    Json(MessageModel::new("Login successfully")),
+                )
+                    .into_response()
             }
-            Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
+            Err(e) => (StatusCode::UNAUTHORIZED, Json(ErrorModel::new(e))).into_response(),
         };
 
         return response;
     }
-    (StatusCode::BAD_REQUEST, "Refresh token not found").into_response()
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorModel::new("Refresh token not found")),
+    )
+        .into_response()
 }
 
+#[utoipa::path(
+    post,
+    path = "/guild-commanders/login",
+    request_body = LoginModel,
+    responses(
+        (status = 200, description = "Access and refresh token cookies set", body = MessageModel),
+        (status = 401, description = "Invalid credentials", body = ErrorModel)
+    )
+)]
 pub async fn guild_commanders_login<T1, T2>(
     State(authentication_use_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
     Json(login_model): Json<LoginModel>,
@@ -190,12 +248,30 @@ where
                 HeaderValue::from_str(&rft_cookie.to_string()).unwrap(),
             );
 
-            (StatusCode::OK, headers, "Login successfully").into_response()
+            (
+                StatusCode::OK,
+                headers,
+                Json(MessageModel::new("Login successfully")),
+            )
+                .into_response()
         }
-        Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
+        Err(e) => (StatusCode::UNAUTHORIZED, Json(ErrorModel::new(e))).into_response(),
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/guild-commanders/refresh-token",
+    responses(
+        (
+            status = 200,
+            description = "Access and refresh token cookies renewed",
+            body = MessageModel
+        ),
+        (status = 400, description = "Refresh token cookie is missing", body = ErrorModel),
+        (status = 401, description = "Invalid refresh token", body = ErrorModel)
+    )
+)]
 pub async fn guild_commanders_refresh_token<T1, T2>(
     State(authentication_use_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
     jar: CookieJar,
@@ -240,12 +316,21 @@ where
                     HeaderValue::from_str(&rft_cookie.to_string()).unwrap(),
                 );
 
-                (StatusCode::OK, headers, "Login successfully").into_response()
+                (
+                    StatusCode::OK,
+                    headers,
+                    Json(MessageModel::new("Login successfully")),
+                )
+                    .into_response()
             }
-            Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
+            Err(e) => (StatusCode::UNAUTHORIZED, Json(ErrorModel::new(e))).into_response(),
         };
 
         return response;
     }
-    (StatusCode::BAD_REQUEST, "Refresh token not found").into_response()
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorModel::new("Refresh token not found")),
+    )
+        .into_response()
 }
@@ -0,0 +1,266 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+    Extension, Json, Router,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    application::use_cases::crew_management::CrewManagementUseCase,
+    domain::{
+        repositories::{
+            crew_switchboard::CrewSwitchboardRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            api_response_model::ErrorModel,
+            crew_member_model::{CrewMemberModel, CrewMembershipModel},
+            crew_membership_statuses::CrewMembershipStatuses,
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::guild_commanders_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                crew_switchboard::CrewSwitchboardPostgres, quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(invite, approve, reject, kick, pending_requests))]
+pub struct CrewManagementApi;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let crew_switchboard_repository = CrewSwitchboardPostgres::new(Arc::clone(&db_pool));
+    let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
+
+    let crew_management_use_case = CrewManagementUseCase::new(
+        Arc::new(crew_switchboard_repository),
+        Arc::new(quest_viewing_repository),
+    );
+
+    Router::new()
+        .route("/invite/:quest_id/:adventurer_id", post(invite))
+        .route("/approve/:quest_id/:adventurer_id", patch(approve))
+        .route("/reject/:quest_id/:adventurer_id", delete(reject))
+        .route("/kick/:quest_id/:adventurer_id", delete(kick))
+        .route("/pending/:quest_id", get(pending_requests))
+        .route_layer(middleware::from_fn(guild_commanders_authorization))
+        .with_state(Arc::new(crew_management_use_case))
+}
+
+#[utoipa::path(
+    post,
+    path = "/invite/{quest_id}/{adventurer_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("adventurer_id" = i32, Path, description = "Adventurer id")
+    ),
+    responses(
+        (status = 200, description = "Adventurer invited", body = CrewMembershipModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn invite<T1, T2>(
+    State(crew_management_use_case): State<Arc<CrewManagementUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_management_use_case
+        .invite(quest_id, guild_commander_id, adventurer_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(CrewMembershipModel {
+                quest_id,
+                adventurer_id,
+                status: Some(CrewMembershipStatuses::Invited),
+            }),
+        )
+            .into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/approve/{quest_id}/{adventurer_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("adventurer_id" = i32, Path, description = "Adventurer id")
+    ),
+    responses(
+        (status = 200, description = "Join request approved", body = CrewMembershipModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn approve<T1, T2>(
+    State(crew_management_use_case): State<Arc<CrewManagementUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_management_use_case
+        .approve(quest_id, guild_commander_id, adventurer_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(CrewMembershipModel {
+                quest_id,
+                adventurer_id,
+                status: Some(CrewMembershipStatuses::Joined),
+            }),
+        )
+            .into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/reject/{quest_id}/{adventurer_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("adventurer_id" = i32, Path, description = "Adventurer id")
+    ),
+    responses(
+        (status = 200, description = "Join request rejected", body = CrewMembershipModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn reject<T1, T2>(
+    State(crew_management_use_case): State<Arc<CrewManagementUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_management_use_case
+        .reject(quest_id, guild_commander_id, adventurer_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(CrewMembershipModel {
+                quest_id,
+                adventurer_id,
+                status: None,
+            }),
+        )
+            .into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/kick/{quest_id}/{adventurer_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("adventurer_id" = i32, Path, description = "Adventurer id")
+    ),
+    responses(
+        (status = 200, description = "Adventurer removed from the crew", body = CrewMembershipModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn kick<T1, T2>(
+    State(crew_management_use_case): State<Arc<CrewManagementUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_management_use_case
+        .kick(quest_id, guild_commander_id, adventurer_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(CrewMembershipModel {
+                quest_id,
+                adventurer_id,
+                status: None,
+            }),
+        )
+            .into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/pending/{quest_id}",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (status = 200, description = "Pending join requests", body = Vec<CrewMemberModel>),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn pending_requests<T1, T2>(
+    State(crew_management_use_case): State<Arc<CrewManagementUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_management_use_case
+        .pending_requests(quest_id, guild_commander_id)
+        .await
+    {
+        Ok(pending) => (StatusCode::OK, Json(pending)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
@@ -5,14 +5,23 @@ use axum::{
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{delete, post},
-    Extension, Router,
+    routing::{delete, get, post},
+    Extension, Json, Router,
 };
+use utoipa::OpenApi;
 
 use crate::{
     application::use_cases::crew_switchboard::CrewSwitchboardUseCase,
-    domain::repositories::{
-        crew_switchboard::CrewSwitchboardRepository, quest_viewing::QuestViewingRepository,
+    domain::{
+        repositories::{
+            crew_switchboard::CrewSwitchboardRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            api_response_model::ErrorModel, crew_member_model::CrewMembershipModel,
+            crew_membership_statuses::CrewMembershipStatuses,
+            eligibility_model::IneligibilityModel, quest_model::QuestsByStatusModel,
+            quest_objective_model::QuestObjectiveModel, waitlist_model::WaitlistPositionModel,
+        },
     },
     infrastructure::{
         axum_http::middlewares::adventurers_authorization,
@@ -25,6 +34,19 @@ use crate::{
     },
 };
 
+#[derive(OpenApi)]
+#[openapi(paths(
+    join,
+    leave,
+    my_quests,
+    join_waitlist,
+    leave_waitlist,
+    waitlist_position,
+    complete_objective,
+    reopen_objective,
+))]
+pub struct CrewSwitchboardApi;
+
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let crew_switchboard_repository = CrewSwitchboardPostgres::new(Arc::clone(&db_pool));
     let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
@@ -37,10 +59,35 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     Router::new()
         .route("/join/:quest_id", post(join))
         .route("/leave/:quest_id", delete(leave))
+        .route("/mine", get(my_quests))
+        .route("/waitlist/:quest_id", post(join_waitlist))
+        .route("/waitlist/:quest_id", delete(leave_waitlist))
+        .route("/waitlist/:quest_id", get(waitlist_position))
+        .route(
+            "/objectives/:quest_id/:objective_id",
+            post(complete_objective),
+        )
+        .route(
+            "/objectives/:quest_id/:objective_id",
+            delete(reopen_objective),
+        )
         .route_layer(middleware::from_fn(adventurers_authorization))
         .with_state(Arc::new(crew_switchboard_use_case))
 }
 
+#[utoipa::path(
+    post,
+    path = "/join/{quest_id}",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (status = 200, description = "Adventurer joined the quest", body = CrewMembershipModel),
+        (status = 202, description = "Join request is pending approval", body = CrewMembershipModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 403, description = "Adventurer is not eligible", body = IneligibilityModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
 pub async fn join<T1, T2>(
     State(crew_switchboard_use_case): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
     Extension(adventurer_id): Extension<i32>,
@@ -54,18 +101,46 @@ where
         .join(quest_id, adventurer_id)
         .await
     {
-        Ok(_) => (
+        Ok(CrewMembershipStatuses::Pending) => (
+            StatusCode::ACCEPTED,
+            Json(CrewMembershipModel {
+                quest_id,
+                adventurer_id,
+                status: Some(CrewMembershipStatuses::Pending),
+            }),
+        )
+            .into_response(),
+        Ok(status) => (
             StatusCode::OK,
-            format!(
-                "Adventurer id: {} has joined quest id {}",
-                adventurer_id, quest_id
-            ),
+            Json(CrewMembershipModel {
+                quest_id,
+                adventurer_id,
+                status: Some(status),
+            }),
         )
             .into_response(),
-        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+        Err(err) => match err.downcast::<IneligibilityModel>() {
+            Ok(ineligibility) => (StatusCode::FORBIDDEN, Json(ineligibility)).into_response(),
+            Err(err) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorModel::new(err)),
+            )
+                .into_response(),
+        },
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/leave/{quest_id}",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (status = 200, description = "Adventurer left the quest", body = CrewMembershipModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
 pub async fn leave<T1, T2>(
     State(crew_switchboard_use_case): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
     Extension(adventurer_id): Extension<i32>,
@@ -81,12 +156,232 @@ where
     {
         Ok(_) => (
             StatusCode::OK,
-            format!(
-                "Adventurer id: {} has left quest id {}",
-                adventurer_id, quest_id
-            ),
+            Json(CrewMembershipModel {
+                quest_id,
+                adventurer_id,
+                status: None,
+            }),
+        )
+            .into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/mine",
+    responses(
+        (status = 200, description = "Joined quests grouped by status", body = QuestsByStatusModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn my_quests<T1, T2>(
+    State(crew_switchboard_use_case): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_use_case.my_quests(adventurer_id).await {
+        Ok(quests_by_status) => (StatusCode::OK, Json(quests_by_status)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/waitlist/{quest_id}",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (
+            status = 201,
+            description = "Adventurer joined the waitlist",
+            body = WaitlistPositionModel
+        ),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 403, description = "Adventurer is not eligible", body = IneligibilityModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn join_waitlist<T1, T2>(
+    State(crew_switchboard_use_case): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_use_case
+        .join_waitlist(quest_id, adventurer_id)
+        .await
+    {
+        Ok(waitlist_position) => (StatusCode::CREATED, Json(waitlist_position)).into_response(),
+        Err(err) => match err.downcast::<IneligibilityModel>() {
+            Ok(ineligibility) => (StatusCode::FORBIDDEN, Json(ineligibility)).into_response(),
+            Err(err) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorModel::new(err)),
+            )
+                .into_response(),
+        },
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/waitlist/{quest_id}",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (status = 200, description = "Adventurer left the waitlist", body = CrewMembershipModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn leave_waitlist<T1, T2>(
+    State(crew_switchboard_use_case): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_use_case
+        .leave_waitlist(quest_id, adventurer_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(CrewMembershipModel {
+                quest_id,
+                adventurer_id,
+                status: None,
+            }),
+        )
+            .into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/waitlist/{quest_id}",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (status = 200, description = "Waitlist position", body = WaitlistPositionModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn waitlist_position<T1, T2>(
+    State(crew_switchboard_use_case): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_use_case
+        .waitlist_position(quest_id, adventurer_id)
+        .await
+    {
+        Ok(waitlist_position) => (StatusCode::OK, Json(waitlist_position)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/objectives/{quest_id}/{objective_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("objective_id" = i32, Path, description = "Objective id")
+    ),
+    responses(
+        (status = 200, description = "Objective completed", body = QuestObjectiveModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn complete_objective<T1, T2>(
+    State(crew_switchboard_use_case): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path((quest_id, objective_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_use_case
+        .complete_objective(quest_id, objective_id, adventurer_id)
+        .await
+    {
+        Ok(quest_objective) => (StatusCode::OK, Json(quest_objective)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/objectives/{quest_id}/{objective_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("objective_id" = i32, Path, description = "Objective id")
+    ),
+    responses(
+        (status = 200, description = "Objective reopened", body = QuestObjectiveModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn reopen_objective<T1, T2>(
+    State(crew_switchboard_use_case): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path((quest_id, objective_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match crew_switchboard_use_case
+        .reopen_objective(quest_id, objective_id, adventurer_id)
+        .await
+    {
+        Ok(quest_objective) => (StatusCode::OK, Json(quest_objective)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
         )
             .into_response(),
-        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
     }
 }
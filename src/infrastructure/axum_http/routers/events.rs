@@ -0,0 +1,68 @@
+use std::{convert::Infallible, sync::Arc};
+
+use axum::{
+    extract::{Query, State},
+    response::{
+        sse::{Event, KeepAlive},
+        IntoResponse, Sse,
+    },
+    routing::get,
+    Router,
+};
+use futures_util::stream;
+use tokio::sync::broadcast::error::RecvError;
+use utoipa::OpenApi;
+
+use crate::{
+    application::quest_events::QuestEventBus,
+    domain::value_objects::quest_event_model::{PublicQuestEventModel, QuestEventFilter},
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(subscribe))]
+pub struct EventsApi;
+
+pub fn routes(quest_event_bus: Arc<QuestEventBus>) -> Router {
+    Router::new()
+        .route("/", get(subscribe))
+        .with_state(quest_event_bus)
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    params(QuestEventFilter),
+    responses(
+        (status = 200, description = "Server-sent stream of quest events", body = PublicQuestEventModel)
+    )
+)]
+pub async fn subscribe(
+    State(quest_event_bus): State<Arc<QuestEventBus>>,
+    filter: Query<QuestEventFilter>,
+) -> impl IntoResponse {
+    let quest_ids = filter.quest_ids();
+    let receiver = quest_event_bus.subscribe();
+
+    let events = stream::unfold(receiver, move |mut receiver| {
+        let quest_ids = quest_ids.clone();
+
+        async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) if quest_ids.is_empty() || quest_ids.contains(&event.quest_id) => {
+                        let event = Event::default()
+                            .event(event.kind.to_string())
+                            .json_data(&event)
+                            .unwrap_or_default();
+
+                        return Some((Ok::<_, Infallible>(event), receiver));
+                    }
+                    Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        }
+    });
+
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        extract::{Query, State},
+        response::IntoResponse,
+    };
+    use futures_util::StreamExt;
+
+    use crate::{
+        application::quest_events::QuestEventBus,
+        domain::{
+            repositories::quest_event_handler::QuestEventHandler,
+            value_objects::{
+                outbox_event_model::OutboxEventModel,
+                quest_event_model::{QuestEventFilter, QuestEventModel},
+                quest_statuses::QuestStatuses,
+            },
+        },
+        infrastructure::axum_http::routers::events::subscribe,
+    };
+
+    #[tokio::test]
+    async fn test_subscribe_streams_events_without_recipient_ids() {
+        let quest_event_bus = Arc::new(QuestEventBus::default());
+
+        let response = subscribe(
+            State(Arc::clone(&quest_event_bus)),
+            Query(QuestEventFilter::default()),
+        )
+        .await
+        .into_response();
+
+        quest_event_bus
+            .handle(&OutboxEventModel {
+                id: 1,
+                idempotency_key: "key".to_string(),
+                event: QuestEventModel::status_changed(1, QuestStatuses::InJourney, vec![7, 8]),
+            })
+            .await
+            .unwrap();
+
+        let chunk = response
+            .into_body()
+            .into_data_stream()
+            .next()
+            .await
+            .unwrap()
+            .unwrap();
+        let sse = String::from_utf8(chunk.to_vec()).unwrap();
+
+        assert!(sse.starts_with("event: StatusChanged\n"));
+        assert!(sse.contains("\"quest_id\":1"));
+        assert!(!sse.contains("recipient_ids"));
+    }
+}
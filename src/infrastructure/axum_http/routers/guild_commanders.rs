@@ -1,18 +1,26 @@
 use std::sync::Arc;
 
 use axum::{extract::State, http::StatusCode, response::IntoResponse, routing::post, Json, Router};
+use utoipa::OpenApi;
 
 use crate::{
     application::use_cases::guild_commanders::GuildCommandersUseCase,
     domain::{
         repositories::guild_commanders::GuildCommandersRepository,
-        value_objects::guild_commander_model::RegisterGuildCommanderModel,
+        value_objects::{
+            api_response_model::{CreatedModel, ErrorModel},
+            guild_commander_model::RegisterGuildCommanderModel,
+        },
     },
     infrastructure::postgres::{
         postgres_connection::PgPoolSquad, repositories::guild_commanders::GuildCommanderPostgres,
     },
 };
 
+#[derive(OpenApi)]
+#[openapi(paths(register))]
+pub struct GuildCommandersApi;
+
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let guild_commanders_repository = GuildCommanderPostgres::new(db_pool);
     let guild_commanders_use_case =
@@ -23,6 +31,15 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .with_state(Arc::new(guild_commanders_use_case))
 }
 
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = RegisterGuildCommanderModel,
+    responses(
+        (status = 201, description = "Guild commander registered", body = CreatedModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    )
+)]
 pub async fn register<T>(
     State(guild_commanders_use_case): State<Arc<GuildCommandersUseCase<T>>>,
     Json(register_guild_commander_model): Json<RegisterGuildCommanderModel>,
@@ -36,12 +53,15 @@ where
     {
         Ok(guild_commander_id) => (
             StatusCode::CREATED,
-            format!(
-                "Registered guild commander id: {} successfully",
-                guild_commander_id
-            ),
-        ),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+            Json(CreatedModel::new(
+                guild_commander_id,
+                format!(
+                    "Registered guild commander id: {} successfully",
+                    guild_commander_id
+                ),
+            )),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
     }
-    .into_response()
 }
@@ -5,9 +5,10 @@ use axum::{
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::patch,
-    Extension, Router,
+    routing::{get, patch, post},
+    Extension, Json, Router,
 };
+use utoipa::OpenApi;
 
 use crate::{
     application::use_cases::journey_ledger::JourneyLedgerUseCase,
@@ -15,10 +16,18 @@ use crate::{
         repositories::{
             journey_ledger::JourneyLedgerRepository, quest_viewing::QuestViewingRepository,
         },
-        value_objects::quest_statuses::QuestStatuses,
+        value_objects::{
+            api_response_model::{ErrorModel, ValidationErrorModel},
+            quest_bulk_model::{BulkResultModel, BulkTransitionQuestsModel},
+            quest_model::{QuestStatusModel, QuestsByStatusModel},
+            quest_statuses::QuestStatuses,
+        },
     },
     infrastructure::{
-        axum_http::middlewares::guild_commanders_authorization,
+        axum_http::{
+            middlewares::guild_commanders_authorization, routers::quest_ops::bulk_response,
+            validated_json::ValidatedJson,
+        },
         postgres::{
             postgres_connection::PgPoolSquad,
             repositories::{
@@ -28,6 +37,10 @@ use crate::{
     },
 };
 
+#[derive(OpenApi)]
+#[openapi(paths(in_journey, to_completed, to_failed, transition_many, my_quests))]
+pub struct JourneyLedgerApi;
+
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let journey_ledger_repository = JourneyLedgerPostgres::new(Arc::clone(&db_pool));
     let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
@@ -40,10 +53,23 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .route("/in-journey/:quest_id", patch(in_journey))
         .route("/to-completed/:quest_id", patch(to_completed))
         .route("/to-failed/:quest_id", patch(to_failed))
+        .route("/bulk/transition", post(transition_many))
+        .route("/mine", get(my_quests))
         .route_layer(middleware::from_fn(guild_commanders_authorization))
         .with_state(Arc::new(journey_ledger_use_case))
 }
 
+#[utoipa::path(
+    patch,
+    path = "/in-journey/{quest_id}",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (status = 200, description = "Quest moved to InJourney", body = QuestStatusModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
 pub async fn in_journey<T1, T2>(
     State(journey_ledger_use_case): State<Arc<JourneyLedgerUseCase<T1, T2>>>,
     Extension(guild_commander_id): Extension<i32>,
@@ -59,17 +85,31 @@ where
     {
         Ok(quest_id) => (
             StatusCode::OK,
-            format!(
-                "Quest id: {} is now {:?}",
+            Json(QuestStatusModel {
                 quest_id,
-                QuestStatuses::InJourney
-            ),
+                status: QuestStatuses::InJourney,
+            }),
+        )
+            .into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
         )
             .into_response(),
-        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
     }
 }
 
+#[utoipa::path(
+    patch,
+    path = "/to-completed/{quest_id}",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (status = 200, description = "Quest moved to Completed", body = QuestStatusModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
 pub async fn to_completed<T1, T2>(
     State(journey_ledger_use_case): State<Arc<JourneyLedgerUseCase<T1, T2>>>,
     Extension(guild_commander_id): Extension<i32>,
@@ -85,17 +125,31 @@ where
     {
         Ok(quest_id) => (
             StatusCode::OK,
-            format!(
-                "Quest id: {} is now {:?}",
+            Json(QuestStatusModel {
                 quest_id,
-                QuestStatuses::Completed
-            ),
+                status: QuestStatuses::Completed,
+            }),
+        )
+            .into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
         )
             .into_response(),
-        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
     }
 }
 
+#[utoipa::path(
+    patch,
+    path = "/to-failed/{quest_id}",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (status = 200, description = "Quest moved to Failed", body = QuestStatusModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
 pub async fn to_failed<T1, T2>(
     State(journey_ledger_use_case): State<Arc<JourneyLedgerUseCase<T1, T2>>>,
     Extension(guild_commander_id): Extension<i32>,
@@ -111,9 +165,83 @@ where
     {
         Ok(quest_id) => (
             StatusCode::OK,
-            format!("Quest id: {} is now {:?}", quest_id, QuestStatuses::Failed),
+            Json(QuestStatusModel {
+                quest_id,
+                status: QuestStatuses::Failed,
+            }),
+        )
+            .into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/bulk/transition",
+    request_body = BulkTransitionQuestsModel,
+    responses(
+        (
+            status = 200,
+            description = "Batch committed with per-quest results",
+            body = BulkResultModel
+        ),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 409, description = "All-or-nothing batch rolled back", body = BulkResultModel),
+        (status = 422, description = "Batch payload is invalid", body = ValidationErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn transition_many<T1, T2>(
+    State(journey_ledger_use_case): State<Arc<JourneyLedgerUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    ValidatedJson(bulk_transition_quests_model): ValidatedJson<BulkTransitionQuestsModel>,
+) -> impl IntoResponse
+where
+    T1: JourneyLedgerRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match journey_ledger_use_case
+        .transition_many(guild_commander_id, bulk_transition_quests_model)
+        .await
+    {
+        Ok(bulk_result) => bulk_response(bulk_result),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/mine",
+    responses(
+        (status = 200, description = "Owned quests grouped by status", body = QuestsByStatusModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn my_quests<T1, T2>(
+    State(journey_ledger_use_case): State<Arc<JourneyLedgerUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: JourneyLedgerRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match journey_ledger_use_case.my_quests(guild_commander_id).await {
+        Ok(quests_by_status) => (StatusCode::OK, Json(quests_by_status)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
         )
             .into_response(),
-        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
     }
 }
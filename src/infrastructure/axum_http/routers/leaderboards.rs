@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    application::use_cases::leaderboards::LeaderboardsUseCase,
+    domain::{
+        repositories::leaderboards::LeaderboardsRepository,
+        value_objects::{
+            api_response_model::ErrorModel,
+            leaderboard_model::{LeaderboardEntryModel, LeaderboardFilter},
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad, repositories::leaderboards::LeaderboardsPostgres,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(adventurers, guild_commanders))]
+pub struct LeaderboardsApi;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let leaderboards_repository = LeaderboardsPostgres::new(db_pool);
+    let leaderboards_use_case = LeaderboardsUseCase::new(Arc::new(leaderboards_repository));
+
+    Router::new()
+        .route("/adventurers", get(adventurers))
+        .route("/guild-commanders", get(guild_commanders))
+        .with_state(Arc::new(leaderboards_use_case))
+}
+
+#[utoipa::path(
+    get,
+    path = "/adventurers",
+    params(LeaderboardFilter),
+    responses(
+        (status = 200, description = "Adventurer leaderboard", body = Vec<LeaderboardEntryModel>),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    )
+)]
+pub async fn adventurers<T>(
+    State(leaderboards_use_case): State<Arc<LeaderboardsUseCase<T>>>,
+    filter: Query<LeaderboardFilter>,
+) -> impl IntoResponse
+where
+    T: LeaderboardsRepository + Send + Sync,
+{
+    match leaderboards_use_case.adventurers(&filter).await {
+        Ok(entries) => (StatusCode::OK, Json(entries)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/guild-commanders",
+    params(LeaderboardFilter),
+    responses(
+        (
+            status = 200,
+            description = "Guild commander leaderboard",
+            body = Vec<LeaderboardEntryModel>
+        ),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    )
+)]
+pub async fn guild_commanders<T>(
+    State(leaderboards_use_case): State<Arc<LeaderboardsUseCase<T>>>,
+    filter: Query<LeaderboardFilter>,
+) -> impl IntoResponse
+where
+    T: LeaderboardsRepository + Send + Sync,
+{
+    match leaderboards_use_case.guild_commanders(&filter).await {
+        Ok(entries) => (StatusCode::OK, Json(entries)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
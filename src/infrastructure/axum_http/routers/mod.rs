@@ -1,7 +1,19 @@
 pub mod adventurers;
 pub mod authentication;
+pub mod crew_management;
 pub mod crew_switchboard;
+pub mod events;
+pub mod events_test;
 pub mod guild_commanders;
 pub mod journey_ledger;
+pub mod leaderboards;
+pub mod notifications;
+pub mod quest_categories;
+pub mod quest_categories_test;
+pub mod quest_comments;
 pub mod quest_ops;
+pub mod quest_ops_test;
+pub mod quest_series;
+pub mod quest_templates;
 pub mod quest_viewing;
+pub mod webhooks;
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch},
+    Extension, Json, Router,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    application::use_cases::notifications::NotificationsUseCase,
+    domain::{
+        repositories::{
+            notifications::NotificationsRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            api_response_model::{ErrorModel, MessageModel},
+            authenticated_roles::AuthenticatedIdentity,
+            notification_model::{NotificationFilter, NotificationModel, UnreadNotificationsModel},
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::authenticated_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                notifications::NotificationsPostgres, quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(list, unread_count, mark_read, mark_all_read))]
+pub struct NotificationsApi;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let notifications_repository = NotificationsPostgres::new(Arc::clone(&db_pool));
+    let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
+    let notifications_use_case = NotificationsUseCase::new(
+        Arc::new(notifications_repository),
+        Arc::new(quest_viewing_repository),
+    );
+
+    Router::new()
+        .route("/", get(list))
+        .route("/unread-count", get(unread_count))
+        .route("/read-all", patch(mark_all_read))
+        .route("/:notification_id/read", patch(mark_read))
+        .route_layer(middleware::from_fn(authenticated_authorization))
+        .with_state(Arc::new(notifications_use_case))
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    params(NotificationFilter),
+    responses(
+        (status = 200, description = "Notifications of the caller", body = Vec<NotificationModel>),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn list<T1, T2>(
+    State(notifications_use_case): State<Arc<NotificationsUseCase<T1, T2>>>,
+    Extension(recipient): Extension<AuthenticatedIdentity>,
+    filter: Query<NotificationFilter>,
+) -> impl IntoResponse
+where
+    T1: NotificationsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match notifications_use_case.list(recipient, &filter).await {
+        Ok(notifications) => (StatusCode::OK, Json(notifications)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/unread-count",
+    responses(
+        (status = 200, description = "Unread notification count", body = UnreadNotificationsModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn unread_count<T1, T2>(
+    State(notifications_use_case): State<Arc<NotificationsUseCase<T1, T2>>>,
+    Extension(recipient): Extension<AuthenticatedIdentity>,
+) -> impl IntoResponse
+where
+    T1: NotificationsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match notifications_use_case.unread_count(recipient).await {
+        Ok(unread) => (StatusCode::OK, Json(unread)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{notification_id}/read",
+    params(("notification_id" = i32, Path, description = "Notification id")),
+    responses(
+        (status = 200, description = "Notification marked as read", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn mark_read<T1, T2>(
+    State(notifications_use_case): State<Arc<NotificationsUseCase<T1, T2>>>,
+    Extension(recipient): Extension<AuthenticatedIdentity>,
+    Path(notification_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: NotificationsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match notifications_use_case
+        .mark_read(notification_id, recipient)
+        .await
+    {
+        Ok(notification_id) => {
+            let response = format!(
+                "Mark notification as read success with id: {}",
+                notification_id
+            );
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/read-all",
+    responses(
+        (status = 200, description = "All notifications marked as read", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn mark_all_read<T1, T2>(
+    State(notifications_use_case): State<Arc<NotificationsUseCase<T1, T2>>>,
+    Extension(recipient): Extension<AuthenticatedIdentity>,
+) -> impl IntoResponse
+where
+    T1: NotificationsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match notifications_use_case.mark_all_read(recipient).await {
+        Ok(count) => {
+            let response = format!("Mark all notifications as read success: {}", count);
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{delete, get, patch, post},
+    Extension, Json, Router,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    application::use_cases::quest_categories::QuestCategoriesUseCase,
+    domain::{
+        repositories::quest_categories::QuestCategoriesRepository,
+        value_objects::{
+            api_response_model::{CreatedModel, ErrorModel, MessageModel},
+            quest_category_model::{
+                AddQuestCategoryModel, EditQuestCategoryModel, QuestCategoryConflictModel,
+                QuestCategoryModel,
+            },
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::guild_commanders_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::quest_categories::QuestCategoriesPostgres,
+        },
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(add, edit, remove, list))]
+pub struct QuestCategoriesApi;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_categories_repository = QuestCategoriesPostgres::new(db_pool);
+    let quest_categories_use_case =
+        QuestCategoriesUseCase::new(Arc::new(quest_categories_repository));
+
+    Router::new()
+        .route("/", post(add))
+        .route("/:category_id", patch(edit))
+        .route("/:category_id", delete(remove))
+        .route_layer(middleware::from_fn(guild_commanders_authorization))
+        .route("/", get(list))
+        .with_state(Arc::new(quest_categories_use_case))
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = AddQuestCategoryModel,
+    responses(
+        (status = 201, description = "Quest category added", body = CreatedModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 409, description = "Quest category name already used", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn add<T>(
+    State(quest_categories_use_case): State<Arc<QuestCategoriesUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Json(add_quest_category_model): Json<AddQuestCategoryModel>,
+) -> impl IntoResponse
+where
+    T: QuestCategoriesRepository + Send + Sync,
+{
+    match quest_categories_use_case
+        .add(guild_commander_id, add_quest_category_model)
+        .await
+    {
+        Ok(category_id) => {
+            let response = format!("Add quest category success with id: {}", category_id);
+            (
+                StatusCode::CREATED,
+                Json(CreatedModel::new(category_id, response)),
+            )
+                .into_response()
+        }
+        Err(e) => category_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{category_id}",
+    params(("category_id" = i32, Path, description = "Quest category id")),
+    request_body = EditQuestCategoryModel,
+    responses(
+        (status = 200, description = "Quest category edited", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 409, description = "Quest category name already used", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn edit<T>(
+    State(quest_categories_use_case): State<Arc<QuestCategoriesUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(category_id): Path<i32>,
+    Json(edit_quest_category_model): Json<EditQuestCategoryModel>,
+) -> impl IntoResponse
+where
+    T: QuestCategoriesRepository + Send + Sync,
+{
+    match quest_categories_use_case
+        .edit(category_id, guild_commander_id, edit_quest_category_model)
+        .await
+    {
+        Ok(category_id) => {
+            let response = format!("Edit quest category success with id: {}", category_id);
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => category_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{category_id}",
+    params(("category_id" = i32, Path, description = "Quest category id")),
+    responses(
+        (status = 200, description = "Quest category removed", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn remove<T>(
+    State(quest_categories_use_case): State<Arc<QuestCategoriesUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(category_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: QuestCategoriesRepository + Send + Sync,
+{
+    match quest_categories_use_case
+        .remove(category_id, guild_commander_id)
+        .await
+    {
+        Ok(_) => {
+            let response = format!(
+                "Remove quest category success with category id: {}",
+                category_id
+            );
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    responses(
+        (status = 200, description = "Quest categories", body = Vec<QuestCategoryModel>),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    )
+)]
+pub async fn list<T>(
+    State(quest_categories_use_case): State<Arc<QuestCategoriesUseCase<T>>>,
+) -> impl IntoResponse
+where
+    T: QuestCategoriesRepository + Send + Sync,
+{
+    match quest_categories_use_case.list().await {
+        Ok(categories) => (StatusCode::OK, Json(categories)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+fn category_error_response(e: anyhow::Error) -> Response {
+    match e.downcast::<QuestCategoryConflictModel>() {
+        Ok(conflict) => (StatusCode::CONFLICT, Json(ErrorModel::new(conflict))).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::{extract::State, http::StatusCode, response::IntoResponse, Extension, Json};
+
+    use crate::{
+        application::use_cases::quest_categories::QuestCategoriesUseCase,
+        domain::{
+            repositories::quest_categories::MockQuestCategoriesRepository,
+            value_objects::quest_category_model::{
+                AddQuestCategoryModel, QuestCategoryConflictModel,
+            },
+        },
+        infrastructure::axum_http::routers::quest_categories::add,
+    };
+
+    #[tokio::test]
+    async fn test_add_allows_the_same_name_for_different_commanders() {
+        let mut mock_quest_categories_repo = MockQuestCategoriesRepository::new();
+
+        let categories = Arc::new(Mutex::new(Vec::<(i32, String)>::new()));
+        mock_quest_categories_repo
+            .expect_add()
+            .returning(move |add_quest_category_entity| {
+                let mut categories = categories.lock().unwrap();
+                let key = (
+                    add_quest_category_entity.guild_commander_id,
+                    add_quest_category_entity.name,
+                );
+
+                let result = if categories.contains(&key) {
+                    Err(QuestCategoryConflictModel::new(key.1).into())
+                } else {
+                    categories.push(key);
+                    Ok(categories.len() as i32)
+                };
+
+                Box::pin(async move { result })
+            });
+
+        let use_case = Arc::new(QuestCategoriesUseCase::new(Arc::new(
+            mock_quest_categories_repo,
+        )));
+
+        let mut statuses = Vec::new();
+        for guild_commander_id in [1, 2, 1] {
+            let response = add(
+                State(Arc::clone(&use_case)),
+                Extension(guild_commander_id),
+                Json(AddQuestCategoryModel {
+                    name: "Raids".to_string(),
+                }),
+            )
+            .await
+            .into_response();
+
+            statuses.push(response.status());
+        }
+
+        assert_eq!(
+            statuses,
+            vec![
+                StatusCode::CREATED,
+                StatusCode::CREATED,
+                StatusCode::CONFLICT
+            ]
+        );
+    }
+}
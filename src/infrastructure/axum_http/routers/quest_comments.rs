@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+    Extension, Json, Router,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    application::use_cases::quest_comments::QuestCommentsUseCase,
+    domain::{
+        repositories::{
+            crew_switchboard::CrewSwitchboardRepository, quest_comments::QuestCommentsRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            api_response_model::{CreatedModel, ErrorModel, MessageModel},
+            authenticated_roles::AuthenticatedIdentity,
+            quest_comment_model::{
+                AddQuestCommentModel, EditQuestCommentModel, QuestCommentFilter,
+                QuestCommentPageModel,
+            },
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::authenticated_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                crew_switchboard::CrewSwitchboardPostgres, quest_comments::QuestCommentsPostgres,
+                quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(add, edit, remove, list))]
+pub struct QuestCommentsApi;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_comments_repository = QuestCommentsPostgres::new(Arc::clone(&db_pool));
+    let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
+    let crew_switchboard_repository = CrewSwitchboardPostgres::new(Arc::clone(&db_pool));
+
+    let quest_comments_use_case = QuestCommentsUseCase::new(
+        Arc::new(quest_comments_repository),
+        Arc::new(quest_viewing_repository),
+        Arc::new(crew_switchboard_repository),
+    );
+
+    Router::new()
+        .route("/:quest_id", post(add))
+        .route("/:quest_id", get(list))
+        .route("/:quest_id/:comment_id", patch(edit))
+        .route("/:quest_id/:comment_id", delete(remove))
+        .route_layer(middleware::from_fn(authenticated_authorization))
+        .with_state(Arc::new(quest_comments_use_case))
+}
+
+#[utoipa::path(
+    post,
+    path = "/{quest_id}",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    request_body = AddQuestCommentModel,
+    responses(
+        (status = 201, description = "Comment added", body = CreatedModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn add<T1, T2, T3>(
+    State(quest_comments_use_case): State<Arc<QuestCommentsUseCase<T1, T2, T3>>>,
+    Extension(author): Extension<AuthenticatedIdentity>,
+    Path(quest_id): Path<i32>,
+    Json(add_quest_comment_model): Json<AddQuestCommentModel>,
+) -> impl IntoResponse
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwitchboardRepository + Send + Sync,
+{
+    match quest_comments_use_case
+        .add(quest_id, author, add_quest_comment_model)
+        .await
+    {
+        Ok(comment_id) => {
+            let response = format!("Add quest comment success with id: {}", comment_id);
+            (
+                StatusCode::CREATED,
+                Json(CreatedModel::new(comment_id, response)),
+            )
+                .into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{quest_id}/{comment_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("comment_id" = i32, Path, description = "Comment id")
+    ),
+    request_body = EditQuestCommentModel,
+    responses(
+        (status = 200, description = "Comment edited", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn edit<T1, T2, T3>(
+    State(quest_comments_use_case): State<Arc<QuestCommentsUseCase<T1, T2, T3>>>,
+    Extension(author): Extension<AuthenticatedIdentity>,
+    Path((quest_id, comment_id)): Path<(i32, i32)>,
+    Json(edit_quest_comment_model): Json<EditQuestCommentModel>,
+) -> impl IntoResponse
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwitchboardRepository + Send + Sync,
+{
+    match quest_comments_use_case
+        .edit(quest_id, comment_id, author, edit_quest_comment_model)
+        .await
+    {
+        Ok(comment_id) => {
+            let response = format!("Edit quest comment success with id: {}", comment_id);
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{quest_id}/{comment_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("comment_id" = i32, Path, description = "Comment id")
+    ),
+    responses(
+        (status = 200, description = "Comment removed", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn remove<T1, T2, T3>(
+    State(quest_comments_use_case): State<Arc<QuestCommentsUseCase<T1, T2, T3>>>,
+    Extension(author): Extension<AuthenticatedIdentity>,
+    Path((quest_id, comment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwitchboardRepository + Send + Sync,
+{
+    match quest_comments_use_case
+        .remove(quest_id, comment_id, author)
+        .await
+    {
+        Ok(_) => {
+            let response = format!(
+                "Remove quest comment success with comment id: {}",
+                comment_id
+            );
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/{quest_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        QuestCommentFilter
+    ),
+    responses(
+        (status = 200, description = "Comment threads of the quest", body = QuestCommentPageModel),
+        (status = 400, description = "Page is out of range", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn list<T1, T2, T3>(
+    State(quest_comments_use_case): State<Arc<QuestCommentsUseCase<T1, T2, T3>>>,
+    Path(quest_id): Path<i32>,
+    filter: Query<QuestCommentFilter>,
+) -> impl IntoResponse
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwitchboardRepository + Send + Sync,
+{
+    if let Err(e) = filter.pagination() {
+        return (StatusCode::BAD_REQUEST, Json(ErrorModel::new(e))).into_response();
+    }
+
+    match quest_comments_use_case.list(quest_id, &filter).await {
+        Ok(comments) => (StatusCode::OK, Json(comments)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
@@ -1,22 +1,39 @@
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
     middleware,
-    response::IntoResponse,
-    routing::{delete, patch, post},
+    response::{IntoResponse, Response},
+    routing::{delete, get, patch, post, put},
     Extension, Json, Router,
 };
+use utoipa::OpenApi;
 
 use crate::{
-    application::use_cases::quest_ops::QuestOpsUseCase,
+    application::use_cases::{quest_ops::QuestOpsUseCase, quest_viewing::QuestViewingUseCase},
     domain::{
         repositories::{quest_ops::QuestOpsRepository, quest_viewing::QuestViewingRepository},
-        value_objects::quest_model::{AddQuestModel, EditQuestModel},
+        value_objects::{
+            api_response_model::{CreatedModel, ErrorModel, MessageModel, ValidationErrorModel},
+            invalid_quest_model::InvalidQuestModel,
+            quest_bulk_model::{BulkAddQuestsModel, BulkRemoveQuestsModel, BulkResultModel},
+            quest_model::{AddQuestModel, EditQuestModel, QuestModel, RemovedQuestModel},
+            quest_objective_model::{AddQuestObjectiveModel, EditQuestObjectiveModel},
+            quest_requirements_model::QuestRequirementsModel,
+            quest_transfer_model::{
+                parse_quest_import, quests_to_csv, QuestExportModel, QuestImportModel,
+                QuestImportReportModel, QuestTransferFilter, TransferFormats,
+            },
+            quest_version_model::{from_etag, QuestVersionConflictModel},
+        },
     },
     infrastructure::{
-        axum_http::middlewares::guild_commanders_authorization,
+        axum_http::{
+            middlewares::guild_commanders_authorization,
+            routers::quest_viewing::{created_quest, edited_quest},
+            validated_json::ValidatedJson,
+        },
         postgres::{
             postgres_connection::PgPoolSquad,
             repositories::{quest_ops::QuestOpsPostgres, quest_viewing::QuestViewingPostgres},
@@ -24,26 +41,88 @@ use crate::{
     },
 };
 
+#[derive(OpenApi)]
+#[openapi(paths(
+    add,
+    add_many,
+    export_quests,
+    import_quests,
+    edit,
+    remove,
+    remove_many,
+    clone_quest,
+    set_requirements,
+    remove_requirements,
+    add_objective,
+    edit_objective,
+    remove_objective,
+    add_dependency,
+    remove_dependency,
+))]
+pub struct QuestOpsApi;
+
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let quest_ops_repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
-    let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
+    let quest_viewing_repository = Arc::new(QuestViewingPostgres::new(Arc::clone(&db_pool)));
     let quest_ops_use_case = QuestOpsUseCase::new(
         Arc::new(quest_ops_repository),
-        Arc::new(quest_viewing_repository),
+        Arc::clone(&quest_viewing_repository),
     );
+    let quest_viewing_use_case = QuestViewingUseCase::new(quest_viewing_repository);
 
     Router::new()
         .route("/", post(add))
+        .route("/bulk", post(add_many))
+        .route("/bulk/remove", post(remove_many))
+        .route("/export", get(export_quests))
+        .route("/import", post(import_quests))
         .route("/:quest_id", patch(edit))
         .route("/:quest_id", delete(remove))
+        .route("/:quest_id/clone", post(clone_quest))
+        .route("/:quest_id/requirements", put(set_requirements))
+        .route("/:quest_id/requirements", delete(remove_requirements))
+        .route(
+            "/:quest_id/prerequisites/:prerequisite_id",
+            put(add_dependency),
+        )
+        .route(
+            "/:quest_id/prerequisites/:prerequisite_id",
+            delete(remove_dependency),
+        )
+        .route("/:quest_id/objectives", post(add_objective))
+        .route("/:quest_id/objectives/:objective_id", patch(edit_objective))
+        .route(
+            "/:quest_id/objectives/:objective_id",
+            delete(remove_objective),
+        )
         .route_layer(middleware::from_fn(guild_commanders_authorization))
+        .layer(Extension(Arc::new(quest_viewing_use_case)))
         .with_state(Arc::new(quest_ops_use_case))
 }
 
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = AddQuestModel,
+    responses(
+        (
+            status = 201,
+            description = "Quest added",
+            body = QuestModel,
+            headers(("Location" = String, description = "Quest details URL"))
+        ),
+        (status = 400, description = "Quest input is rejected", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 422, description = "Quest payload is invalid", body = ValidationErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
 pub async fn add<T1, T2>(
     State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(quest_viewing_use_case): Extension<Arc<QuestViewingUseCase<T2>>>,
     Extension(guild_commander_id): Extension<i32>,
-    Json(add_quest_model): Json<AddQuestModel>,
+    ValidatedJson(add_quest_model): ValidatedJson<AddQuestModel>,
 ) -> impl IntoResponse
 where
     T1: QuestOpsRepository + Send + Sync,
@@ -53,36 +132,248 @@ where
         .add(guild_commander_id, add_quest_model)
         .await
     {
-        Ok(quest_id) => {
-            let response = format!("Add quest success with id: {}", quest_id);
-            (StatusCode::OK, response)
+        Ok(quest_id) => created_quest(&quest_viewing_use_case, quest_id).await,
+        Err(e) => quest_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/bulk",
+    request_body = BulkAddQuestsModel,
+    responses(
+        (
+            status = 200,
+            description = "Batch committed with per-quest results",
+            body = BulkResultModel
+        ),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 409, description = "All-or-nothing batch rolled back", body = BulkResultModel),
+        (status = 422, description = "Batch payload is invalid", body = ValidationErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn add_many<T1, T2>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    ValidatedJson(bulk_add_quests_model): ValidatedJson<BulkAddQuestsModel>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_use_case
+        .add_many(guild_commander_id, bulk_add_quests_model)
+        .await
+    {
+        Ok(bulk_result) => bulk_response(bulk_result),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/export",
+    params(QuestTransferFilter),
+    responses(
+        (
+            status = 200,
+            description = "Owned quests with crew and status history",
+            content(
+                (Vec<QuestExportModel> = "application/json"),
+                (String = "text/csv")
+            )
+        ),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn export_quests<T1, T2>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Query(filter): Query<QuestTransferFilter>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    let quest_export_models = match quest_ops_use_case.export_quests(guild_commander_id).await {
+        Ok(quest_export_models) => quest_export_models,
+        Err(e) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    };
+
+    match filter.format.unwrap_or_default() {
+        TransferFormats::Json => (StatusCode::OK, Json(quest_export_models)).into_response(),
+        TransferFormats::Csv => match quests_to_csv(&quest_export_models) {
+            Ok(csv) => (
+                StatusCode::OK,
+                [
+                    (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+                    (
+                        header::CONTENT_DISPOSITION,
+                        "attachment; filename=\"quests.csv\"",
+                    ),
+                ],
+                csv,
+            )
+                .into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+        },
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/import",
+    params(QuestTransferFilter),
+    request_body(
+        description = "Quests to import, shaped like the export",
+        content(
+            (Vec<QuestImportModel> = "application/json"),
+            (String = "text/csv")
+        )
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Quests imported or dry run passed",
+            body = QuestImportReportModel
+        ),
+        (status = 400, description = "Import body cannot be parsed", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (
+            status = 422,
+            description = "Some rows failed so nothing was imported",
+            body = QuestImportReportModel
+        ),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn import_quests<T1, T2>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Query(filter): Query<QuestTransferFilter>,
+    body: String,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    let rows = match parse_quest_import(filter.format.unwrap_or_default(), &body) {
+        Ok(rows) => rows,
+        Err(e) => return (StatusCode::BAD_REQUEST, Json(ErrorModel::new(e))).into_response(),
+    };
+
+    match quest_ops_use_case
+        .import_quests(guild_commander_id, rows, filter.dry_run.unwrap_or(false))
+        .await
+    {
+        Ok(report) if report.invalid > 0 => {
+            (StatusCode::UNPROCESSABLE_ENTITY, Json(report)).into_response()
+        }
+        Ok(report) => (StatusCode::OK, Json(report)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{quest_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("If-Match" = Option<String>, Header, description = "Quest ETag the edit is based on")
+    ),
+    request_body = EditQuestModel,
+    responses(
+        (
+            status = 200,
+            description = "Quest edited",
+            body = QuestModel,
+            headers(("ETag" = String, description = "Edited quest version"))
+        ),
+        (status = 400, description = "Invalid If-Match header or quest input", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 409, description = "Version field is stale", body = QuestVersionConflictModel),
+        (status = 412, description = "If-Match header is stale", body = QuestVersionConflictModel),
+        (status = 422, description = "Quest payload is invalid", body = ValidationErrorModel),
+        (status = 428, description = "Quest version is missing", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
 pub async fn edit<T1, T2>(
     State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(quest_viewing_use_case): Extension<Arc<QuestViewingUseCase<T2>>>,
     Extension(guild_commander_id): Extension<i32>,
     Path(quest_id): Path<i32>,
-    Json(edit_quest_model): Json<EditQuestModel>,
+    headers: HeaderMap,
+    ValidatedJson(edit_quest_model): ValidatedJson<EditQuestModel>,
 ) -> impl IntoResponse
 where
     T1: QuestOpsRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
 {
+    let if_match = match headers.get(header::IF_MATCH) {
+        Some(if_match) => match if_match.to_str().ok().and_then(from_etag) {
+            Some(version) => Some(version),
+            None => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorModel::new("Invalid If-Match header")),
+                )
+                    .into_response();
+            }
+        },
+        None => None,
+    };
+
+    let Some(expected_version) = if_match.or(edit_quest_model.version) else {
+        return (
+            StatusCode::PRECONDITION_REQUIRED,
+            Json(ErrorModel::new(
+                "Quest version is required via If-Match header or version field",
+            )),
+        )
+            .into_response();
+    };
+
     match quest_ops_use_case
-        .edit(quest_id, guild_commander_id, edit_quest_model)
+        .edit(
+            quest_id,
+            guild_commander_id,
+            expected_version,
+            edit_quest_model,
+        )
         .await
     {
-        Ok(quest_id) => {
-            let response = format!("Edit quest success with id: {}", quest_id);
-            (StatusCode::OK, response)
-        }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+        Ok(quest_id) => edited_quest(&quest_viewing_use_case, quest_id).await,
+        Err(err) => match err.downcast::<QuestVersionConflictModel>() {
+            Ok(conflict) if if_match.is_some() => {
+                (StatusCode::PRECONDITION_FAILED, Json(conflict)).into_response()
+            }
+            Ok(conflict) => (StatusCode::CONFLICT, Json(conflict)).into_response(),
+            Err(err) => quest_error_response(err),
+        },
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/{quest_id}",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (status = 200, description = "Quest removed", body = RemovedQuestModel),
+        (status = 400, description = "Quest cannot be removed", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
 pub async fn remove<T1, T2>(
     State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
     Extension(guild_commander_id): Extension<i32>,
@@ -95,11 +386,362 @@ where
     match quest_ops_use_case
         .remove(quest_id, guild_commander_id)
         .await
+    {
+        Ok(_) => (StatusCode::OK, Json(RemovedQuestModel { quest_id })).into_response(),
+        Err(e) => quest_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/bulk/remove",
+    request_body = BulkRemoveQuestsModel,
+    responses(
+        (
+            status = 200,
+            description = "Batch committed with per-quest results",
+            body = BulkResultModel
+        ),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 409, description = "All-or-nothing batch rolled back", body = BulkResultModel),
+        (status = 422, description = "Batch payload is invalid", body = ValidationErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn remove_many<T1, T2>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    ValidatedJson(bulk_remove_quests_model): ValidatedJson<BulkRemoveQuestsModel>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_use_case
+        .remove_many(guild_commander_id, bulk_remove_quests_model)
+        .await
+    {
+        Ok(bulk_result) => bulk_response(bulk_result),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/{quest_id}/clone",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (
+            status = 201,
+            description = "Quest cloned",
+            body = QuestModel,
+            headers(("Location" = String, description = "Quest details URL"))
+        ),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn clone_quest<T1, T2>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(quest_viewing_use_case): Extension<Arc<QuestViewingUseCase<T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_use_case
+        .clone_quest(quest_id, guild_commander_id)
+        .await
+    {
+        Ok(cloned_quest_id) => created_quest(&quest_viewing_use_case, cloned_quest_id).await,
+        Err(e) => quest_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/{quest_id}/requirements",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    request_body = QuestRequirementsModel,
+    responses(
+        (status = 200, description = "Quest requirements replaced", body = MessageModel),
+        (status = 400, description = "Quest requirements are rejected", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn set_requirements<T1, T2>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+    Json(quest_requirements_model): Json<QuestRequirementsModel>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_use_case
+        .set_requirements(quest_id, guild_commander_id, quest_requirements_model)
+        .await
+    {
+        Ok(quest_id) => {
+            let response = format!("Set quest requirements success with quest id: {}", quest_id);
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => quest_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{quest_id}/requirements",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (status = 200, description = "Quest requirements removed", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn remove_requirements<T1, T2>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_use_case
+        .remove_requirements(quest_id, guild_commander_id)
+        .await
     {
         Ok(_) => {
-            let response = format!("Remove quest success with quest id: {}", quest_id);
-            (StatusCode::OK, response)
+            let response = format!(
+                "Remove quest requirements success with quest id: {}",
+                quest_id
+            );
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/{quest_id}/objectives",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    request_body = AddQuestObjectiveModel,
+    responses(
+        (status = 201, description = "Objective added", body = CreatedModel),
+        (status = 400, description = "Objective title or position is invalid", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn add_objective<T1, T2>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+    Json(add_quest_objective_model): Json<AddQuestObjectiveModel>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_use_case
+        .add_objective(quest_id, guild_commander_id, add_quest_objective_model)
+        .await
+    {
+        Ok(objective_id) => {
+            let response = format!("Add quest objective success with id: {}", objective_id);
+            (
+                StatusCode::CREATED,
+                Json(CreatedModel::new(objective_id, response)),
+            )
+                .into_response()
+        }
+        Err(e) => quest_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{quest_id}/objectives/{objective_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("objective_id" = i32, Path, description = "Objective id")
+    ),
+    request_body = EditQuestObjectiveModel,
+    responses(
+        (status = 200, description = "Objective edited", body = MessageModel),
+        (status = 400, description = "Objective title or position is invalid", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn edit_objective<T1, T2>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, objective_id)): Path<(i32, i32)>,
+    Json(edit_quest_objective_model): Json<EditQuestObjectiveModel>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_use_case
+        .edit_objective(
+            quest_id,
+            objective_id,
+            guild_commander_id,
+            edit_quest_objective_model,
+        )
+        .await
+    {
+        Ok(objective_id) => {
+            let response = format!("Edit quest objective success with id: {}", objective_id);
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => quest_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{quest_id}/objectives/{objective_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("objective_id" = i32, Path, description = "Objective id")
+    ),
+    responses(
+        (status = 200, description = "Objective removed", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn remove_objective<T1, T2>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, objective_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_use_case
+        .remove_objective(quest_id, objective_id, guild_commander_id)
+        .await
+    {
+        Ok(_) => {
+            let response = format!(
+                "Remove quest objective success with objective id: {}",
+                objective_id
+            );
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/{quest_id}/prerequisites/{prerequisite_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("prerequisite_id" = i32, Path, description = "Prerequisite quest id")
+    ),
+    responses(
+        (status = 200, description = "Prerequisite added", body = MessageModel),
+        (status = 400, description = "Prerequisite is missing or would create a cycle", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn add_dependency<T1, T2>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, prerequisite_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_use_case
+        .add_dependency(quest_id, prerequisite_id, guild_commander_id)
+        .await
+    {
+        Ok(_) => {
+            let response = format!(
+                "Add quest prerequisite success with quest id: {} and prerequisite id: {}",
+                quest_id, prerequisite_id
+            );
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => quest_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{quest_id}/prerequisites/{prerequisite_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("prerequisite_id" = i32, Path, description = "Prerequisite quest id")
+    ),
+    responses(
+        (status = 200, description = "Prerequisite removed", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn remove_dependency<T1, T2>(
+    State(quest_ops_use_case): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, prerequisite_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_use_case
+        .remove_dependency(quest_id, prerequisite_id, guild_commander_id)
+        .await
+    {
+        Ok(_) => {
+            let response = format!(
+                "Remove quest prerequisite success with quest id: {} and prerequisite id: {}",
+                quest_id, prerequisite_id
+            );
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+pub fn bulk_response(bulk_result: BulkResultModel) -> Response {
+    let status = if bulk_result.committed {
+        StatusCode::OK
+    } else {
+        StatusCode::CONFLICT
+    };
+
+    (status, Json(bulk_result)).into_response()
+}
+
+pub fn quest_error_response(e: anyhow::Error) -> Response {
+    match e.downcast::<InvalidQuestModel>() {
+        Ok(invalid) => (StatusCode::BAD_REQUEST, Json(ErrorModel::new(invalid))).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
     }
 }
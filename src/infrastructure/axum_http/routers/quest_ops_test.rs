@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        body::to_bytes,
+        extract::{Path, State},
+        http::StatusCode,
+        response::IntoResponse,
+        Extension, Json,
+    };
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        application::use_cases::quest_ops::QuestOpsUseCase,
+        domain::{
+            entities::quests::QuestEntity,
+            repositories::{
+                quest_ops::MockQuestOpsRepository, quest_viewing::MockQuestViewingRepository,
+            },
+            value_objects::{
+                quest_adventurer_junction::DEFAULT_CREW_SIZE,
+                quest_difficulties::QuestDifficulties,
+                quest_requirements_model::QuestRequirementsModel, quest_statuses::QuestStatuses,
+            },
+        },
+        infrastructure::axum_http::routers::quest_ops::{remove, set_requirements},
+    };
+
+    fn open_quest(id: i32, guild_commander_id: i32) -> QuestEntity {
+        QuestEntity {
+            id,
+            name: "test".to_string(),
+            description: Some("test".to_string()),
+            status: QuestStatuses::Open.to_string(),
+            guild_commander_id,
+            approval_required: false,
+            starts_at: None,
+            due_at: None,
+            join_deadline: None,
+            reward_gold: 0,
+            reward_xp: 0,
+            difficulty: QuestDifficulties::Normal.to_string(),
+            category_id: None,
+            max_adventurers: DEFAULT_CREW_SIZE,
+            series_id: None,
+            objectives_required: false,
+            version: 1,
+            created_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    fn quest_viewing_repo(adventurers_count: i64) -> MockQuestViewingRepository {
+        let mut mock_quest_viewing_repo = MockQuestViewingRepository::new();
+
+        mock_quest_viewing_repo
+            .expect_view_details()
+            .returning(|quest_id| Box::pin(async move { Ok(open_quest(quest_id, 1)) }));
+
+        mock_quest_viewing_repo
+            .expect_adventurers_counting_by_quest_id()
+            .returning(move |_| Box::pin(async move { Ok(adventurers_count) }));
+
+        mock_quest_viewing_repo
+    }
+
+    #[tokio::test]
+    async fn test_remove_returns_the_removed_quest_id() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+
+        mock_quest_ops_repo
+            .expect_remove()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let use_case = Arc::new(QuestOpsUseCase::new(
+            Arc::new(mock_quest_ops_repo),
+            Arc::new(quest_viewing_repo(0)),
+        ));
+
+        let response = remove(State(use_case), Extension(1), Path(7))
+            .await
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, serde_json::json!({ "quest_id": 7 }));
+    }
+
+    #[tokio::test]
+    async fn test_remove_rejects_a_taken_quest_with_bad_request() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+
+        mock_quest_ops_repo.expect_remove().never();
+
+        let use_case = Arc::new(QuestOpsUseCase::new(
+            Arc::new(mock_quest_ops_repo),
+            Arc::new(quest_viewing_repo(1)),
+        ));
+
+        let response = remove(State(use_case), Extension(1), Path(7))
+            .await
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_set_requirements_rejects_negative_minimums_with_bad_request() {
+        let mut mock_quest_ops_repo = MockQuestOpsRepository::new();
+
+        mock_quest_ops_repo.expect_upsert_requirements().never();
+
+        let use_case = Arc::new(QuestOpsUseCase::new(
+            Arc::new(mock_quest_ops_repo),
+            Arc::new(quest_viewing_repo(0)),
+        ));
+
+        let response = set_requirements(
+            State(use_case),
+            Extension(1),
+            Path(7),
+            Json(QuestRequirementsModel {
+                min_level: Some(-1),
+                ..Default::default()
+            }),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,285 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, post},
+    Extension, Json, Router,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    application::use_cases::quest_series::QuestSeriesUseCase,
+    domain::{
+        repositories::quest_series::QuestSeriesRepository,
+        value_objects::{
+            api_response_model::{ErrorModel, MessageModel},
+            quest_series_model::{AddQuestSeriesModel, EditQuestSeriesModel, QuestSeriesModel},
+        },
+    },
+    infrastructure::{
+        axum_http::{
+            api_version::v1_location, middlewares::guild_commanders_authorization,
+            routers::quest_ops::quest_error_response,
+        },
+        postgres::{
+            postgres_connection::PgPoolSquad, repositories::quest_series::QuestSeriesPostgres,
+        },
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(add, list, view_details, edit, pause, resume, end))]
+pub struct QuestSeriesApi;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_series_repository = QuestSeriesPostgres::new(db_pool);
+    let quest_series_use_case = QuestSeriesUseCase::new(Arc::new(quest_series_repository));
+
+    Router::new()
+        .route("/", post(add))
+        .route("/", get(list))
+        .route("/:series_id", get(view_details))
+        .route("/:series_id", patch(edit))
+        .route("/:series_id/pause", post(pause))
+        .route("/:series_id/resume", post(resume))
+        .route("/:series_id/end", post(end))
+        .route_layer(middleware::from_fn(guild_commanders_authorization))
+        .with_state(Arc::new(quest_series_use_case))
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = AddQuestSeriesModel,
+    responses(
+        (
+            status = 201,
+            description = "Quest series added",
+            body = QuestSeriesModel,
+            headers(("Location" = String, description = "Quest series URL"))
+        ),
+        (status = 400, description = "Quest series input is rejected", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn add<T>(
+    State(quest_series_use_case): State<Arc<QuestSeriesUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Json(add_quest_series_model): Json<AddQuestSeriesModel>,
+) -> impl IntoResponse
+where
+    T: QuestSeriesRepository + Send + Sync,
+{
+    match quest_series_use_case
+        .add(guild_commander_id, add_quest_series_model)
+        .await
+    {
+        Ok(series_id) => match quest_series_use_case
+            .view_details(series_id, guild_commander_id)
+            .await
+        {
+            Ok(series) => (
+                StatusCode::CREATED,
+                [(
+                    header::LOCATION,
+                    v1_location(&format!("/quest-series/{}", series_id)),
+                )],
+                Json(series),
+            )
+                .into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+        },
+        Err(e) => quest_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    responses(
+        (status = 200, description = "Quest series of the caller", body = Vec<QuestSeriesModel>),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn list<T>(
+    State(quest_series_use_case): State<Arc<QuestSeriesUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: QuestSeriesRepository + Send + Sync,
+{
+    match quest_series_use_case.list(guild_commander_id).await {
+        Ok(series) => (StatusCode::OK, Json(series)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/{series_id}",
+    params(("series_id" = i32, Path, description = "Quest series id")),
+    responses(
+        (status = 200, description = "Quest series", body = QuestSeriesModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn view_details<T>(
+    State(quest_series_use_case): State<Arc<QuestSeriesUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: QuestSeriesRepository + Send + Sync,
+{
+    match quest_series_use_case
+        .view_details(series_id, guild_commander_id)
+        .await
+    {
+        Ok(series) => (StatusCode::OK, Json(series)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{series_id}",
+    params(("series_id" = i32, Path, description = "Quest series id")),
+    request_body = EditQuestSeriesModel,
+    responses(
+        (status = 200, description = "Quest series edited", body = MessageModel),
+        (status = 400, description = "Quest series input is rejected", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn edit<T>(
+    State(quest_series_use_case): State<Arc<QuestSeriesUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+    Json(edit_quest_series_model): Json<EditQuestSeriesModel>,
+) -> impl IntoResponse
+where
+    T: QuestSeriesRepository + Send + Sync,
+{
+    match quest_series_use_case
+        .edit(series_id, guild_commander_id, edit_quest_series_model)
+        .await
+    {
+        Ok(series_id) => {
+            let response = format!("Edit quest series success with id: {}", series_id);
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => quest_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/{series_id}/pause",
+    params(("series_id" = i32, Path, description = "Quest series id")),
+    responses(
+        (status = 200, description = "Quest series paused", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn pause<T>(
+    State(quest_series_use_case): State<Arc<QuestSeriesUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: QuestSeriesRepository + Send + Sync,
+{
+    match quest_series_use_case
+        .pause(series_id, guild_commander_id)
+        .await
+    {
+        Ok(series_id) => {
+            let response = format!("Pause quest series success with id: {}", series_id);
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/{series_id}/resume",
+    params(("series_id" = i32, Path, description = "Quest series id")),
+    responses(
+        (status = 200, description = "Quest series resumed", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn resume<T>(
+    State(quest_series_use_case): State<Arc<QuestSeriesUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: QuestSeriesRepository + Send + Sync,
+{
+    match quest_series_use_case
+        .resume(series_id, guild_commander_id)
+        .await
+    {
+        Ok(series_id) => {
+            let response = format!("Resume quest series success with id: {}", series_id);
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/{series_id}/end",
+    params(("series_id" = i32, Path, description = "Quest series id")),
+    responses(
+        (status = 200, description = "Quest series ended", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn end<T>(
+    State(quest_series_use_case): State<Arc<QuestSeriesUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: QuestSeriesRepository + Send + Sync,
+{
+    match quest_series_use_case
+        .end(series_id, guild_commander_id)
+        .await
+    {
+        Ok(series_id) => {
+            let response = format!("End quest series success with id: {}", series_id);
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
@@ -0,0 +1,300 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+    Extension, Json, Router,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    application::use_cases::{
+        quest_templates::QuestTemplatesUseCase, quest_viewing::QuestViewingUseCase,
+    },
+    domain::{
+        repositories::{
+            quest_ops::QuestOpsRepository, quest_templates::QuestTemplatesRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            api_response_model::{ErrorModel, MessageModel},
+            quest_model::QuestModel,
+            quest_template_model::{
+                AddQuestTemplateModel, EditQuestTemplateModel, QuestFromTemplateModel,
+                QuestTemplateModel,
+            },
+        },
+    },
+    infrastructure::{
+        axum_http::{
+            api_version::v1_location,
+            middlewares::guild_commanders_authorization,
+            routers::{quest_ops::quest_error_response, quest_viewing::created_quest},
+        },
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                quest_ops::QuestOpsPostgres, quest_templates::QuestTemplatesPostgres,
+                quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(add, list, view_details, edit, remove, create_quest))]
+pub struct QuestTemplatesApi;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_templates_repository = QuestTemplatesPostgres::new(Arc::clone(&db_pool));
+    let quest_ops_repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
+    let quest_viewing_repository = Arc::new(QuestViewingPostgres::new(Arc::clone(&db_pool)));
+    let quest_templates_use_case = QuestTemplatesUseCase::new(
+        Arc::new(quest_templates_repository),
+        Arc::new(quest_ops_repository),
+        Arc::clone(&quest_viewing_repository),
+    );
+    let quest_viewing_use_case = QuestViewingUseCase::new(quest_viewing_repository);
+
+    Router::new()
+        .route("/", post(add))
+        .route("/", get(list))
+        .route("/:template_id", get(view_details))
+        .route("/:template_id", patch(edit))
+        .route("/:template_id", delete(remove))
+        .route("/:template_id/quests", post(create_quest))
+        .route_layer(middleware::from_fn(guild_commanders_authorization))
+        .layer(Extension(Arc::new(quest_viewing_use_case)))
+        .with_state(Arc::new(quest_templates_use_case))
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = AddQuestTemplateModel,
+    responses(
+        (
+            status = 201,
+            description = "Quest template added",
+            body = QuestTemplateModel,
+            headers(("Location" = String, description = "Quest template URL"))
+        ),
+        (status = 400, description = "Quest template input is rejected", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn add<T1, T2, T3>(
+    State(quest_templates_use_case): State<Arc<QuestTemplatesUseCase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Json(add_quest_template_model): Json<AddQuestTemplateModel>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    match quest_templates_use_case
+        .add(guild_commander_id, add_quest_template_model)
+        .await
+    {
+        Ok(template_id) => match quest_templates_use_case
+            .view_details(template_id, guild_commander_id)
+            .await
+        {
+            Ok(template) => (
+                StatusCode::CREATED,
+                [(
+                    header::LOCATION,
+                    v1_location(&format!("/quest-templates/{}", template_id)),
+                )],
+                Json(template),
+            )
+                .into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+        },
+        Err(e) => quest_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    responses(
+        (
+            status = 200,
+            description = "Quest templates of the caller",
+            body = Vec<QuestTemplateModel>
+        ),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn list<T1, T2, T3>(
+    State(quest_templates_use_case): State<Arc<QuestTemplatesUseCase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    match quest_templates_use_case.list(guild_commander_id).await {
+        Ok(templates) => (StatusCode::OK, Json(templates)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/{template_id}",
+    params(("template_id" = i32, Path, description = "Quest template id")),
+    responses(
+        (status = 200, description = "Quest template", body = QuestTemplateModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn view_details<T1, T2, T3>(
+    State(quest_templates_use_case): State<Arc<QuestTemplatesUseCase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    match quest_templates_use_case
+        .view_details(template_id, guild_commander_id)
+        .await
+    {
+        Ok(template) => (StatusCode::OK, Json(template)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{template_id}",
+    params(("template_id" = i32, Path, description = "Quest template id")),
+    request_body = EditQuestTemplateModel,
+    responses(
+        (status = 200, description = "Quest template edited", body = MessageModel),
+        (status = 400, description = "Quest template input is rejected", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn edit<T1, T2, T3>(
+    State(quest_templates_use_case): State<Arc<QuestTemplatesUseCase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+    Json(edit_quest_template_model): Json<EditQuestTemplateModel>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    match quest_templates_use_case
+        .edit(template_id, guild_commander_id, edit_quest_template_model)
+        .await
+    {
+        Ok(template_id) => {
+            let response = format!("Edit quest template success with id: {}", template_id);
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => quest_error_response(e),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{template_id}",
+    params(("template_id" = i32, Path, description = "Quest template id")),
+    responses(
+        (status = 200, description = "Quest template removed", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn remove<T1, T2, T3>(
+    State(quest_templates_use_case): State<Arc<QuestTemplatesUseCase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    match quest_templates_use_case
+        .remove(template_id, guild_commander_id)
+        .await
+    {
+        Ok(_) => {
+            let response = format!(
+                "Remove quest template success with template id: {}",
+                template_id
+            );
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/{template_id}/quests",
+    params(("template_id" = i32, Path, description = "Quest template id")),
+    request_body = QuestFromTemplateModel,
+    responses(
+        (
+            status = 201,
+            description = "Quest created from the template",
+            body = QuestModel,
+            headers(("Location" = String, description = "Quest details URL"))
+        ),
+        (status = 400, description = "Quest input is rejected", body = ErrorModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn create_quest<T1, T2, T3>(
+    State(quest_templates_use_case): State<Arc<QuestTemplatesUseCase<T1, T2, T3>>>,
+    Extension(quest_viewing_use_case): Extension<Arc<QuestViewingUseCase<T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+    Json(quest_from_template_model): Json<QuestFromTemplateModel>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    match quest_templates_use_case
+        .create_quest(template_id, guild_commander_id, quest_from_template_model)
+        .await
+    {
+        Ok(quest_id) => created_quest(&quest_viewing_use_case, quest_id).await,
+        Err(e) => quest_error_response(e),
+    }
+}
@@ -1,56 +1,295 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
-    response::IntoResponse,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
     routing::get,
     Json, Router,
 };
+use utoipa::OpenApi;
 
 use crate::{
     application::use_cases::quest_viewing::QuestViewingUseCase,
     domain::{
         repositories::quest_viewing::QuestViewingRepository,
-        value_objects::board_checking_filter::BoardCheckingFilter,
+        value_objects::{
+            api_response_model::ErrorModel, board_checking_filter::BoardCheckingFilter,
+            crew_member_model::CrewMemberModel, quest_dependency_model::QuestDependencyGraphModel,
+            quest_model::QuestModel, quest_objective_model::QuestObjectiveModel,
+            quest_requirements_model::QuestRequirementsModel, quest_version_model::to_etag,
+        },
     },
-    infrastructure::postgres::{
-        postgres_connection::PgPoolSquad, repositories::quest_viewing::QuestViewingPostgres,
+    infrastructure::{
+        axum_http::{
+            api_version::v1_location,
+            http_caching::{caching_headers, collection_etag, is_not_modified, not_modified},
+        },
+        postgres::{
+            postgres_connection::PgPoolSquad, repositories::quest_viewing::QuestViewingPostgres,
+        },
+        quest_viewing_cache::CachedQuestViewing,
     },
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+#[derive(OpenApi)]
+#[openapi(paths(
+    view_details,
+    board_checking,
+    crew_roster,
+    objectives,
+    dependency_graph,
+    requirements,
+))]
+pub struct QuestViewingApi;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, cache_ttl: Duration) -> Router {
     let quest_viewing_repository = QuestViewingPostgres::new(db_pool);
-    let quest_viewing_use_case = QuestViewingUseCase::new(Arc::new(quest_viewing_repository));
+    let cached_quest_viewing =
+        CachedQuestViewing::new(Arc::new(quest_viewing_repository), cache_ttl);
+    let quest_viewing_use_case = QuestViewingUseCase::new(Arc::new(cached_quest_viewing));
 
     Router::new()
         .route("/:quest_id", get(view_details))
+        .route("/:quest_id/crew", get(crew_roster))
+        .route("/:quest_id/objectives", get(objectives))
+        .route("/:quest_id/dependency-graph", get(dependency_graph))
+        .route("/:quest_id/requirements", get(requirements))
         .route("/board-checking", get(board_checking))
         .with_state(Arc::new(quest_viewing_use_case))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{quest_id}",
+    params(
+        ("quest_id" = i32, Path, description = "Quest id"),
+        ("If-None-Match" = Option<String>, Header, description = "Previously returned ETag")
+    ),
+    responses(
+        (status = 200, description = "Quest details", body = QuestModel),
+        (status = 304, description = "Quest is unchanged"),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    )
+)]
 pub async fn view_details<T>(
     State(quest_viewing_use_case): State<Arc<QuestViewingUseCase<T>>>,
     Path(quest_id): Path<i32>,
+    request_headers: HeaderMap,
 ) -> impl IntoResponse
 where
     T: QuestViewingRepository + Send + Sync,
 {
     match quest_viewing_use_case.view_details(quest_id).await {
-        Ok(quest_model) => (StatusCode::OK, Json(quest_model)).into_response(),
-        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, Json(err.to_string())).into_response(),
+        Ok(quest_model) => {
+            let etag = to_etag(quest_model.version);
+            let headers = caching_headers(&etag, Some(quest_model.updated_at));
+
+            if is_not_modified(&request_headers, &etag, Some(quest_model.updated_at)) {
+                return not_modified(headers);
+            }
+
+            (StatusCode::OK, headers, Json(quest_model)).into_response()
+        }
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/board-checking",
+    params(BoardCheckingFilter),
+    responses(
+        (status = 200, description = "Quests matching the filter", body = Vec<QuestModel>),
+        (status = 304, description = "Board is unchanged"),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    )
+)]
 pub async fn board_checking<T>(
     State(quest_viewing_use_case): State<Arc<QuestViewingUseCase<T>>>,
     filter: Query<BoardCheckingFilter>,
+    request_headers: HeaderMap,
 ) -> impl IntoResponse
 where
     T: QuestViewingRepository + Send + Sync,
 {
     match quest_viewing_use_case.board_checking(&filter).await {
-        Ok(quest_models) => (StatusCode::OK, Json(quest_models)).into_response(),
-        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, Json(err.to_string())).into_response(),
+        Ok(quest_models) => {
+            let etag = collection_etag(&quest_models);
+            let headers = caching_headers(&etag, None);
+
+            if is_not_modified(&request_headers, &etag, None) {
+                return not_modified(headers);
+            }
+
+            (StatusCode::OK, headers, Json(quest_models)).into_response()
+        }
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/{quest_id}/crew",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (status = 200, description = "Crew of the quest", body = Vec<CrewMemberModel>),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    )
+)]
+pub async fn crew_roster<T>(
+    State(quest_viewing_use_case): State<Arc<QuestViewingUseCase<T>>>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    match quest_viewing_use_case.crew_roster(quest_id).await {
+        Ok(crew) => (StatusCode::OK, Json(crew)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/{quest_id}/objectives",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (status = 200, description = "Objectives of the quest", body = Vec<QuestObjectiveModel>),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    )
+)]
+pub async fn objectives<T>(
+    State(quest_viewing_use_case): State<Arc<QuestViewingUseCase<T>>>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    match quest_viewing_use_case.objectives(quest_id).await {
+        Ok(objectives) => (StatusCode::OK, Json(objectives)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/{quest_id}/dependency-graph",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (
+            status = 200,
+            description = "Prerequisite graph of the quest",
+            body = QuestDependencyGraphModel
+        ),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    )
+)]
+pub async fn dependency_graph<T>(
+    State(quest_viewing_use_case): State<Arc<QuestViewingUseCase<T>>>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    match quest_viewing_use_case.dependency_graph(quest_id).await {
+        Ok(dependency_graph) => (StatusCode::OK, Json(dependency_graph)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/{quest_id}/requirements",
+    params(("quest_id" = i32, Path, description = "Quest id")),
+    responses(
+        (status = 200, description = "Requirements of the quest", body = QuestRequirementsModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    )
+)]
+pub async fn requirements<T>(
+    State(quest_viewing_use_case): State<Arc<QuestViewingUseCase<T>>>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    match quest_viewing_use_case.requirements(quest_id).await {
+        Ok(requirements) => (StatusCode::OK, Json(requirements)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+pub async fn created_quest<T>(
+    quest_viewing_use_case: &QuestViewingUseCase<T>,
+    quest_id: i32,
+) -> Response
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    match quest_viewing_use_case.view_details(quest_id).await {
+        Ok(quest_model) => (
+            StatusCode::CREATED,
+            [
+                (
+                    header::LOCATION,
+                    v1_location(&format!("/quest-viewing/{}", quest_id)),
+                ),
+                (header::ETAG, to_etag(quest_model.version)),
+            ],
+            Json(quest_model),
+        )
+            .into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+pub async fn edited_quest<T>(
+    quest_viewing_use_case: &QuestViewingUseCase<T>,
+    quest_id: i32,
+) -> Response
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    match quest_viewing_use_case.view_details(quest_id).await {
+        Ok(quest_model) => (
+            StatusCode::OK,
+            [(header::ETAG, to_etag(quest_model.version))],
+            Json(quest_model),
+        )
+            .into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
     }
 }
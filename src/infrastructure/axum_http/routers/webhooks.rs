@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+    Extension, Json, Router,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    application::use_cases::webhooks::WebhooksUseCase,
+    domain::{
+        repositories::webhooks::WebhooksRepository,
+        value_objects::{
+            api_response_model::{CreatedModel, ErrorModel, MessageModel},
+            webhook_model::{
+                AddWebhookModel, EditWebhookModel, WebhookDeliveryFilter, WebhookDeliveryModel,
+                WebhookModel,
+            },
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::guild_commanders_authorization,
+        postgres::{postgres_connection::PgPoolSquad, repositories::webhooks::WebhooksPostgres},
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(add, list, edit, remove, deliveries))]
+pub struct WebhooksApi;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let webhooks_repository = WebhooksPostgres::new(db_pool);
+    let webhooks_use_case = WebhooksUseCase::new(Arc::new(webhooks_repository));
+
+    Router::new()
+        .route("/", post(add))
+        .route("/", get(list))
+        .route("/:webhook_id", patch(edit))
+        .route("/:webhook_id", delete(remove))
+        .route("/:webhook_id/deliveries", get(deliveries))
+        .route_layer(middleware::from_fn(guild_commanders_authorization))
+        .with_state(Arc::new(webhooks_use_case))
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = AddWebhookModel,
+    responses(
+        (status = 201, description = "Webhook added", body = CreatedModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn add<T>(
+    State(webhooks_use_case): State<Arc<WebhooksUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Json(add_webhook_model): Json<AddWebhookModel>,
+) -> impl IntoResponse
+where
+    T: WebhooksRepository + Send + Sync,
+{
+    match webhooks_use_case
+        .add(guild_commander_id, add_webhook_model)
+        .await
+    {
+        Ok(webhook_id) => {
+            let response = format!("Add webhook success with id: {}", webhook_id);
+            (
+                StatusCode::CREATED,
+                Json(CreatedModel::new(webhook_id, response)),
+            )
+                .into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    responses(
+        (status = 200, description = "Webhooks of the caller", body = Vec<WebhookModel>),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn list<T>(
+    State(webhooks_use_case): State<Arc<WebhooksUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: WebhooksRepository + Send + Sync,
+{
+    match webhooks_use_case.list(guild_commander_id).await {
+        Ok(webhooks) => (StatusCode::OK, Json(webhooks)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{webhook_id}",
+    params(("webhook_id" = i32, Path, description = "Webhook id")),
+    request_body = EditWebhookModel,
+    responses(
+        (status = 200, description = "Webhook edited", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn edit<T>(
+    State(webhooks_use_case): State<Arc<WebhooksUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(webhook_id): Path<i32>,
+    Json(edit_webhook_model): Json<EditWebhookModel>,
+) -> impl IntoResponse
+where
+    T: WebhooksRepository + Send + Sync,
+{
+    match webhooks_use_case
+        .edit(webhook_id, guild_commander_id, edit_webhook_model)
+        .await
+    {
+        Ok(webhook_id) => {
+            let response = format!("Edit webhook success with id: {}", webhook_id);
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{webhook_id}",
+    params(("webhook_id" = i32, Path, description = "Webhook id")),
+    responses(
+        (status = 200, description = "Webhook removed", body = MessageModel),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn remove<T>(
+    State(webhooks_use_case): State<Arc<WebhooksUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(webhook_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: WebhooksRepository + Send + Sync,
+{
+    match webhooks_use_case
+        .remove(webhook_id, guild_commander_id)
+        .await
+    {
+        Ok(_) => {
+            let response = format!("Remove webhook success with webhook id: {}", webhook_id);
+            (StatusCode::OK, Json(MessageModel::new(response))).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorModel::new(e))).into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/{webhook_id}/deliveries",
+    params(
+        ("webhook_id" = i32, Path, description = "Webhook id"),
+        WebhookDeliveryFilter
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Delivery attempts of the webhook",
+            body = Vec<WebhookDeliveryModel>
+        ),
+        (status = 401, description = "Missing or invalid access token", body = ErrorModel),
+        (status = 500, description = "Unexpected error", body = ErrorModel)
+    ),
+    security(("act" = []))
+)]
+pub async fn deliveries<T>(
+    State(webhooks_use_case): State<Arc<WebhooksUseCase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(webhook_id): Path<i32>,
+    filter: Query<WebhookDeliveryFilter>,
+) -> impl IntoResponse
+where
+    T: WebhooksRepository + Send + Sync,
+{
+    match webhooks_use_case
+        .deliveries(webhook_id, guild_commander_id, &filter)
+        .await
+    {
+        Ok(deliveries) => (StatusCode::OK, Json(deliveries)).into_response(),
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorModel::new(err)),
+        )
+            .into_response(),
+    }
+}
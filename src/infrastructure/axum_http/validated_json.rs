@@ -0,0 +1,49 @@
+use axum::{
+    async_trait,
+    extract::{FromRequest, Request},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::de::DeserializeOwned;
+use validator::Validate;
+
+use crate::domain::value_objects::{
+    api_response_model::{ErrorModel, ValidationErrorModel},
+    input_sanitizer::Sanitize,
+};
+
+pub struct ValidatedJson<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Sanitize + Validate,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(mut value) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(|rejection| {
+                (
+                    rejection.status(),
+                    Json(ErrorModel::new(rejection.body_text())),
+                )
+                    .into_response()
+            })?;
+
+        value.sanitize();
+
+        if let Err(errors) = value.validate() {
+            return Err((
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ValidationErrorModel::from(&errors)),
+            )
+                .into_response());
+        }
+
+        Ok(Self(value))
+    }
+}
@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{to_bytes, Body},
+        extract::{FromRequest, Request},
+        http::{header, StatusCode},
+    };
+    use serde_json::json;
+
+    use crate::{
+        domain::value_objects::{
+            api_response_model::ValidationErrorModel,
+            quest_bulk_model::BulkAddQuestsModel,
+            quest_model::{AddQuestModel, EditQuestModel},
+        },
+        infrastructure::axum_http::validated_json::ValidatedJson,
+    };
+
+    fn json_request(body: serde_json::Value) -> Request {
+        Request::builder()
+            .method("POST")
+            .uri("/")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    async fn validation_errors(body: serde_json::Value) -> ValidationErrorModel {
+        let rejection = ValidatedJson::<AddQuestModel>::from_request(json_request(body), &())
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(rejection.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let bytes = to_bytes(rejection.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_blank_name_is_rejected() {
+        let errors = validation_errors(json!({ "name": "   " })).await;
+
+        assert!(errors.fields.contains_key("name"));
+    }
+
+    #[tokio::test]
+    async fn test_oversized_fields_are_rejected() {
+        let errors = validation_errors(json!({
+            "name": "a".repeat(256),
+            "description": "b".repeat(10_001),
+        }))
+        .await;
+
+        assert!(errors.fields.contains_key("name"));
+        assert!(errors.fields.contains_key("description"));
+    }
+
+    #[tokio::test]
+    async fn test_markup_in_name_is_rejected() {
+        let errors = validation_errors(json!({ "name": "Slay <b>the</b> dragon" })).await;
+
+        assert_eq!(
+            errors.fields.get("name").unwrap(),
+            &vec!["Must not contain control characters or angle brackets".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_malformed_json_is_rejected_before_validation() {
+        let request = Request::builder()
+            .method("POST")
+            .uri("/")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from("{\"name\":"))
+            .unwrap();
+
+        let rejection = ValidatedJson::<AddQuestModel>::from_request(request, &())
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(rejection.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_payload_is_trimmed_and_sanitized() {
+        let ValidatedJson(add_quest_model) =
+            ValidatedJson::<AddQuestModel>::from_request(
+                json_request(json!({
+                    "name": "  Slay the dragon  ",
+                    "description": "Bring <b>water</b>\r\n<script>alert(1)</script>[map](javascript:alert(1)) <https://example.com>",
+                })),
+                &(),
+            )
+            .await
+            .ok()
+            .unwrap();
+
+        assert_eq!(add_quest_model.name, "Slay the dragon");
+        assert_eq!(
+            add_quest_model.description.as_deref(),
+            Some("Bring water\n[map](#) <https://example.com>")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_edit_payload_keeps_omitted_fields_empty() {
+        let ValidatedJson(edit_quest_model) = ValidatedJson::<EditQuestModel>::from_request(
+            json_request(json!({ "version": 1 })),
+            &(),
+        )
+        .await
+        .ok()
+        .unwrap();
+
+        assert!(edit_quest_model.name.is_none());
+        assert!(edit_quest_model.description.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_nested_errors_are_keyed_by_item() {
+        let rejection = ValidatedJson::<BulkAddQuestsModel>::from_request(
+            json_request(json!({ "quests": [{ "name": "Valid" }, { "name": "" }] })),
+            &(),
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert_eq!(rejection.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let bytes = to_bytes(rejection.into_body(), usize::MAX).await.unwrap();
+        let errors: ValidationErrorModel = serde_json::from_slice(&bytes).unwrap();
+
+        assert_eq!(
+            errors.fields.keys().collect::<Vec<_>>(),
+            vec!["quests[1].name"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_non_ascii_description_is_sanitized_without_panicking() {
+        let ValidatedJson(add_quest_model) = ValidatedJson::<AddQuestModel>::from_request(
+            json_request(json!({
+                "name": "Kelvin",
+                "description": "\u{212A}\u{212A}</script> <script>\u{212A}</SCRIPT>after",
+            })),
+            &(),
+        )
+        .await
+        .ok()
+        .unwrap();
+
+        assert_eq!(
+            add_quest_model.description.as_deref(),
+            Some("\u{212A}\u{212A} after")
+        );
+    }
+}
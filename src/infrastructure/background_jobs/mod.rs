@@ -0,0 +1,4 @@
+pub mod outbox_relay;
+pub mod quest_expiry;
+pub mod quest_recurrence;
+pub mod webhook_delivery;
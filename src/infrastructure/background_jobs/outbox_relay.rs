@@ -0,0 +1,59 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+use crate::{
+    application::{
+        quest_events::QuestEventBus,
+        use_cases::{
+            notifications::NotificationsUseCase, outbox_relay::OutboxRelayUseCase,
+            webhooks::WebhooksUseCase,
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::{
+            notifications::NotificationsPostgres, outbox::OutboxPostgres,
+            quest_viewing::QuestViewingPostgres, webhooks::WebhooksPostgres,
+        },
+    },
+};
+
+pub fn spawn(
+    db_pool: Arc<PgPoolSquad>,
+    quest_event_bus: Arc<QuestEventBus>,
+    period: Duration,
+) -> JoinHandle<()> {
+    let notifications_use_case = NotificationsUseCase::new(
+        Arc::new(NotificationsPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(QuestViewingPostgres::new(Arc::clone(&db_pool))),
+    );
+    let webhooks_use_case =
+        WebhooksUseCase::new(Arc::new(WebhooksPostgres::new(Arc::clone(&db_pool))));
+
+    let outbox_repository = OutboxPostgres::new(Arc::clone(&db_pool));
+    let outbox_relay_use_case = OutboxRelayUseCase::new(Arc::new(outbox_repository))
+        .with_quest_event_handler(Arc::new(notifications_use_case))
+        .with_quest_event_handler(Arc::new(webhooks_use_case))
+        .with_quest_event_handler(quest_event_bus);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+
+        loop {
+            interval.tick().await;
+
+            match outbox_relay_use_case
+                .relay(chrono::Utc::now().naive_utc())
+                .await
+            {
+                Ok(outbox_event_ids) if !outbox_event_ids.is_empty() => {
+                    info!("Outbox events have been relayed: {:?}", outbox_event_ids)
+                }
+                Ok(_) => {}
+                Err(e) => error!("Failed to relay outbox events: {}", e),
+            }
+        }
+    })
+}
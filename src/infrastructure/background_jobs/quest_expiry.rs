@@ -0,0 +1,39 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+use crate::{
+    application::use_cases::journey_ledger::JourneyLedgerUseCase,
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::{
+            journey_ledger::JourneyLedgerPostgres, quest_viewing::QuestViewingPostgres,
+        },
+    },
+};
+
+pub fn spawn(db_pool: Arc<PgPoolSquad>, period: Duration) -> JoinHandle<()> {
+    let journey_ledger_repository = JourneyLedgerPostgres::new(Arc::clone(&db_pool));
+    let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
+    let journey_ledger_use_case = JourneyLedgerUseCase::new(
+        Arc::new(journey_ledger_repository),
+        Arc::new(quest_viewing_repository),
+    );
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+
+        loop {
+            interval.tick().await;
+
+            match journey_ledger_use_case.fail_overdue_quests().await {
+                Ok(quest_ids) if !quest_ids.is_empty() => {
+                    info!("Overdue quests have been failed: {:?}", quest_ids)
+                }
+                Ok(_) => {}
+                Err(e) => error!("Failed to fail overdue quests: {}", e),
+            }
+        }
+    })
+}
@@ -0,0 +1,35 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+use crate::{
+    application::use_cases::quest_series::QuestSeriesUseCase,
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad, repositories::quest_series::QuestSeriesPostgres,
+    },
+};
+
+pub fn spawn(db_pool: Arc<PgPoolSquad>, period: Duration) -> JoinHandle<()> {
+    let quest_series_repository = QuestSeriesPostgres::new(Arc::clone(&db_pool));
+    let quest_series_use_case = QuestSeriesUseCase::new(Arc::new(quest_series_repository));
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+
+        loop {
+            interval.tick().await;
+
+            match quest_series_use_case
+                .materialize_due_series(chrono::Utc::now().naive_utc())
+                .await
+            {
+                Ok(quest_ids) if !quest_ids.is_empty() => {
+                    info!("Recurring quests have been materialized: {:?}", quest_ids)
+                }
+                Ok(_) => {}
+                Err(e) => error!("Failed to materialize recurring quests: {}", e),
+            }
+        }
+    })
+}
@@ -0,0 +1,42 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+use crate::{
+    application::use_cases::webhook_deliveries::WebhookDeliveriesUseCase,
+    infrastructure::{
+        postgres::{postgres_connection::PgPoolSquad, repositories::webhooks::WebhooksPostgres},
+        webhook_sender::HttpWebhookSender,
+    },
+};
+
+pub fn spawn(
+    db_pool: Arc<PgPoolSquad>,
+    period: Duration,
+    allow_private_targets: bool,
+) -> JoinHandle<()> {
+    let webhooks_repository = WebhooksPostgres::new(Arc::clone(&db_pool));
+    let webhook_sender = HttpWebhookSender::new(allow_private_targets);
+    let webhook_deliveries_use_case =
+        WebhookDeliveriesUseCase::new(Arc::new(webhooks_repository), Arc::new(webhook_sender));
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+
+        loop {
+            interval.tick().await;
+
+            match webhook_deliveries_use_case
+                .deliver_due(chrono::Utc::now().naive_utc())
+                .await
+            {
+                Ok(delivery_ids) if !delivery_ids.is_empty() => {
+                    info!("Webhook deliveries have been sent: {:?}", delivery_ids)
+                }
+                Ok(_) => {}
+                Err(e) => error!("Failed to deliver webhooks: {}", e),
+            }
+        }
+    })
+}
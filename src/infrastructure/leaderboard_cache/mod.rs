@@ -0,0 +1,44 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+use crate::domain::value_objects::{
+    leaderboard_model::LeaderboardStatsModel, leaderboard_windows::LeaderboardWindows,
+};
+
+pub const CACHE_TTL: Duration = Duration::from_secs(300);
+
+type LeaderboardCache = Mutex<HashMap<String, (Instant, Vec<LeaderboardStatsModel>)>>;
+
+static CACHE: OnceLock<LeaderboardCache> = OnceLock::new();
+
+fn cache() -> &'static LeaderboardCache {
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn cache_key(board: &str, window: LeaderboardWindows) -> String {
+    format!("{}:{}", board, window)
+}
+
+pub fn get(board: &str, window: LeaderboardWindows) -> Option<Vec<LeaderboardStatsModel>> {
+    let cache = cache().lock().ok()?;
+
+    match cache.get(&cache_key(board, window)) {
+        Some((cached_at, stats)) if cached_at.elapsed() < CACHE_TTL => Some(stats.clone()),
+        _ => None,
+    }
+}
+
+pub fn put(board: &str, window: LeaderboardWindows, stats: Vec<LeaderboardStatsModel>) {
+    if let Ok(mut cache) = cache().lock() {
+        cache.insert(cache_key(board, window), (Instant::now(), stats));
+    }
+}
+
+pub fn invalidate() {
+    if let Ok(mut cache) = cache().lock() {
+        cache.clear();
+    }
+}
@@ -1,4 +1,10 @@
 pub mod argon2_hashing;
+pub mod background_jobs;
 pub mod axum_http;
 pub mod jwt_authentication;
+pub mod leaderboard_cache;
 pub mod postgres;
+pub mod quest_viewing_cache;
+pub mod quest_viewing_cache_test;
+pub mod webhook_sender;
+pub mod webhook_sender_test;
@@ -0,0 +1,32 @@
+use anyhow::Result;
+use diesel::{Connection, PgConnection};
+
+pub fn run_in_bulk<P, F>(
+    conn: &mut PgConnection,
+    items: Vec<P>,
+    all_or_nothing: bool,
+    mut run: F,
+) -> Result<Vec<Result<i32>>>
+where
+    F: FnMut(&mut PgConnection, P) -> Result<i32>,
+{
+    let mut outcomes = Vec::with_capacity(items.len());
+
+    let transaction = conn.transaction::<_, anyhow::Error, _>(|conn| {
+        for item in items.into_iter() {
+            outcomes.push(conn.transaction::<_, anyhow::Error, _>(|conn| run(conn, item)));
+        }
+
+        if all_or_nothing && outcomes.iter().any(Result::is_err) {
+            return Err(anyhow::anyhow!("Bulk operation rolled back"));
+        }
+
+        Ok(())
+    });
+
+    match transaction {
+        Ok(()) => Ok(outcomes),
+        Err(_) if all_or_nothing && outcomes.iter().any(Result::is_err) => Ok(outcomes),
+        Err(e) => Err(e),
+    }
+}
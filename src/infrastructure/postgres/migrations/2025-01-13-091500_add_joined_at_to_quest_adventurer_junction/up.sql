@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE
+    quest_adventurer_junction
+ADD
+    COLUMN joined_at TIMESTAMP NOT NULL DEFAULT now();
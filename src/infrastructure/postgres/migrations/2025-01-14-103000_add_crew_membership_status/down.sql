@@ -0,0 +1,5 @@
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS approval_required;
+
+ALTER TABLE
+    quest_adventurer_junction DROP COLUMN IF EXISTS "status";
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+ALTER TABLE
+    quest_adventurer_junction
+ADD
+    COLUMN "status" VARCHAR(255) NOT NULL DEFAULT 'Joined';
+
+ALTER TABLE
+    quests
+ADD
+    COLUMN approval_required BOOLEAN NOT NULL DEFAULT false;
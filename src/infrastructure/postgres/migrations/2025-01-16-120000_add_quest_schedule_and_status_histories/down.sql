@@ -0,0 +1,6 @@
+DROP TABLE IF EXISTS quest_status_histories;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS starts_at,
+    DROP COLUMN IF EXISTS due_at,
+    DROP COLUMN IF EXISTS join_deadline;
@@ -0,0 +1,25 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN starts_at TIMESTAMP,
+ADD
+    COLUMN due_at TIMESTAMP,
+ADD
+    COLUMN join_deadline TIMESTAMP;
+
+CREATE TABLE quest_status_histories (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    "status" VARCHAR(255) NOT NULL,
+    actor VARCHAR(255) NOT NULL,
+    guild_commander_id INTEGER,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    quest_status_histories
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id),
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id);
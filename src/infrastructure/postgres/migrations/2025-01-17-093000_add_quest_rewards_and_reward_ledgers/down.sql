@@ -0,0 +1,9 @@
+DROP TABLE IF EXISTS reward_ledgers;
+
+ALTER TABLE
+    adventurers DROP COLUMN IF EXISTS gold,
+    DROP COLUMN IF EXISTS xp;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS reward_gold,
+    DROP COLUMN IF EXISTS reward_xp;
@@ -0,0 +1,32 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN reward_gold INTEGER NOT NULL DEFAULT 0,
+ADD
+    COLUMN reward_xp INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE
+    adventurers
+ADD
+    COLUMN gold BIGINT NOT NULL DEFAULT 0,
+ADD
+    COLUMN xp BIGINT NOT NULL DEFAULT 0;
+
+CREATE TABLE reward_ledgers (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    adventurer_id INTEGER NOT NULL,
+    quest_status VARCHAR(255) NOT NULL,
+    gold INTEGER NOT NULL,
+    xp INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    UNIQUE (quest_id, adventurer_id, quest_status)
+);
+
+ALTER TABLE
+    reward_ledgers
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id),
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id);
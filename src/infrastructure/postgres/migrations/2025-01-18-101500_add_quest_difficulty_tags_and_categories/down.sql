@@ -0,0 +1,7 @@
+DROP TABLE IF EXISTS quest_tags;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS category_id,
+    DROP COLUMN IF EXISTS difficulty;
+
+DROP TABLE IF EXISTS quest_categories;
@@ -0,0 +1,40 @@
+-- Your SQL goes here
+CREATE TABLE quest_categories (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    guild_commander_id INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    UNIQUE (guild_commander_id, name)
+);
+
+ALTER TABLE
+    quest_categories
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id);
+
+ALTER TABLE
+    quests
+ADD
+    COLUMN difficulty VARCHAR(255) NOT NULL DEFAULT 'Normal',
+ADD
+    COLUMN category_id INTEGER;
+
+ALTER TABLE
+    quests
+ADD
+    CONSTRAINT fk_category FOREIGN KEY (category_id) REFERENCES quest_categories(id) ON DELETE SET NULL;
+
+CREATE TABLE quest_tags (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    UNIQUE (quest_id, name)
+);
+
+ALTER TABLE
+    quest_tags
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_quest_tags_name ON quest_tags (name);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS adventurer_skills;
+
+DROP TABLE IF EXISTS quest_requirements;
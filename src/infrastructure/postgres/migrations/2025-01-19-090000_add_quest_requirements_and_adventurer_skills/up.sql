@@ -0,0 +1,27 @@
+-- Your SQL goes here
+CREATE TABLE quest_requirements (
+    quest_id INTEGER PRIMARY KEY,
+    min_level INTEGER,
+    min_completed_quests INTEGER,
+    required_skills JSONB NOT NULL DEFAULT '[]',
+    allowed_adventurer_ids JSONB NOT NULL DEFAULT '[]',
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    quest_requirements
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE;
+
+CREATE TABLE adventurer_skills (
+    id SERIAL PRIMARY KEY,
+    adventurer_id INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    UNIQUE (adventurer_id, name)
+);
+
+ALTER TABLE
+    adventurer_skills
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id) ON DELETE CASCADE;
@@ -0,0 +1,28 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN max_adventurers INTEGER NOT NULL DEFAULT 4;
+
+CREATE TABLE quest_templates (
+    id SERIAL PRIMARY KEY,
+    guild_commander_id INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    approval_required BOOLEAN NOT NULL DEFAULT false,
+    max_adventurers INTEGER NOT NULL DEFAULT 4,
+    difficulty VARCHAR(255) NOT NULL DEFAULT 'Normal',
+    category_id INTEGER,
+    tags JSONB NOT NULL DEFAULT '[]',
+    reward_gold INTEGER NOT NULL DEFAULT 0,
+    reward_xp INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    quest_templates
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id),
+ADD
+    CONSTRAINT fk_category FOREIGN KEY (category_id) REFERENCES quest_categories(id) ON DELETE SET NULL;
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS idx_quests_series_occurrence;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS series_occurrence_at,
+    DROP COLUMN IF EXISTS series_id;
+
+DROP TABLE IF EXISTS quest_series;
@@ -0,0 +1,47 @@
+-- Your SQL goes here
+CREATE TABLE quest_series (
+    id SERIAL PRIMARY KEY,
+    guild_commander_id INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    approval_required BOOLEAN NOT NULL DEFAULT false,
+    max_adventurers INTEGER NOT NULL DEFAULT 4,
+    difficulty VARCHAR(255) NOT NULL DEFAULT 'Normal',
+    category_id INTEGER,
+    tags JSONB NOT NULL DEFAULT '[]',
+    reward_gold INTEGER NOT NULL DEFAULT 0,
+    reward_xp INTEGER NOT NULL DEFAULT 0,
+    frequency VARCHAR(255) NOT NULL,
+    weekdays JSONB NOT NULL DEFAULT '[]',
+    cron_expression VARCHAR(255),
+    starts_at TIMESTAMP NOT NULL,
+    duration_minutes INTEGER,
+    ends_at TIMESTAMP,
+    status VARCHAR(255) NOT NULL DEFAULT 'Active',
+    materialized_until TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    quest_series
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id),
+ADD
+    CONSTRAINT fk_category FOREIGN KEY (category_id) REFERENCES quest_categories(id) ON DELETE SET NULL;
+
+ALTER TABLE
+    quests
+ADD
+    COLUMN series_id INTEGER,
+ADD
+    COLUMN series_occurrence_at TIMESTAMP;
+
+ALTER TABLE
+    quests
+ADD
+    CONSTRAINT fk_series FOREIGN KEY (series_id) REFERENCES quest_series(id) ON DELETE SET NULL;
+
+CREATE UNIQUE INDEX idx_quests_series_occurrence ON quests (series_id, series_occurrence_at)
+WHERE
+    deleted_at IS NULL;
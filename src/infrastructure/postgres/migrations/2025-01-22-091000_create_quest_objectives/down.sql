@@ -0,0 +1,4 @@
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS objectives_required;
+
+DROP TABLE IF EXISTS quest_objectives;
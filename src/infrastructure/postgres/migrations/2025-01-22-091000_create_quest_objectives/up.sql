@@ -0,0 +1,27 @@
+-- Your SQL goes here
+CREATE TABLE quest_objectives (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    description TEXT,
+    done BOOLEAN NOT NULL DEFAULT false,
+    completed_by INTEGER,
+    completed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    quest_objectives
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_completed_by FOREIGN KEY (completed_by) REFERENCES adventurers(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_quest_objectives_quest_id ON quest_objectives (quest_id, position);
+
+ALTER TABLE
+    quests
+ADD
+    COLUMN objectives_required BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE quest_dependencies (
+    quest_id INTEGER NOT NULL,
+    prerequisite_id INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (quest_id, prerequisite_id),
+    CHECK (quest_id <> prerequisite_id)
+);
+
+ALTER TABLE
+    quest_dependencies
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_prerequisite FOREIGN KEY (prerequisite_id) REFERENCES quests(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_quest_dependencies_prerequisite_id ON quest_dependencies (prerequisite_id);
@@ -0,0 +1,23 @@
+-- Your SQL goes here
+CREATE TABLE quest_comments (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    parent_id INTEGER,
+    author_role VARCHAR(255) NOT NULL,
+    author_id INTEGER NOT NULL,
+    body TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    deleted_at TIMESTAMP
+);
+
+ALTER TABLE
+    quest_comments
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_parent FOREIGN KEY (parent_id) REFERENCES quest_comments(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_quest_comments_quest_id ON quest_comments (quest_id, created_at);
+
+CREATE INDEX idx_quest_comments_parent_id ON quest_comments (parent_id);
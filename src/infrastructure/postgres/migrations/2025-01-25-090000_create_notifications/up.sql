@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE notifications (
+    id SERIAL PRIMARY KEY,
+    recipient_role VARCHAR(255) NOT NULL,
+    recipient_id INTEGER NOT NULL,
+    quest_id INTEGER,
+    kind VARCHAR(255) NOT NULL,
+    message TEXT NOT NULL,
+    read_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    notifications
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_notifications_recipient ON notifications (recipient_role, recipient_id, created_at);
@@ -0,0 +1,11 @@
+DROP INDEX IF EXISTS idx_webhook_deliveries_idempotency;
+
+ALTER TABLE webhook_deliveries DROP COLUMN IF EXISTS idempotency_key;
+
+DROP INDEX IF EXISTS idx_notifications_idempotency;
+
+ALTER TABLE notifications DROP COLUMN IF EXISTS idempotency_key;
+
+DROP TABLE IF EXISTS outbox_event_handlers;
+
+DROP TABLE IF EXISTS outbox_events;
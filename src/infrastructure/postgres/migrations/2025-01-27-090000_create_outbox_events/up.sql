@@ -0,0 +1,40 @@
+-- Your SQL goes here
+CREATE TABLE outbox_events (
+    id SERIAL PRIMARY KEY,
+    idempotency_key VARCHAR(255) NOT NULL UNIQUE,
+    kind VARCHAR(255) NOT NULL,
+    quest_id INTEGER NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    processed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_outbox_events_pending ON outbox_events (id) WHERE processed_at IS NULL;
+
+CREATE TABLE outbox_event_handlers (
+    outbox_event_id INTEGER NOT NULL,
+    handler VARCHAR(255) NOT NULL,
+    handled_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (outbox_event_id, handler)
+);
+
+ALTER TABLE
+    outbox_event_handlers
+ADD
+    CONSTRAINT fk_outbox_event FOREIGN KEY (outbox_event_id) REFERENCES outbox_events(id) ON DELETE CASCADE;
+
+ALTER TABLE
+    notifications
+ADD
+    COLUMN idempotency_key VARCHAR(255);
+
+CREATE UNIQUE INDEX idx_notifications_idempotency ON notifications (idempotency_key, recipient_role, recipient_id);
+
+ALTER TABLE
+    webhook_deliveries
+ADD
+    COLUMN idempotency_key VARCHAR(255);
+
+CREATE UNIQUE INDEX idx_webhook_deliveries_idempotency ON webhook_deliveries (webhook_id, idempotency_key);
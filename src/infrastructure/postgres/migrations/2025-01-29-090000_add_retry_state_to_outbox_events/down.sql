@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS idx_outbox_events_due;
+
+CREATE INDEX idx_outbox_events_pending ON outbox_events (id) WHERE processed_at IS NULL;
+
+ALTER TABLE outbox_events DROP COLUMN IF EXISTS next_attempt_at;
+
+ALTER TABLE outbox_events DROP COLUMN IF EXISTS status;
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+ALTER TABLE
+    outbox_events
+ADD
+    COLUMN status VARCHAR(255) NOT NULL DEFAULT 'Pending',
+ADD
+    COLUMN next_attempt_at TIMESTAMP NOT NULL DEFAULT now();
+
+UPDATE outbox_events SET status = 'Processed' WHERE processed_at IS NOT NULL;
+
+UPDATE outbox_events SET status = 'DeadLettered' WHERE processed_at IS NULL AND attempts >= 20;
+
+DROP INDEX IF EXISTS idx_outbox_events_pending;
+
+CREATE INDEX idx_outbox_events_due ON outbox_events (next_attempt_at) WHERE status = 'Pending';
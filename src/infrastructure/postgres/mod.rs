@@ -1,3 +1,4 @@
+pub mod bulk_transaction;
 pub mod repositories;
 pub mod postgres_connection;
-pub mod schema;
\ No newline at end of file
+pub mod schema;
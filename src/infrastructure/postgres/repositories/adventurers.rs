@@ -5,10 +5,16 @@ use std::sync::Arc;
 
 use crate::{
     domain::{
-        entities::adventurers::{AdventurerEntity, RegisterAdventurerEntity},
+        entities::{
+            adventurer_skills::AddAdventurerSkillEntity,
+            adventurers::{AdventurerEntity, RegisterAdventurerEntity},
+        },
         repositories::adventurers::AdventurersRepository,
     },
-    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::adventurers},
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        schema::{adventurer_skills, adventurers},
+    },
 };
 
 pub struct AdventurerPostgres {
@@ -42,4 +48,51 @@ impl AdventurersRepository for AdventurerPostgres {
 
         Ok(result)
     }
+
+    async fn find_by_id(&self, adventurer_id: i32) -> Result<AdventurerEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = adventurers::table
+            .filter(adventurers::id.eq(adventurer_id))
+            .select(AdventurerEntity::as_select())
+            .first::<AdventurerEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn skills(&self, adventurer_id: i32) -> Result<Vec<String>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = adventurer_skills::table
+            .filter(adventurer_skills::adventurer_id.eq(adventurer_id))
+            .select(adventurer_skills::name)
+            .order_by(adventurer_skills::name.asc())
+            .load::<String>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn replace_skills(&self, adventurer_id: i32, skills: Vec<String>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            diesel::delete(adventurer_skills::table)
+                .filter(adventurer_skills::adventurer_id.eq(adventurer_id))
+                .execute(conn)?;
+
+            let skill_entities = skills
+                .into_iter()
+                .map(|name| AddAdventurerSkillEntity {
+                    adventurer_id,
+                    name,
+                })
+                .collect::<Vec<_>>();
+
+            insert_into(adventurer_skills::table)
+                .values(skill_entities)
+                .execute(conn)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 use anyhow::{Ok, Result};
 use axum::async_trait;
+use chrono::NaiveDateTime;
 use diesel::{
     dsl::{delete, insert_into},
     prelude::*,
@@ -8,11 +9,27 @@ use std::sync::Arc;
 
 use crate::{
     domain::{
+        entities::quest_objectives::QuestObjectiveEntity,
         repositories::crew_switchboard::CrewSwitchboardRepository,
-        value_objects::quest_adventurer_junction::QuestAdventurerJunction,
+        value_objects::{
+            crew_membership_statuses::CrewMembershipStatuses,
+            eligibility_model::AdventurerStandingModel,
+            quest_adventurer_junction::QuestAdventurerJunction,
+            quest_event_model::{QuestEventKinds, QuestEventModel},
+            quest_statuses::QuestStatuses,
+            waitlist_model::{next_in_line, WaitlistCandidatesModel},
+        },
     },
-    infrastructure::postgres::{
-        postgres_connection::PgPoolSquad, schema::quest_adventurer_junction,
+    infrastructure::{
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{outbox::record_event, quest_ops::touch_quest},
+            schema::{
+                adventurer_skills, adventurers, quest_adventurer_junction, quest_objectives,
+                quest_waitlist, quests,
+            },
+        },
+        quest_viewing_cache,
     },
 };
 
@@ -31,19 +48,380 @@ impl CrewSwitchboardRepository for CrewSwitchboardPostgres {
     async fn join(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        insert_into(quest_adventurer_junction::table)
-            .values(&junction_body)
-            .execute(&mut conn)?;
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            insert_into(quest_adventurer_junction::table)
+                .values((
+                    &junction_body,
+                    quest_adventurer_junction::status
+                        .eq(CrewMembershipStatuses::Joined.to_string()),
+                ))
+                .execute(conn)?;
+
+            leave_waitlist(conn, &junction_body)?;
+
+            record_event(
+                conn,
+                QuestEventModel::crew(
+                    QuestEventKinds::CrewJoined,
+                    junction_body.quest_id,
+                    junction_body.adventurer_id,
+                ),
+            )?;
+
+            touch_quest(conn, junction_body.quest_id)?;
+
+            Ok(())
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(())
+    }
+    async fn leave(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        waitlist_candidates: WaitlistCandidatesModel,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let removed_status = delete(quest_adventurer_junction::table)
+                .filter(quest_adventurer_junction::adventurer_id.eq(junction_body.adventurer_id))
+                .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
+                .returning(quest_adventurer_junction::status)
+                .get_result::<String>(conn)
+                .optional()?;
+
+            if removed_status != Some(CrewMembershipStatuses::Joined.to_string()) {
+                return Ok(());
+            }
+
+            record_event(
+                conn,
+                QuestEventModel::crew(
+                    QuestEventKinds::CrewLeft,
+                    junction_body.quest_id,
+                    junction_body.adventurer_id,
+                ),
+            )?;
+
+            touch_quest(conn, junction_body.quest_id)?;
+
+            let waitlist = quest_waitlist::table
+                .filter(quest_waitlist::quest_id.eq(junction_body.quest_id))
+                .order_by(quest_waitlist::id.asc())
+                .select((quest_waitlist::id, quest_waitlist::adventurer_id))
+                .for_update()
+                .load::<(i32, i32)>(conn)?;
+
+            let crew = quest_adventurer_junction::table
+                .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
+                .select((
+                    quest_adventurer_junction::adventurer_id,
+                    quest_adventurer_junction::status,
+                ))
+                .load::<(i32, String)>(conn)?;
+
+            let crew_adventurer_ids = crew
+                .iter()
+                .map(|(adventurer_id, _)| *adventurer_id)
+                .collect::<Vec<i32>>();
+
+            let joined_count = crew
+                .iter()
+                .filter(|(_, status)| *status == CrewMembershipStatuses::Joined.to_string())
+                .count() as i64;
+
+            let (max_adventurers, approval_required) = quests::table
+                .filter(quests::id.eq(junction_body.quest_id))
+                .select((quests::max_adventurers, quests::approval_required))
+                .first::<(i32, bool)>(conn)?;
+
+            let mut waitlist_candidates = waitlist_candidates;
+            if joined_count >= max_adventurers as i64 {
+                waitlist_candidates.eligible_adventurer_ids.clear();
+            }
+
+            let promotion = next_in_line(&waitlist, &crew_adventurer_ids, &waitlist_candidates);
+
+            delete(quest_waitlist::table)
+                .filter(quest_waitlist::id.eq_any(&promotion.cleared_waitlist_ids))
+                .execute(conn)?;
+
+            if let Some((_, adventurer_id)) = promotion.promoted {
+                let promoted_status = if approval_required {
+                    CrewMembershipStatuses::Pending
+                } else {
+                    CrewMembershipStatuses::Joined
+                };
+
+                let promoted = insert_into(quest_adventurer_junction::table)
+                    .values((
+                        &QuestAdventurerJunction {
+                            quest_id: junction_body.quest_id,
+                            adventurer_id,
+                        },
+                        quest_adventurer_junction::status.eq(promoted_status.to_string()),
+                    ))
+                    .on_conflict_do_nothing()
+                    .execute(conn)?;
+
+                if promoted > 0 && promoted_status == CrewMembershipStatuses::Joined {
+                    record_event(
+                        conn,
+                        QuestEventModel::crew(
+                            QuestEventKinds::CrewJoined,
+                            junction_body.quest_id,
+                            adventurer_id,
+                        ),
+                    )?;
+                }
+            }
+
+            Ok(())
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(())
+    }
+    async fn request_to_join(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            insert_into(quest_adventurer_junction::table)
+                .values((
+                    &junction_body,
+                    quest_adventurer_junction::status
+                        .eq(CrewMembershipStatuses::Pending.to_string()),
+                ))
+                .execute(conn)?;
+
+            leave_waitlist(conn, &junction_body)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+    async fn invite(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            insert_into(quest_adventurer_junction::table)
+                .values((
+                    &junction_body,
+                    quest_adventurer_junction::status
+                        .eq(CrewMembershipStatuses::Invited.to_string()),
+                ))
+                .execute(conn)?;
+
+            leave_waitlist(conn, &junction_body)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+    async fn approve(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let approved = diesel::update(quest_adventurer_junction::table)
+                .filter(quest_adventurer_junction::adventurer_id.eq(junction_body.adventurer_id))
+                .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
+                .set((
+                    quest_adventurer_junction::status
+                        .eq(CrewMembershipStatuses::Joined.to_string()),
+                    quest_adventurer_junction::joined_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .execute(conn)?;
+
+            if approved > 0 {
+                record_event(
+                    conn,
+                    QuestEventModel::crew(
+                        QuestEventKinds::CrewJoined,
+                        junction_body.quest_id,
+                        junction_body.adventurer_id,
+                    ),
+                )?;
+
+                touch_quest(conn, junction_body.quest_id)?;
+            }
+
+            Ok(())
+        })?;
+
+        quest_viewing_cache::invalidate();
 
         Ok(())
     }
-    async fn leave(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
+    async fn membership_status(
+        &self,
+        junction_body: QuestAdventurerJunction,
+    ) -> Result<Option<CrewMembershipStatuses>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        delete(quest_adventurer_junction::table)
+
+        let result = quest_adventurer_junction::table
             .filter(quest_adventurer_junction::adventurer_id.eq(junction_body.adventurer_id))
             .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
+            .select(quest_adventurer_junction::status)
+            .first::<String>(&mut conn)
+            .optional()?;
+
+        match result {
+            Some(status) => Ok(Some(CrewMembershipStatuses::try_from(&status)?)),
+            None => Ok(None),
+        }
+    }
+    async fn join_waitlist(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(quest_waitlist::table)
+            .values((
+                quest_waitlist::quest_id.eq(junction_body.quest_id),
+                quest_waitlist::adventurer_id.eq(junction_body.adventurer_id),
+            ))
             .execute(&mut conn)?;
 
         Ok(())
     }
+    async fn leave_waitlist(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        leave_waitlist(&mut conn, &junction_body)?;
+
+        Ok(())
+    }
+    async fn waitlist_adventurer_ids(&self, quest_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_waitlist::table
+            .filter(quest_waitlist::quest_id.eq(quest_id))
+            .order_by(quest_waitlist::id.asc())
+            .select(quest_waitlist::adventurer_id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(results)
+    }
+    async fn waitlist_position(
+        &self,
+        junction_body: QuestAdventurerJunction,
+    ) -> Result<Option<i64>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let waitlist_id = quest_waitlist::table
+            .filter(quest_waitlist::adventurer_id.eq(junction_body.adventurer_id))
+            .filter(quest_waitlist::quest_id.eq(junction_body.quest_id))
+            .select(quest_waitlist::id)
+            .first::<i32>(&mut conn)
+            .optional()?;
+
+        let Some(waitlist_id) = waitlist_id else {
+            return Ok(None);
+        };
+
+        let position = quest_waitlist::table
+            .filter(quest_waitlist::quest_id.eq(junction_body.quest_id))
+            .filter(quest_waitlist::id.le(waitlist_id))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(Some(position))
+    }
+    async fn adventurer_standing(&self, adventurer_id: i32) -> Result<AdventurerStandingModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let xp = adventurers::table
+            .filter(adventurers::id.eq(adventurer_id))
+            .select(adventurers::xp)
+            .first::<i64>(&mut conn)?;
+
+        let completed_quests = quest_adventurer_junction::table
+            .inner_join(quests::table)
+            .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+            .filter(
+                quest_adventurer_junction::status.eq(CrewMembershipStatuses::Joined.to_string()),
+            )
+            .filter(quests::status.eq(QuestStatuses::Completed.to_string()))
+            .filter(quests::deleted_at.is_null())
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        let skills = adventurer_skills::table
+            .filter(adventurer_skills::adventurer_id.eq(adventurer_id))
+            .select(adventurer_skills::name)
+            .load::<String>(&mut conn)?;
+
+        Ok(AdventurerStandingModel {
+            adventurer_id,
+            xp,
+            completed_quests,
+            skills,
+        })
+    }
+    async fn complete_objective(
+        &self,
+        objective_id: i32,
+        adventurer_id: i32,
+    ) -> Result<QuestObjectiveEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let now = chrono::Utc::now().naive_utc();
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let result = diesel::update(quest_objectives::table)
+                .filter(quest_objectives::id.eq(objective_id))
+                .set((
+                    quest_objectives::done.eq(true),
+                    quest_objectives::completed_by.eq(adventurer_id),
+                    quest_objectives::completed_at.eq(now),
+                    quest_objectives::updated_at.eq(now),
+                ))
+                .returning(QuestObjectiveEntity::as_returning())
+                .get_result::<QuestObjectiveEntity>(conn)?;
+
+            touch_quest(conn, result.quest_id)?;
+
+            Ok(result)
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(result)
+    }
+    async fn reopen_objective(&self, objective_id: i32) -> Result<QuestObjectiveEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let result = diesel::update(quest_objectives::table)
+                .filter(quest_objectives::id.eq(objective_id))
+                .set((
+                    quest_objectives::done.eq(false),
+                    quest_objectives::completed_by.eq(None::<i32>),
+                    quest_objectives::completed_at.eq(None::<NaiveDateTime>),
+                    quest_objectives::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .returning(QuestObjectiveEntity::as_returning())
+                .get_result::<QuestObjectiveEntity>(conn)?;
+
+            touch_quest(conn, result.quest_id)?;
+
+            Ok(result)
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(result)
+    }
+}
+
+fn leave_waitlist(conn: &mut PgConnection, junction_body: &QuestAdventurerJunction) -> Result<()> {
+    delete(quest_waitlist::table)
+        .filter(quest_waitlist::adventurer_id.eq(junction_body.adventurer_id))
+        .filter(quest_waitlist::quest_id.eq(junction_body.quest_id))
+        .execute(conn)?;
+
+    Ok(())
 }
@@ -1,14 +1,35 @@
 use anyhow::Result;
 use axum::async_trait;
-use diesel::prelude::*;
+use diesel::{insert_into, prelude::*};
 use std::sync::Arc;
 
 use crate::{
     domain::{
+        entities::{
+            quest_status_histories::AddQuestStatusHistoryEntity,
+            reward_ledgers::AddRewardLedgerEntity,
+        },
         repositories::journey_ledger::JourneyLedgerRepository,
-        value_objects::quest_statuses::QuestStatuses,
+        value_objects::{
+            crew_membership_statuses::CrewMembershipStatuses,
+            quest_bulk_model::QuestTransitionModel, quest_event_model::QuestEventModel,
+            quest_reward_model::QuestRewardModel, quest_status_actors::QuestStatusActors,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::{
+        leaderboard_cache,
+        postgres::{
+            bulk_transaction::run_in_bulk,
+            postgres_connection::PgPoolSquad,
+            repositories::outbox::record_event,
+            schema::{
+                adventurers, quest_adventurer_junction, quest_status_histories, quest_waitlist,
+                quests, reward_ledgers,
+            },
+        },
+        quest_viewing_cache,
     },
-    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quests},
 };
 
 pub struct JourneyLedgerPostgres {
@@ -26,46 +47,256 @@ impl JourneyLedgerRepository for JourneyLedgerPostgres {
     async fn in_journey(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .set((
-                quests::status.eq(QuestStatuses::InJourney.to_string()),
-                quests::guild_commander_id.eq(guild_commander_id),
-            ))
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            transition_quest(
+                conn,
+                quest_id,
+                QuestStatuses::InJourney,
+                guild_commander_id,
+                &QuestRewardModel::default(),
+            )
+        })?;
+
+        leaderboard_cache::invalidate();
+        quest_viewing_cache::invalidate();
 
         Ok(result)
     }
-    async fn to_completed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+    async fn to_completed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        reward: QuestRewardModel,
+    ) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .set((
-                quests::status.eq(QuestStatuses::Completed.to_string()),
-                quests::guild_commander_id.eq(guild_commander_id),
-            ))
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            transition_quest(
+                conn,
+                quest_id,
+                QuestStatuses::Completed,
+                guild_commander_id,
+                &reward,
+            )
+        })?;
+
+        leaderboard_cache::invalidate();
+        quest_viewing_cache::invalidate();
 
         Ok(result)
     }
-    async fn to_failed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+    async fn to_failed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        reward: QuestRewardModel,
+    ) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .set((
-                quests::status.eq(QuestStatuses::Failed.to_string()),
-                quests::guild_commander_id.eq(guild_commander_id),
-            ))
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            transition_quest(
+                conn,
+                quest_id,
+                QuestStatuses::Failed,
+                guild_commander_id,
+                &reward,
+            )
+        })?;
+
+        leaderboard_cache::invalidate();
+        quest_viewing_cache::invalidate();
+
+        Ok(result)
+    }
+    async fn transition_many(
+        &self,
+        transitions: Vec<QuestTransitionModel>,
+        guild_commander_id: i32,
+        all_or_nothing: bool,
+    ) -> Result<Vec<Result<i32>>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = run_in_bulk(
+            &mut conn,
+            transitions,
+            all_or_nothing,
+            |conn, transition| {
+                transition_quest(
+                    conn,
+                    transition.quest_id,
+                    transition.status,
+                    guild_commander_id,
+                    &transition.reward,
+                )
+            },
+        )?;
+
+        leaderboard_cache::invalidate();
+        quest_viewing_cache::invalidate();
+
+        Ok(results)
+    }
+    async fn to_failed_by_system(&self, quest_id: i32, reward: QuestRewardModel) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let result = diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::status.eq(QuestStatuses::InJourney.to_string()))
+                .set((
+                    quests::status.eq(QuestStatuses::Failed.to_string()),
+                    quests::version.eq(quests::version + 1),
+                    quests::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .returning(quests::id)
+                .get_result::<i32>(conn)?;
+
+            record_status_history(conn, quest_id, QuestStatuses::Failed, None)?;
+
+            pay_crew_rewards(conn, quest_id, QuestStatuses::Failed, &reward)?;
+
+            Ok(result)
+        })?;
+
+        leaderboard_cache::invalidate();
+        quest_viewing_cache::invalidate();
 
         Ok(result)
     }
 }
+
+fn transition_quest(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    status: QuestStatuses,
+    guild_commander_id: i32,
+    reward: &QuestRewardModel,
+) -> Result<i32> {
+    let expected_statuses = match status {
+        QuestStatuses::InJourney => vec![QuestStatuses::Open, QuestStatuses::Failed],
+        QuestStatuses::Completed | QuestStatuses::Failed => vec![QuestStatuses::InJourney],
+        QuestStatuses::Open => vec![],
+    };
+
+    let result = diesel::update(quests::table)
+        .filter(quests::id.eq(quest_id))
+        .filter(quests::deleted_at.is_null())
+        .filter(quests::guild_commander_id.eq(guild_commander_id))
+        .filter(
+            quests::status.eq_any(
+                expected_statuses
+                    .iter()
+                    .map(|expected_status| expected_status.to_string())
+                    .collect::<Vec<_>>(),
+            ),
+        )
+        .set((
+            quests::status.eq(status.to_string()),
+            quests::version.eq(quests::version + 1),
+            quests::updated_at.eq(chrono::Utc::now().naive_utc()),
+        ))
+        .returning(quests::id)
+        .get_result::<i32>(conn)
+        .optional()?;
+
+    let Some(result) = result else {
+        return Err(anyhow::anyhow!("Invalid condition to change status"));
+    };
+
+    if status != QuestStatuses::Failed {
+        diesel::delete(quest_waitlist::table)
+            .filter(quest_waitlist::quest_id.eq(quest_id))
+            .execute(conn)?;
+    }
+
+    record_status_history(conn, quest_id, status.clone(), Some(guild_commander_id))?;
+
+    if status != QuestStatuses::InJourney {
+        pay_crew_rewards(conn, quest_id, status, reward)?;
+    }
+
+    Ok(result)
+}
+
+fn record_status_history(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    status: QuestStatuses,
+    guild_commander_id: Option<i32>,
+) -> Result<()> {
+    let actor = match guild_commander_id {
+        Some(_) => QuestStatusActors::GuildCommander,
+        None => QuestStatusActors::System,
+    };
+
+    insert_into(quest_status_histories::table)
+        .values(AddQuestStatusHistoryEntity {
+            quest_id,
+            status: status.to_string(),
+            actor: actor.to_string(),
+            guild_commander_id,
+            created_at: chrono::Utc::now().naive_utc(),
+        })
+        .execute(conn)?;
+
+    let crew_ids = joined_crew_ids(conn, quest_id)?;
+    record_event(
+        conn,
+        QuestEventModel::status_changed(quest_id, status, crew_ids),
+    )?;
+
+    Ok(())
+}
+
+fn joined_crew_ids(conn: &mut PgConnection, quest_id: i32) -> Result<Vec<i32>> {
+    let crew_ids = quest_adventurer_junction::table
+        .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+        .filter(quest_adventurer_junction::status.eq(CrewMembershipStatuses::Joined.to_string()))
+        .select(quest_adventurer_junction::adventurer_id)
+        .load::<i32>(conn)?;
+
+    Ok(crew_ids)
+}
+
+fn pay_crew_rewards(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    status: QuestStatuses,
+    reward: &QuestRewardModel,
+) -> Result<()> {
+    let adventurer_ids = joined_crew_ids(conn, quest_id)?;
+
+    for adventurer_id in adventurer_ids.into_iter() {
+        let inserted = insert_into(reward_ledgers::table)
+            .values(AddRewardLedgerEntity {
+                quest_id,
+                adventurer_id,
+                quest_status: status.to_string(),
+                gold: reward.gold,
+                xp: reward.xp,
+                created_at: chrono::Utc::now().naive_utc(),
+            })
+            .on_conflict((
+                reward_ledgers::quest_id,
+                reward_ledgers::adventurer_id,
+                reward_ledgers::quest_status,
+            ))
+            .do_nothing()
+            .execute(conn)?;
+
+        if inserted == 0 {
+            continue;
+        }
+
+        diesel::update(adventurers::table)
+            .filter(adventurers::id.eq(adventurer_id))
+            .set((
+                adventurers::gold.eq(adventurers::gold + reward.gold as i64),
+                adventurers::xp.eq(adventurers::xp + reward.xp as i64),
+            ))
+            .execute(conn)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use anyhow::Result;
+use axum::async_trait;
+use diesel::{prelude::*, sql_query, sql_types::Timestamp};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        repositories::leaderboards::LeaderboardsRepository,
+        value_objects::{
+            leaderboard_model::LeaderboardStatsModel, leaderboard_windows::LeaderboardWindows,
+        },
+    },
+    infrastructure::{leaderboard_cache, postgres::postgres_connection::PgPoolSquad},
+};
+
+const ADVENTURERS_STATS_QUERY: &str = r#"
+    SELECT
+        adventurers.id,
+        adventurers.username,
+        COUNT(*) FILTER (WHERE quests.status = 'Completed') AS completed_quests,
+        COUNT(*) FILTER (WHERE quests.status = 'Failed') AS failed_quests,
+        COALESCE((
+            SELECT SUM(reward_ledgers.xp)
+            FROM reward_ledgers
+            WHERE reward_ledgers.adventurer_id = adventurers.id
+                AND reward_ledgers.created_at >= $1
+        ), 0)::BIGINT AS xp
+    FROM adventurers
+    INNER JOIN quest_adventurer_junction
+        ON quest_adventurer_junction.adventurer_id = adventurers.id
+        AND quest_adventurer_junction.status = 'Joined'
+    INNER JOIN quests
+        ON quests.id = quest_adventurer_junction.quest_id
+    WHERE quests.deleted_at IS NULL
+        AND quests.status IN ('Completed', 'Failed')
+        AND EXISTS (
+            SELECT 1
+            FROM quest_status_histories
+            WHERE quest_status_histories.quest_id = quests.id
+                AND quest_status_histories.status = quests.status
+                AND quest_status_histories.created_at >= $1
+        )
+    GROUP BY adventurers.id, adventurers.username
+"#;
+
+const GUILD_COMMANDERS_STATS_QUERY: &str = r#"
+    SELECT
+        guild_commanders.id,
+        guild_commanders.username,
+        COUNT(*) FILTER (WHERE quests.status = 'Completed') AS completed_quests,
+        COUNT(*) FILTER (WHERE quests.status = 'Failed') AS failed_quests,
+        COALESCE((
+            SELECT SUM(reward_ledgers.xp)
+            FROM reward_ledgers
+            INNER JOIN quests AS rewarded_quests
+                ON rewarded_quests.id = reward_ledgers.quest_id
+            WHERE rewarded_quests.guild_commander_id = guild_commanders.id
+                AND reward_ledgers.created_at >= $1
+        ), 0)::BIGINT AS xp
+    FROM guild_commanders
+    INNER JOIN quests
+        ON quests.guild_commander_id = guild_commanders.id
+    WHERE quests.deleted_at IS NULL
+        AND quests.status IN ('Completed', 'Failed')
+        AND EXISTS (
+            SELECT 1
+            FROM quest_status_histories
+            WHERE quest_status_histories.quest_id = quests.id
+                AND quest_status_histories.status = quests.status
+                AND quest_status_histories.created_at >= $1
+        )
+    GROUP BY guild_commanders.id, guild_commanders.username
+"#;
+
+pub struct LeaderboardsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl LeaderboardsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+
+    fn load_stats(
+        &self,
+        board: &str,
+        query: &str,
+        window: LeaderboardWindows,
+    ) -> Result<Vec<LeaderboardStatsModel>> {
+        if let Some(stats) = leaderboard_cache::get(board, window) {
+            return Ok(stats);
+        }
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let stats = sql_query(query)
+            .bind::<Timestamp, _>(window.since(chrono::Utc::now().naive_utc()))
+            .load::<LeaderboardStatsModel>(&mut conn)?;
+
+        leaderboard_cache::put(board, window, stats.clone());
+
+        Ok(stats)
+    }
+}
+
+#[async_trait]
+impl LeaderboardsRepository for LeaderboardsPostgres {
+    async fn adventurers_stats(
+        &self,
+        window: LeaderboardWindows,
+    ) -> Result<Vec<LeaderboardStatsModel>> {
+        self.load_stats("adventurers", ADVENTURERS_STATS_QUERY, window)
+    }
+
+    async fn guild_commanders_stats(
+        &self,
+        window: LeaderboardWindows,
+    ) -> Result<Vec<LeaderboardStatsModel>> {
+        self.load_stats("guild_commanders", GUILD_COMMANDERS_STATS_QUERY, window)
+    }
+}
@@ -0,0 +1,119 @@
+use anyhow::Result;
+use axum::async_trait;
+use diesel::{dsl::insert_into, prelude::*};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::notifications::{AddNotificationEntity, NotificationEntity},
+        repositories::notifications::NotificationsRepository,
+    },
+    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::notifications},
+};
+
+pub struct NotificationsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl NotificationsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl NotificationsRepository for NotificationsPostgres {
+    async fn add(&self, add_notification_entities: Vec<AddNotificationEntity>) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(notifications::table)
+            .values(add_notification_entities)
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn notifications_by_recipient(
+        &self,
+        recipient_role: String,
+        recipient_id: i32,
+        unread_only: bool,
+        limit: i64,
+    ) -> Result<Vec<NotificationEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = notifications::table
+            .filter(notifications::recipient_role.eq(recipient_role))
+            .filter(notifications::recipient_id.eq(recipient_id))
+            .into_boxed();
+
+        if unread_only {
+            query = query.filter(notifications::read_at.is_null());
+        }
+
+        let results = query
+            .order((notifications::created_at.desc(), notifications::id.desc()))
+            .limit(limit)
+            .select(NotificationEntity::as_select())
+            .load::<NotificationEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+    async fn unread_counting_by_recipient(
+        &self,
+        recipient_role: String,
+        recipient_id: i32,
+    ) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = notifications::table
+            .filter(notifications::recipient_role.eq(recipient_role))
+            .filter(notifications::recipient_id.eq(recipient_id))
+            .filter(notifications::read_at.is_null())
+            .count()
+            .first::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn mark_read(
+        &self,
+        notification_id: i32,
+        recipient_role: String,
+        recipient_id: i32,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            diesel::update(notifications::table)
+                .filter(notifications::id.eq(notification_id))
+                .filter(notifications::recipient_role.eq(&recipient_role))
+                .filter(notifications::recipient_id.eq(recipient_id))
+                .filter(notifications::read_at.is_null())
+                .set(notifications::read_at.eq(chrono::Utc::now().naive_utc()))
+                .execute(conn)?;
+
+            let result = notifications::table
+                .filter(notifications::id.eq(notification_id))
+                .filter(notifications::recipient_role.eq(&recipient_role))
+                .filter(notifications::recipient_id.eq(recipient_id))
+                .select(notifications::id)
+                .first::<i32>(conn)?;
+
+            Ok(result)
+        })?;
+
+        Ok(result)
+    }
+    async fn mark_all_read(&self, recipient_role: String, recipient_id: i32) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(notifications::table)
+            .filter(notifications::recipient_role.eq(recipient_role))
+            .filter(notifications::recipient_id.eq(recipient_id))
+            .filter(notifications::read_at.is_null())
+            .set(notifications::read_at.eq(chrono::Utc::now().naive_utc()))
+            .execute(&mut conn)?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,152 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{dsl::insert_into, prelude::*};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::outbox_events::OutboxEventEntity,
+        repositories::outbox::OutboxRepository,
+        value_objects::{
+            outbox_event_model::to_outbox_entity, outbox_event_statuses::OutboxEventStatuses,
+            quest_event_model::QuestEventModel,
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        schema::{outbox_event_handlers, outbox_events},
+    },
+};
+
+pub struct OutboxPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl OutboxPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for OutboxPostgres {
+    async fn pending_events(
+        &self,
+        now: NaiveDateTime,
+        claimed_until: NaiveDateTime,
+        limit: i64,
+    ) -> Result<Vec<OutboxEventEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let results = outbox_events::table
+                .filter(outbox_events::status.eq(OutboxEventStatuses::Pending.to_string()))
+                .filter(outbox_events::next_attempt_at.le(now))
+                .order_by(outbox_events::id.asc())
+                .limit(limit)
+                .select(OutboxEventEntity::as_select())
+                .for_update()
+                .skip_locked()
+                .load::<OutboxEventEntity>(conn)?;
+
+            let outbox_event_ids = results
+                .iter()
+                .map(|outbox_event| outbox_event.id)
+                .collect::<Vec<_>>();
+
+            diesel::update(outbox_events::table)
+                .filter(outbox_events::id.eq_any(outbox_event_ids))
+                .set(outbox_events::next_attempt_at.eq(claimed_until))
+                .execute(conn)?;
+
+            Ok(results)
+        })?;
+
+        Ok(results)
+    }
+    async fn handlers_by_outbox_event_id(&self, outbox_event_id: i32) -> Result<Vec<String>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = outbox_event_handlers::table
+            .filter(outbox_event_handlers::outbox_event_id.eq(outbox_event_id))
+            .select(outbox_event_handlers::handler)
+            .load::<String>(&mut conn)?;
+
+        Ok(results)
+    }
+    async fn mark_handled(&self, outbox_event_id: i32, handler: String) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(outbox_event_handlers::table)
+            .values((
+                outbox_event_handlers::outbox_event_id.eq(outbox_event_id),
+                outbox_event_handlers::handler.eq(handler),
+                outbox_event_handlers::handled_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn mark_processed(
+        &self,
+        outbox_event_id: i32,
+        processed_at: NaiveDateTime,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(outbox_events::table)
+            .filter(outbox_events::id.eq(outbox_event_id))
+            .set((
+                outbox_events::status.eq(OutboxEventStatuses::Processed.to_string()),
+                outbox_events::processed_at.eq(processed_at),
+                outbox_events::attempts.eq(outbox_events::attempts + 1),
+                outbox_events::last_error.eq(None::<String>),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn mark_failed(
+        &self,
+        outbox_event_id: i32,
+        last_error: String,
+        next_attempt_at: NaiveDateTime,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(outbox_events::table)
+            .filter(outbox_events::id.eq(outbox_event_id))
+            .set((
+                outbox_events::attempts.eq(outbox_events::attempts + 1),
+                outbox_events::last_error.eq(last_error),
+                outbox_events::next_attempt_at.eq(next_attempt_at),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn mark_dead_lettered(&self, outbox_event_id: i32, last_error: String) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(outbox_events::table)
+            .filter(outbox_events::id.eq(outbox_event_id))
+            .set((
+                outbox_events::status.eq(OutboxEventStatuses::DeadLettered.to_string()),
+                outbox_events::attempts.eq(outbox_events::attempts + 1),
+                outbox_events::last_error.eq(last_error),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
+
+pub fn record_event(conn: &mut PgConnection, event: QuestEventModel) -> Result<()> {
+    insert_into(outbox_events::table)
+        .values(to_outbox_entity(&event))
+        .execute(conn)?;
+
+    Ok(())
+}
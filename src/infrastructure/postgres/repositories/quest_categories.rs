@@ -0,0 +1,100 @@
+use anyhow::Result;
+use axum::async_trait;
+use diesel::{
+    dsl::insert_into,
+    prelude::*,
+    result::{DatabaseErrorKind, Error},
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::quest_categories::{
+            AddQuestCategoryEntity, EditQuestCategoryEntity, QuestCategoryEntity,
+        },
+        repositories::quest_categories::QuestCategoriesRepository,
+        value_objects::quest_category_model::QuestCategoryConflictModel,
+    },
+    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quest_categories},
+};
+
+pub struct QuestCategoriesPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl QuestCategoriesPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QuestCategoriesRepository for QuestCategoriesPostgres {
+    async fn add(&self, add_quest_category_entity: AddQuestCategoryEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let name = add_quest_category_entity.name.clone();
+
+        let result = insert_into(quest_categories::table)
+            .values(add_quest_category_entity)
+            .returning(quest_categories::id)
+            .get_result::<i32>(&mut conn)
+            .map_err(|e| name_conflict(e, name))?;
+
+        Ok(result)
+    }
+    async fn edit(
+        &self,
+        category_id: i32,
+        edit_quest_category_entity: EditQuestCategoryEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let name = edit_quest_category_entity.name.clone();
+
+        let result = diesel::update(quest_categories::table)
+            .filter(quest_categories::id.eq(category_id))
+            .set(edit_quest_category_entity)
+            .returning(quest_categories::id)
+            .get_result::<i32>(&mut conn)
+            .map_err(|e| name_conflict(e, name))?;
+
+        Ok(result)
+    }
+    async fn remove(&self, category_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(quest_categories::table)
+            .filter(quest_categories::id.eq(category_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn view_details(&self, category_id: i32) -> Result<QuestCategoryEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_categories::table
+            .filter(quest_categories::id.eq(category_id))
+            .select(QuestCategoryEntity::as_select())
+            .first::<QuestCategoryEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn list(&self) -> Result<Vec<QuestCategoryEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_categories::table
+            .select(QuestCategoryEntity::as_select())
+            .order_by(quest_categories::name.asc())
+            .load::<QuestCategoryEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+}
+
+fn name_conflict(e: Error, name: String) -> anyhow::Error {
+    match e {
+        Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+            QuestCategoryConflictModel::new(name).into()
+        }
+        e => e.into(),
+    }
+}
@@ -0,0 +1,147 @@
+use anyhow::Result;
+use axum::async_trait;
+use diesel::{
+    dsl::{exists, insert_into},
+    prelude::*,
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::quest_comments::{
+            AddQuestCommentEntity, EditQuestCommentEntity, QuestCommentEntity,
+        },
+        repositories::quest_comments::QuestCommentsRepository,
+    },
+    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quest_comments},
+};
+
+pub struct QuestCommentsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl QuestCommentsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QuestCommentsRepository for QuestCommentsPostgres {
+    async fn add(&self, add_quest_comment_entity: AddQuestCommentEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(quest_comments::table)
+            .values(add_quest_comment_entity)
+            .returning(quest_comments::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn edit(
+        &self,
+        comment_id: i32,
+        edit_quest_comment_entity: EditQuestCommentEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(quest_comments::table)
+            .filter(quest_comments::id.eq(comment_id))
+            .filter(quest_comments::deleted_at.is_null())
+            .set(edit_quest_comment_entity)
+            .returning(quest_comments::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn remove(&self, comment_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(quest_comments::table)
+            .filter(quest_comments::id.eq(comment_id))
+            .filter(quest_comments::deleted_at.is_null())
+            .set(quest_comments::deleted_at.eq(chrono::Utc::now().naive_utc()))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn view_details(&self, comment_id: i32) -> Result<QuestCommentEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_comments::table
+            .filter(quest_comments::id.eq(comment_id))
+            .filter(quest_comments::deleted_at.is_null())
+            .select(QuestCommentEntity::as_select())
+            .first::<QuestCommentEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn threads_by_quest_id(
+        &self,
+        quest_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<QuestCommentEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let replies = diesel::alias!(quest_comments as replies);
+
+        let results = quest_comments::table
+            .filter(quest_comments::quest_id.eq(quest_id))
+            .filter(quest_comments::parent_id.is_null())
+            .filter(
+                quest_comments::deleted_at.is_null().or(exists(
+                    replies
+                        .filter(
+                            replies
+                                .field(quest_comments::parent_id)
+                                .eq(quest_comments::id.nullable()),
+                        )
+                        .filter(replies.field(quest_comments::deleted_at).is_null()),
+                )),
+            )
+            .order((quest_comments::created_at.desc(), quest_comments::id.desc()))
+            .limit(limit)
+            .offset(offset)
+            .select(QuestCommentEntity::as_select())
+            .load::<QuestCommentEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+    async fn threads_counting_by_quest_id(&self, quest_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let replies = diesel::alias!(quest_comments as replies);
+
+        let result = quest_comments::table
+            .filter(quest_comments::quest_id.eq(quest_id))
+            .filter(quest_comments::parent_id.is_null())
+            .filter(
+                quest_comments::deleted_at.is_null().or(exists(
+                    replies
+                        .filter(
+                            replies
+                                .field(quest_comments::parent_id)
+                                .eq(quest_comments::id.nullable()),
+                        )
+                        .filter(replies.field(quest_comments::deleted_at).is_null()),
+                )),
+            )
+            .count()
+            .first::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn replies_by_parent_ids(&self, parent_ids: Vec<i32>) -> Result<Vec<QuestCommentEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_comments::table
+            .filter(quest_comments::parent_id.eq_any(parent_ids))
+            .filter(quest_comments::deleted_at.is_null())
+            .order((quest_comments::created_at.asc(), quest_comments::id.asc()))
+            .select(QuestCommentEntity::as_select())
+            .load::<QuestCommentEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+}
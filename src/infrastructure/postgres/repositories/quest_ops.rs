@@ -1,15 +1,45 @@
 use anyhow::Result;
 use axum::async_trait;
-use diesel::{dsl::insert_into, prelude::*};
+use diesel::{
+    dsl::{exists, insert_into, select},
+    prelude::*,
+    sql_query,
+    sql_types::Int4,
+};
 use std::sync::Arc;
 
 use crate::{
     domain::{
-        entities::quests::{AddQuestEntity, EditQuestEntity},
+        entities::{
+            quest_dependencies::QuestDependencyEntity,
+            quest_objectives::{
+                AddQuestObjectiveEntity, EditQuestObjectiveEntity, QuestObjectiveEntity,
+            },
+            quest_requirements::{QuestRequirementEntity, UpsertQuestRequirementEntity},
+            quest_tags::AddQuestTagEntity,
+            quests::{AddQuestEntity, EditQuestEntity, ImportQuestEntity, QuestEntity},
+        },
         repositories::quest_ops::QuestOpsRepository,
-        value_objects::quest_statuses::QuestStatuses,
+        value_objects::{
+            invalid_quest_model::InvalidQuestModel,
+            quest_dependency_model::creates_cycle,
+            quest_event_model::{QuestEventKinds, QuestEventModel},
+            quest_statuses::QuestStatuses,
+            quest_version_model::QuestVersionConflictModel,
+        },
+    },
+    infrastructure::{
+        postgres::{
+            bulk_transaction::run_in_bulk,
+            postgres_connection::PgPoolSquad,
+            repositories::{outbox::record_event, quest_viewing::CHAIN_DEPENDENCIES_QUERY},
+            schema::{
+                quest_categories, quest_dependencies, quest_objectives, quest_requirements,
+                quest_tags, quests,
+            },
+        },
+        quest_viewing_cache,
     },
-    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quests},
 };
 
 pub struct QuestOpsPostgres {
@@ -24,40 +54,474 @@ impl QuestOpsPostgres {
 
 #[async_trait]
 impl QuestOpsRepository for QuestOpsPostgres {
-    async fn add(&self, add_quest_entity: AddQuestEntity) -> Result<i32> {
+    async fn add(&self, add_quest_entity: AddQuestEntity, tags: Vec<String>) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = insert_into(quests::table)
-            .values(add_quest_entity)
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            insert_quest(conn, add_quest_entity, tags)
+        })?;
+
+        quest_viewing_cache::invalidate();
 
         Ok(result)
     }
-    async fn edit(&self, quest_id: i32, edit_quest_entity: EditQuestEntity) -> Result<i32> {
+    async fn add_many(
+        &self,
+        quests: Vec<(AddQuestEntity, Vec<String>)>,
+        all_or_nothing: bool,
+    ) -> Result<Vec<Result<i32>>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .filter(quests::status.eq(QuestStatuses::Open.to_string()))
-            .set(edit_quest_entity)
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+
+        let results = run_in_bulk(&mut conn, quests, all_or_nothing, |conn, (entity, tags)| {
+            insert_quest(conn, entity, tags)
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(results)
+    }
+    async fn import_many(&self, quests: Vec<ImportQuestEntity>) -> Result<Vec<Result<i32>>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = run_in_bulk(&mut conn, quests, true, |conn, import_quest_entity| {
+            let result = insert_quest(conn, import_quest_entity.quest, import_quest_entity.tags)?;
+
+            if let Some(requirements) = import_quest_entity.requirements {
+                insert_into(quest_requirements::table)
+                    .values(requirements.to_entity(result))
+                    .execute(conn)?;
+            }
+
+            let objective_entities = import_quest_entity
+                .objectives
+                .iter()
+                .map(|(position, objective)| objective.to_entity(result, *position))
+                .collect::<Vec<_>>();
+
+            insert_into(quest_objectives::table)
+                .values(objective_entities)
+                .execute(conn)?;
+
+            Ok(result)
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(results)
+    }
+    async fn edit(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        expected_version: i32,
+        edit_quest_entity: EditQuestEntity,
+        tags: Option<Vec<String>>,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            ensure_category_owned(conn, edit_quest_entity.category_id, guild_commander_id)?;
+
+            let result = diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::guild_commander_id.eq(guild_commander_id))
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::status.eq(QuestStatuses::Open.to_string()))
+                .filter(quests::version.eq(expected_version))
+                .set((edit_quest_entity, quests::version.eq(quests::version + 1)))
+                .returning(quests::id)
+                .get_result::<i32>(conn)
+                .optional()?;
+
+            let Some(result) = result else {
+                let current_version = quests::table
+                    .filter(quests::id.eq(quest_id))
+                    .filter(quests::guild_commander_id.eq(guild_commander_id))
+                    .filter(quests::deleted_at.is_null())
+                    .select(quests::version)
+                    .first::<i32>(conn)
+                    .optional()?;
+
+                return match current_version {
+                    Some(current_version) if current_version != expected_version => Err(
+                        QuestVersionConflictModel::new(quest_id, expected_version, current_version)
+                            .into(),
+                    ),
+                    _ => Err(anyhow::anyhow!("Quest is not editable")),
+                };
+            };
+
+            if let Some(tags) = tags {
+                replace_tags(conn, result, tags)?;
+            }
+
+            record_event(
+                conn,
+                QuestEventModel::quest(QuestEventKinds::QuestEdited, result),
+            )?;
+
+            Ok(result)
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(result)
+    }
+    async fn clone_quest(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let quest = quests::table
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .select(QuestEntity::as_select())
+                .for_share()
+                .first::<QuestEntity>(conn)?;
+
+            let tags = quest_tags::table
+                .filter(quest_tags::quest_id.eq(quest_id))
+                .select(quest_tags::name)
+                .order_by(quest_tags::name.asc())
+                .load::<String>(conn)?;
+
+            let requirements = quest_requirements::table
+                .filter(quest_requirements::quest_id.eq(quest_id))
+                .select(QuestRequirementEntity::as_select())
+                .first::<QuestRequirementEntity>(conn)
+                .optional()?;
+
+            let objectives = quest_objectives::table
+                .filter(quest_objectives::quest_id.eq(quest_id))
+                .select(QuestObjectiveEntity::as_select())
+                .order_by(quest_objectives::position.asc())
+                .load::<QuestObjectiveEntity>(conn)?;
+
+            let result = insert_quest(conn, quest.to_clone_entity(guild_commander_id), tags)?;
+
+            if let Some(requirements) = requirements {
+                insert_into(quest_requirements::table)
+                    .values(UpsertQuestRequirementEntity {
+                        quest_id: result,
+                        min_level: requirements.min_level,
+                        min_completed_quests: requirements.min_completed_quests,
+                        required_skills: requirements.required_skills,
+                        allowed_adventurer_ids: requirements.allowed_adventurer_ids,
+                        updated_at: chrono::Utc::now().naive_utc(),
+                    })
+                    .execute(conn)?;
+            }
+
+            let objective_entities = objectives
+                .into_iter()
+                .map(|objective| AddQuestObjectiveEntity {
+                    quest_id: result,
+                    position: objective.position,
+                    title: objective.title,
+                    description: objective.description,
+                    created_at: chrono::Utc::now().naive_utc(),
+                    updated_at: chrono::Utc::now().naive_utc(),
+                })
+                .collect::<Vec<_>>();
+
+            insert_into(quest_objectives::table)
+                .values(objective_entities)
+                .execute(conn)?;
+
+            Ok(result)
+        })?;
+
+        quest_viewing_cache::invalidate();
 
         Ok(result)
     }
     async fn remove(&self, quest_id: i32, guild_commander_id: i32) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .filter(quests::status.eq(QuestStatuses::Open.to_string()))
-            .set((
-                quests::deleted_at.eq(chrono::Utc::now().naive_utc()),
-                quests::guild_commander_id.eq(guild_commander_id),
-            ))
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            soft_delete_quest(conn, quest_id, guild_commander_id)
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(())
+    }
+    async fn remove_many(
+        &self,
+        quest_ids: Vec<i32>,
+        guild_commander_id: i32,
+        all_or_nothing: bool,
+    ) -> Result<Vec<Result<i32>>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = run_in_bulk(&mut conn, quest_ids, all_or_nothing, |conn, quest_id| {
+            if soft_delete_quest(conn, quest_id, guild_commander_id)? == 0 {
+                return Err(anyhow::anyhow!("Quest is not removable"));
+            }
+
+            Ok(quest_id)
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(results)
+    }
+    async fn upsert_requirements(
+        &self,
+        upsert_quest_requirement_entity: UpsertQuestRequirementEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(quest_requirements::table)
+            .values(&upsert_quest_requirement_entity)
+            .on_conflict(quest_requirements::quest_id)
+            .do_update()
+            .set(&upsert_quest_requirement_entity)
+            .returning(quest_requirements::quest_id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn remove_requirements(&self, quest_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(quest_requirements::table)
+            .filter(quest_requirements::quest_id.eq(quest_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn add_objective(
+        &self,
+        add_quest_objective_entity: AddQuestObjectiveEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let (result, quest_id) = insert_into(quest_objectives::table)
+                .values(add_quest_objective_entity)
+                .returning((quest_objectives::id, quest_objectives::quest_id))
+                .get_result::<(i32, i32)>(conn)?;
+
+            touch_quest(conn, quest_id)?;
+
+            Ok(result)
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(result)
+    }
+    async fn edit_objective(
+        &self,
+        objective_id: i32,
+        edit_quest_objective_entity: EditQuestObjectiveEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let (result, quest_id) = diesel::update(quest_objectives::table)
+                .filter(quest_objectives::id.eq(objective_id))
+                .set(edit_quest_objective_entity)
+                .returning((quest_objectives::id, quest_objectives::quest_id))
+                .get_result::<(i32, i32)>(conn)?;
+
+            touch_quest(conn, quest_id)?;
+
+            Ok(result)
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(result)
+    }
+    async fn remove_objective(&self, objective_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let quest_id = diesel::delete(quest_objectives::table)
+                .filter(quest_objectives::id.eq(objective_id))
+                .returning(quest_objectives::quest_id)
+                .get_result::<i32>(conn)
+                .optional()?;
+
+            if let Some(quest_id) = quest_id {
+                touch_quest(conn, quest_id)?;
+            }
+
+            Ok(())
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(())
+    }
+    async fn add_dependency(&self, quest_dependency_entity: QuestDependencyEntity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            sql_query("LOCK TABLE quest_dependencies IN SHARE ROW EXCLUSIVE MODE").execute(conn)?;
+
+            let live_quests = quests::table
+                .filter(quests::id.eq_any([
+                    quest_dependency_entity.quest_id,
+                    quest_dependency_entity.prerequisite_id,
+                ]))
+                .filter(quests::deleted_at.is_null())
+                .count()
+                .get_result::<i64>(conn)?;
+
+            if live_quests != 2 {
+                return Err(InvalidQuestModel::new("Quest prerequisite not found").into());
+            }
+
+            let edges = sql_query(CHAIN_DEPENDENCIES_QUERY)
+                .bind::<Int4, _>(quest_dependency_entity.prerequisite_id)
+                .load::<QuestDependencyEntity>(conn)?
+                .iter()
+                .map(|dependency| dependency.to_model())
+                .collect::<Vec<_>>();
+
+            if creates_cycle(
+                &edges,
+                quest_dependency_entity.quest_id,
+                quest_dependency_entity.prerequisite_id,
+            ) {
+                return Err(InvalidQuestModel::new("Quest dependency would create a cycle").into());
+            }
+
+            insert_into(quest_dependencies::table)
+                .values(quest_dependency_entity)
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+    async fn remove_dependency(&self, quest_id: i32, prerequisite_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(quest_dependencies::table)
+            .filter(quest_dependencies::quest_id.eq(quest_id))
+            .filter(quest_dependencies::prerequisite_id.eq(prerequisite_id))
             .execute(&mut conn)?;
 
         Ok(())
     }
 }
+
+fn insert_quest(
+    conn: &mut PgConnection,
+    add_quest_entity: AddQuestEntity,
+    tags: Vec<String>,
+) -> Result<i32> {
+    ensure_category_owned(
+        conn,
+        add_quest_entity.category_id,
+        add_quest_entity.guild_commander_id,
+    )?;
+
+    let result = insert_into(quests::table)
+        .values(add_quest_entity)
+        .returning(quests::id)
+        .get_result::<i32>(conn)?;
+
+    replace_tags(conn, result, tags)?;
+
+    record_event(
+        conn,
+        QuestEventModel::quest(QuestEventKinds::QuestAdded, result),
+    )?;
+
+    Ok(result)
+}
+
+fn soft_delete_quest(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    guild_commander_id: i32,
+) -> Result<usize> {
+    let removed = diesel::update(quests::table)
+        .filter(quests::id.eq(quest_id))
+        .filter(quests::deleted_at.is_null())
+        .filter(quests::status.eq(QuestStatuses::Open.to_string()))
+        .filter(quests::guild_commander_id.eq(guild_commander_id))
+        .set(quests::deleted_at.eq(chrono::Utc::now().naive_utc()))
+        .execute(conn)?;
+
+    if removed > 0 {
+        remove_dependencies(conn, &[quest_id])?;
+
+        record_event(
+            conn,
+            QuestEventModel::quest(QuestEventKinds::QuestRemoved, quest_id),
+        )?;
+    }
+
+    Ok(removed)
+}
+
+pub fn ensure_category_owned(
+    conn: &mut PgConnection,
+    category_id: Option<i32>,
+    guild_commander_id: i32,
+) -> Result<()> {
+    let Some(category_id) = category_id else {
+        return Ok(());
+    };
+
+    let owned = select(exists(
+        quest_categories::table
+            .filter(quest_categories::id.eq(category_id))
+            .filter(quest_categories::guild_commander_id.eq(guild_commander_id)),
+    ))
+    .get_result::<bool>(conn)?;
+
+    if !owned {
+        return Err(InvalidQuestModel::new("Quest category not found").into());
+    }
+
+    Ok(())
+}
+
+pub fn remove_dependencies(conn: &mut PgConnection, quest_ids: &[i32]) -> Result<()> {
+    diesel::delete(quest_dependencies::table)
+        .filter(
+            quest_dependencies::quest_id
+                .eq_any(quest_ids)
+                .or(quest_dependencies::prerequisite_id.eq_any(quest_ids)),
+        )
+        .execute(conn)?;
+
+    Ok(())
+}
+
+pub fn touch_quest(conn: &mut PgConnection, quest_id: i32) -> Result<()> {
+    diesel::update(quests::table)
+        .filter(quests::id.eq(quest_id))
+        .set((
+            quests::version.eq(quests::version + 1),
+            quests::updated_at.eq(chrono::Utc::now().naive_utc()),
+        ))
+        .execute(conn)?;
+
+    Ok(())
+}
+
+fn replace_tags(conn: &mut PgConnection, quest_id: i32, tags: Vec<String>) -> Result<()> {
+    diesel::delete(quest_tags::table)
+        .filter(quest_tags::quest_id.eq(quest_id))
+        .execute(conn)?;
+
+    let tag_entities = tags
+        .into_iter()
+        .map(|name| AddQuestTagEntity { quest_id, name })
+        .collect::<Vec<_>>();
+
+    insert_into(quest_tags::table)
+        .values(tag_entities)
+        .execute(conn)?;
+
+    Ok(())
+}
@@ -0,0 +1,272 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    dsl::{exists, insert_into, not},
+    prelude::*,
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::{
+            quest_series::{AddQuestSeriesEntity, EditQuestSeriesEntity, QuestSeriesEntity},
+            quest_tags::AddQuestTagEntity,
+            quests::AddQuestEntity,
+        },
+        repositories::quest_series::QuestSeriesRepository,
+        value_objects::{
+            quest_event_model::{QuestEventKinds, QuestEventModel},
+            quest_series_statuses::QuestSeriesStatuses,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::{
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                outbox::record_event,
+                quest_ops::{ensure_category_owned, remove_dependencies},
+            },
+            schema::{quest_adventurer_junction, quest_series, quest_tags, quests},
+        },
+        quest_viewing_cache,
+    },
+};
+
+pub struct QuestSeriesPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl QuestSeriesPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QuestSeriesRepository for QuestSeriesPostgres {
+    async fn add(&self, add_quest_series_entity: AddQuestSeriesEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        ensure_category_owned(
+            &mut conn,
+            add_quest_series_entity.category_id,
+            add_quest_series_entity.guild_commander_id,
+        )?;
+
+        let result = insert_into(quest_series::table)
+            .values(add_quest_series_entity)
+            .returning(quest_series::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn edit(
+        &self,
+        series_id: i32,
+        expected_updated_at: NaiveDateTime,
+        edit_quest_series_entity: EditQuestSeriesEntity,
+        add_quest_entities: Vec<AddQuestEntity>,
+        materialized_until: Option<NaiveDateTime>,
+        now: NaiveDateTime,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let (guild_commander_id, updated_at) = quest_series::table
+                .filter(quest_series::id.eq(series_id))
+                .select((quest_series::guild_commander_id, quest_series::updated_at))
+                .for_update()
+                .first::<(i32, NaiveDateTime)>(conn)?;
+
+            if updated_at != expected_updated_at {
+                return Err(anyhow::anyhow!(
+                    "Quest series has been changed by another request"
+                ));
+            }
+
+            ensure_category_owned(
+                conn,
+                edit_quest_series_entity.category_id,
+                guild_commander_id,
+            )?;
+
+            let series = diesel::update(quest_series::table)
+                .filter(quest_series::id.eq(series_id))
+                .set(edit_quest_series_entity)
+                .returning(QuestSeriesEntity::as_returning())
+                .get_result::<QuestSeriesEntity>(conn)?;
+
+            discard_pending(conn, series_id, now)?;
+            insert_occurrences(conn, add_quest_entities, &series.tags())?;
+
+            diesel::update(quest_series::table)
+                .filter(quest_series::id.eq(series_id))
+                .set(quest_series::materialized_until.eq(materialized_until))
+                .execute(conn)?;
+
+            Ok(series.id)
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(result)
+    }
+    async fn update_status(&self, series_id: i32, status: String) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(quest_series::table)
+            .filter(quest_series::id.eq(series_id))
+            .set((
+                quest_series::status.eq(status),
+                quest_series::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .returning(quest_series::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn view_details(&self, series_id: i32) -> Result<QuestSeriesEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_series::table
+            .filter(quest_series::id.eq(series_id))
+            .select(QuestSeriesEntity::as_select())
+            .first::<QuestSeriesEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn series_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestSeriesEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_series::table
+            .filter(quest_series::guild_commander_id.eq(guild_commander_id))
+            .select(QuestSeriesEntity::as_select())
+            .order_by(quest_series::id.asc())
+            .load::<QuestSeriesEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn active_series(&self) -> Result<Vec<QuestSeriesEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_series::table
+            .filter(quest_series::status.eq(QuestSeriesStatuses::Active.to_string()))
+            .select(QuestSeriesEntity::as_select())
+            .order_by(quest_series::id.asc())
+            .load::<QuestSeriesEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn materialize(
+        &self,
+        series_id: i32,
+        add_quest_entities: Vec<AddQuestEntity>,
+        tags: Vec<String>,
+        materialized_until: NaiveDateTime,
+    ) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let quest_ids = insert_occurrences(conn, add_quest_entities, &tags)?;
+
+            diesel::update(quest_series::table)
+                .filter(quest_series::id.eq(series_id))
+                .set(quest_series::materialized_until.eq(materialized_until))
+                .execute(conn)?;
+
+            Ok(quest_ids)
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(result)
+    }
+    async fn discard_pending_quests(&self, series_id: i32, now: NaiveDateTime) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let quest_ids = discard_pending(conn, series_id, now)?;
+
+            diesel::update(quest_series::table)
+                .filter(quest_series::id.eq(series_id))
+                .set(quest_series::materialized_until.eq(None::<NaiveDateTime>))
+                .execute(conn)?;
+
+            Ok(quest_ids)
+        })?;
+
+        quest_viewing_cache::invalidate();
+
+        Ok(result)
+    }
+}
+
+fn insert_occurrences(
+    conn: &mut PgConnection,
+    add_quest_entities: Vec<AddQuestEntity>,
+    tags: &[String],
+) -> Result<Vec<i32>> {
+    let mut quest_ids = Vec::new();
+
+    for add_quest_entity in add_quest_entities {
+        let quest_id = insert_into(quests::table)
+            .values(add_quest_entity)
+            .on_conflict_do_nothing()
+            .returning(quests::id)
+            .get_result::<i32>(conn)
+            .optional()?;
+
+        let Some(quest_id) = quest_id else {
+            continue;
+        };
+
+        let tag_entities = tags
+            .iter()
+            .map(|name| AddQuestTagEntity {
+                quest_id,
+                name: name.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        insert_into(quest_tags::table)
+            .values(tag_entities)
+            .execute(conn)?;
+
+        record_event(
+            conn,
+            QuestEventModel::quest(QuestEventKinds::QuestAdded, quest_id),
+        )?;
+
+        quest_ids.push(quest_id);
+    }
+
+    Ok(quest_ids)
+}
+
+fn discard_pending(
+    conn: &mut PgConnection,
+    series_id: i32,
+    now: NaiveDateTime,
+) -> Result<Vec<i32>> {
+    let quest_ids = diesel::update(quests::table)
+        .filter(quests::series_id.eq(series_id))
+        .filter(quests::series_occurrence_at.gt(now))
+        .filter(quests::deleted_at.is_null())
+        .filter(quests::status.eq(QuestStatuses::Open.to_string()))
+        .filter(not(exists(
+            quest_adventurer_junction::table
+                .filter(quest_adventurer_junction::quest_id.eq(quests::id)),
+        )))
+        .set(quests::deleted_at.eq(now))
+        .returning(quests::id)
+        .get_results::<i32>(conn)?;
+
+    remove_dependencies(conn, &quest_ids)?;
+
+    Ok(quest_ids)
+}
@@ -0,0 +1,86 @@
+use anyhow::Result;
+use axum::async_trait;
+use diesel::{dsl::insert_into, prelude::*};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::quest_templates::{
+            AddQuestTemplateEntity, EditQuestTemplateEntity, QuestTemplateEntity,
+        },
+        repositories::quest_templates::QuestTemplatesRepository,
+    },
+    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quest_templates},
+};
+
+pub struct QuestTemplatesPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl QuestTemplatesPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QuestTemplatesRepository for QuestTemplatesPostgres {
+    async fn add(&self, add_quest_template_entity: AddQuestTemplateEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(quest_templates::table)
+            .values(add_quest_template_entity)
+            .returning(quest_templates::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn edit(
+        &self,
+        template_id: i32,
+        edit_quest_template_entity: EditQuestTemplateEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(quest_templates::table)
+            .filter(quest_templates::id.eq(template_id))
+            .set(edit_quest_template_entity)
+            .returning(quest_templates::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn remove(&self, template_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(quest_templates::table)
+            .filter(quest_templates::id.eq(template_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn view_details(&self, template_id: i32) -> Result<QuestTemplateEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_templates::table
+            .filter(quest_templates::id.eq(template_id))
+            .select(QuestTemplateEntity::as_select())
+            .first::<QuestTemplateEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn templates_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestTemplateEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_templates::table
+            .filter(quest_templates::guild_commander_id.eq(guild_commander_id))
+            .select(QuestTemplateEntity::as_select())
+            .order_by(quest_templates::name.asc())
+            .load::<QuestTemplateEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+}
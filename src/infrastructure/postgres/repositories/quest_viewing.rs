@@ -1,19 +1,58 @@
 use anyhow::Result;
 use axum::async_trait;
-use diesel::prelude::*;
-use std::sync::Arc;
+use chrono::NaiveDateTime;
+use diesel::{dsl::count_star, prelude::*, sql_query, sql_types::Int4};
+use std::{collections::HashMap, sync::Arc};
 
 use crate::{
     domain::{
-        entities::quests::QuestEntity, repositories::quest_viewing::QuestViewingRepository,
-        value_objects::board_checking_filter::BoardCheckingFilter,
+        entities::{
+            quest_dependencies::QuestDependencyEntity,
+            quest_objectives::QuestObjectiveEntity,
+            quest_requirements::QuestRequirementEntity,
+            quest_status_histories::QuestStatusHistoryEntity,
+            quest_tags::QuestTagEntity,
+            quests::{QuestEntity, QuestExportEntity},
+        },
+        repositories::quest_viewing::QuestViewingRepository,
+        value_objects::{
+            board_checking_filter::BoardCheckingFilter, crew_member_model::CrewMemberModel,
+            crew_membership_statuses::CrewMembershipStatuses, quest_statuses::QuestStatuses,
+        },
     },
     infrastructure::postgres::{
         postgres_connection::PgPoolSquad,
-        schema::{quest_adventurer_junction, quests},
+        schema::{
+            adventurers, quest_adventurer_junction, quest_dependencies, quest_objectives,
+            quest_requirements, quest_status_histories, quest_tags, quests,
+        },
     },
 };
 
+pub const CHAIN_DEPENDENCIES_QUERY: &str = r#"
+    WITH RECURSIVE chain (quest_id) AS (
+        SELECT $1
+        UNION
+        SELECT
+            CASE
+                WHEN quest_dependencies.quest_id = chain.quest_id
+                THEN quest_dependencies.prerequisite_id
+                ELSE quest_dependencies.quest_id
+            END
+        FROM quest_dependencies
+        INNER JOIN chain
+            ON quest_dependencies.quest_id = chain.quest_id
+            OR quest_dependencies.prerequisite_id = chain.quest_id
+    )
+    SELECT
+        quest_dependencies.quest_id,
+        quest_dependencies.prerequisite_id,
+        quest_dependencies.created_at
+    FROM quest_dependencies
+    WHERE quest_dependencies.quest_id IN (SELECT quest_id FROM chain)
+    ORDER BY quest_dependencies.quest_id, quest_dependencies.prerequisite_id
+"#;
+
 pub struct QuestViewingPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
@@ -52,6 +91,22 @@ impl QuestViewingRepository for QuestViewingPostgres {
             query = query.filter(quests::status.eq(status.to_string()));
         }
 
+        if let Some(difficulty) = &filter.difficulty {
+            query = query.filter(quests::difficulty.eq(difficulty.to_string()));
+        }
+
+        if let Some(category_id) = filter.category_id {
+            query = query.filter(quests::category_id.eq(category_id));
+        }
+
+        if let Some(tag) = &filter.tag {
+            let tagged_quest_ids = quest_tags::table
+                .filter(quest_tags::name.eq(tag.trim().to_lowercase()))
+                .select(quest_tags::quest_id);
+
+            query = query.filter(quests::id.eq_any(tagged_quest_ids));
+        }
+
         let result = query
             .select(QuestEntity::as_select())
             .order_by(quests::created_at.desc())
@@ -64,9 +119,282 @@ impl QuestViewingRepository for QuestViewingPostgres {
 
         let result = quest_adventurer_junction::table
             .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+            .filter(
+                quest_adventurer_junction::status.eq(CrewMembershipStatuses::Joined.to_string()),
+            )
             .count()
             .first::<i64>(&mut conn)?;
 
         Ok(result)
     }
+    async fn tags_by_quest_id(&self, quest_id: i32) -> Result<Vec<String>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_tags::table
+            .filter(quest_tags::quest_id.eq(quest_id))
+            .select(quest_tags::name)
+            .order_by(quest_tags::name.asc())
+            .load::<String>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn objectives_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestObjectiveEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_objectives::table
+            .filter(quest_objectives::quest_id.eq(quest_id))
+            .select(QuestObjectiveEntity::as_select())
+            .order_by((quest_objectives::position.asc(), quest_objectives::id.asc()))
+            .load::<QuestObjectiveEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn prerequisites_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let prerequisite_ids = quest_dependencies::table
+            .filter(quest_dependencies::quest_id.eq(quest_id))
+            .select(quest_dependencies::prerequisite_id);
+
+        let result = quests::table
+            .filter(quests::id.eq_any(prerequisite_ids))
+            .filter(quests::deleted_at.is_null())
+            .select(QuestEntity::as_select())
+            .order_by(quests::id.asc())
+            .load::<QuestEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn chain_dependencies(&self, quest_id: i32) -> Result<Vec<QuestDependencyEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = sql_query(CHAIN_DEPENDENCIES_QUERY)
+            .bind::<Int4, _>(quest_id)
+            .load::<QuestDependencyEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn quests_by_ids(&self, quest_ids: Vec<i32>) -> Result<Vec<QuestEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quests::table
+            .filter(quests::id.eq_any(quest_ids))
+            .filter(quests::deleted_at.is_null())
+            .select(QuestEntity::as_select())
+            .order_by(quests::id.asc())
+            .load::<QuestEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn requirements_by_quest_id(
+        &self,
+        quest_id: i32,
+    ) -> Result<Option<QuestRequirementEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_requirements::table
+            .filter(quest_requirements::quest_id.eq(quest_id))
+            .select(QuestRequirementEntity::as_select())
+            .first::<QuestRequirementEntity>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+    async fn crew_by_quest_id(
+        &self,
+        quest_id: i32,
+        status: CrewMembershipStatuses,
+    ) -> Result<Vec<CrewMemberModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_adventurer_junction::table
+            .inner_join(adventurers::table)
+            .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+            .filter(quest_adventurer_junction::status.eq(status.to_string()))
+            .select((
+                adventurers::id,
+                adventurers::username,
+                quest_adventurer_junction::joined_at,
+            ))
+            .order_by(quest_adventurer_junction::joined_at.asc())
+            .load::<CrewMemberModel>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn status_histories_by_quest_id(
+        &self,
+        quest_id: i32,
+    ) -> Result<Vec<QuestStatusHistoryEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_status_histories::table
+            .filter(quest_status_histories::quest_id.eq(quest_id))
+            .select(QuestStatusHistoryEntity::as_select())
+            .order_by(quest_status_histories::created_at.asc())
+            .load::<QuestStatusHistoryEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn joined_quests_by_adventurer_id(&self, adventurer_id: i32) -> Result<Vec<QuestEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quests::table
+            .inner_join(quest_adventurer_junction::table)
+            .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+            .filter(
+                quest_adventurer_junction::status.eq(CrewMembershipStatuses::Joined.to_string()),
+            )
+            .filter(quests::deleted_at.is_null())
+            .select(QuestEntity::as_select())
+            .order_by(quests::created_at.desc())
+            .load::<QuestEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn owned_quests_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quests::table
+            .filter(quests::guild_commander_id.eq(guild_commander_id))
+            .filter(quests::deleted_at.is_null())
+            .select(QuestEntity::as_select())
+            .order_by(quests::created_at.desc())
+            .load::<QuestEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn quest_exports_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestExportEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run::<_, anyhow::Error, _>(|conn| {
+                let quests = quests::table
+                    .filter(quests::guild_commander_id.eq(guild_commander_id))
+                    .filter(quests::deleted_at.is_null())
+                    .select(QuestEntity::as_select())
+                    .order_by(quests::created_at.desc())
+                    .load::<QuestEntity>(conn)?;
+
+                let quest_ids = quests.iter().map(|quest| quest.id).collect::<Vec<_>>();
+
+                let mut adventurers_counts = quest_adventurer_junction::table
+                    .filter(quest_adventurer_junction::quest_id.eq_any(&quest_ids))
+                    .filter(
+                        quest_adventurer_junction::status
+                            .eq(CrewMembershipStatuses::Joined.to_string()),
+                    )
+                    .group_by(quest_adventurer_junction::quest_id)
+                    .select((quest_adventurer_junction::quest_id, count_star()))
+                    .load::<(i32, i64)>(conn)?
+                    .into_iter()
+                    .collect::<HashMap<_, _>>();
+
+                let mut tags = group_by_quest_id(
+                    quest_tags::table
+                        .filter(quest_tags::quest_id.eq_any(&quest_ids))
+                        .select(QuestTagEntity::as_select())
+                        .order_by(quest_tags::name.asc())
+                        .load::<QuestTagEntity>(conn)?
+                        .into_iter()
+                        .map(|tag| (tag.quest_id, tag.name)),
+                );
+
+                let mut objectives = group_by_quest_id(
+                    quest_objectives::table
+                        .filter(quest_objectives::quest_id.eq_any(&quest_ids))
+                        .select(QuestObjectiveEntity::as_select())
+                        .order_by((quest_objectives::position.asc(), quest_objectives::id.asc()))
+                        .load::<QuestObjectiveEntity>(conn)?
+                        .into_iter()
+                        .map(|objective| (objective.quest_id, objective)),
+                );
+
+                let mut requirements = quest_requirements::table
+                    .filter(quest_requirements::quest_id.eq_any(&quest_ids))
+                    .select(QuestRequirementEntity::as_select())
+                    .load::<QuestRequirementEntity>(conn)?
+                    .into_iter()
+                    .map(|requirements| (requirements.quest_id, requirements))
+                    .collect::<HashMap<_, _>>();
+
+                let mut crew = group_by_quest_id(
+                    quest_adventurer_junction::table
+                        .inner_join(adventurers::table)
+                        .filter(quest_adventurer_junction::quest_id.eq_any(&quest_ids))
+                        .filter(
+                            quest_adventurer_junction::status
+                                .eq(CrewMembershipStatuses::Joined.to_string()),
+                        )
+                        .select((
+                            quest_adventurer_junction::quest_id,
+                            (
+                                adventurers::id,
+                                adventurers::username,
+                                quest_adventurer_junction::joined_at,
+                            ),
+                        ))
+                        .order_by(quest_adventurer_junction::joined_at.asc())
+                        .load::<(i32, CrewMemberModel)>(conn)?,
+                );
+
+                let mut status_histories = group_by_quest_id(
+                    quest_status_histories::table
+                        .filter(quest_status_histories::quest_id.eq_any(&quest_ids))
+                        .select(QuestStatusHistoryEntity::as_select())
+                        .order_by(quest_status_histories::created_at.asc())
+                        .load::<QuestStatusHistoryEntity>(conn)?
+                        .into_iter()
+                        .map(|history| (history.quest_id, history)),
+                );
+
+                let result = quests
+                    .into_iter()
+                    .map(|quest| QuestExportEntity {
+                        adventurers_count: adventurers_counts.remove(&quest.id).unwrap_or(0),
+                        tags: tags.remove(&quest.id).unwrap_or_default(),
+                        objectives: objectives.remove(&quest.id).unwrap_or_default(),
+                        requirements: requirements.remove(&quest.id),
+                        crew: crew.remove(&quest.id).unwrap_or_default(),
+                        status_histories: status_histories.remove(&quest.id).unwrap_or_default(),
+                        quest,
+                    })
+                    .collect::<Vec<_>>();
+
+                Ok(result)
+            })?;
+
+        Ok(result)
+    }
+    async fn overdue_quests(&self, now: NaiveDateTime) -> Result<Vec<QuestEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quests::table
+            .filter(quests::deleted_at.is_null())
+            .filter(quests::status.eq(QuestStatuses::InJourney.to_string()))
+            .filter(quests::due_at.lt(now))
+            .select(QuestEntity::as_select())
+            .order_by(quests::due_at.asc())
+            .load::<QuestEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+}
+
+fn group_by_quest_id<T>(rows: impl IntoIterator<Item = (i32, T)>) -> HashMap<i32, Vec<T>> {
+    let mut grouped = HashMap::<i32, Vec<T>>::new();
+
+    for (quest_id, row) in rows {
+        grouped.entry(quest_id).or_default().push(row);
+    }
+
+    grouped
 }
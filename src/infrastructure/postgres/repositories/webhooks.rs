@@ -0,0 +1,191 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{dsl::insert_into, prelude::*};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::{
+            webhook_deliveries::{
+                AddWebhookDeliveryEntity, UpdateWebhookDeliveryEntity, WebhookDeliveryEntity,
+            },
+            webhooks::{AddWebhookEntity, EditWebhookEntity, WebhookEntity},
+        },
+        repositories::webhooks::WebhooksRepository,
+        value_objects::webhook_delivery_statuses::WebhookDeliveryStatuses,
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        schema::{quests, webhook_deliveries, webhooks},
+    },
+};
+
+pub struct WebhooksPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl WebhooksPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl WebhooksRepository for WebhooksPostgres {
+    async fn add(&self, add_webhook_entity: AddWebhookEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(webhooks::table)
+            .values(add_webhook_entity)
+            .returning(webhooks::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn edit(&self, webhook_id: i32, edit_webhook_entity: EditWebhookEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(webhooks::table)
+            .filter(webhooks::id.eq(webhook_id))
+            .set(edit_webhook_entity)
+            .returning(webhooks::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn remove(&self, webhook_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(webhooks::table)
+            .filter(webhooks::id.eq(webhook_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn view_details(&self, webhook_id: i32) -> Result<WebhookEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = webhooks::table
+            .filter(webhooks::id.eq(webhook_id))
+            .select(WebhookEntity::as_select())
+            .first::<WebhookEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn webhooks_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<WebhookEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = webhooks::table
+            .filter(webhooks::guild_commander_id.eq(guild_commander_id))
+            .order(webhooks::id.asc())
+            .select(WebhookEntity::as_select())
+            .load::<WebhookEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+    async fn active_webhooks_by_quest_id(&self, quest_id: i32) -> Result<Vec<WebhookEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = webhooks::table
+            .inner_join(
+                quests::table.on(quests::guild_commander_id.eq(webhooks::guild_commander_id)),
+            )
+            .filter(quests::id.eq(quest_id))
+            .filter(webhooks::active.eq(true))
+            .order(webhooks::id.asc())
+            .select(WebhookEntity::as_select())
+            .load::<WebhookEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+    async fn enqueue_deliveries(
+        &self,
+        add_webhook_delivery_entities: Vec<AddWebhookDeliveryEntity>,
+    ) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(webhook_deliveries::table)
+            .values(add_webhook_delivery_entities)
+            .on_conflict_do_nothing()
+            .execute(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn due_deliveries(
+        &self,
+        now: NaiveDateTime,
+        claimed_until: NaiveDateTime,
+        limit: i64,
+    ) -> Result<Vec<(WebhookDeliveryEntity, WebhookEntity)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let delivery_ids = webhook_deliveries::table
+                .filter(webhook_deliveries::status.eq(WebhookDeliveryStatuses::Pending.to_string()))
+                .filter(webhook_deliveries::next_attempt_at.le(now))
+                .order(webhook_deliveries::next_attempt_at.asc())
+                .limit(limit)
+                .select(webhook_deliveries::id)
+                .for_update()
+                .skip_locked()
+                .load::<i32>(conn)?;
+
+            diesel::update(webhook_deliveries::table)
+                .filter(webhook_deliveries::id.eq_any(&delivery_ids))
+                .set(webhook_deliveries::next_attempt_at.eq(claimed_until))
+                .execute(conn)?;
+
+            let results = webhook_deliveries::table
+                .inner_join(webhooks::table)
+                .filter(webhook_deliveries::id.eq_any(&delivery_ids))
+                .order(webhook_deliveries::id.asc())
+                .select((
+                    WebhookDeliveryEntity::as_select(),
+                    WebhookEntity::as_select(),
+                ))
+                .load::<(WebhookDeliveryEntity, WebhookEntity)>(conn)?;
+
+            Ok(results)
+        })?;
+
+        Ok(results)
+    }
+    async fn update_delivery(
+        &self,
+        delivery_id: i32,
+        update_webhook_delivery_entity: UpdateWebhookDeliveryEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(webhook_deliveries::table)
+            .filter(webhook_deliveries::id.eq(delivery_id))
+            .set(update_webhook_delivery_entity)
+            .returning(webhook_deliveries::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn deliveries_by_webhook_id(
+        &self,
+        webhook_id: i32,
+        limit: i64,
+    ) -> Result<Vec<WebhookDeliveryEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = webhook_deliveries::table
+            .filter(webhook_deliveries::webhook_id.eq(webhook_id))
+            .order((
+                webhook_deliveries::created_at.desc(),
+                webhook_deliveries::id.desc(),
+            ))
+            .limit(limit)
+            .select(WebhookDeliveryEntity::as_select())
+            .load::<WebhookDeliveryEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+}
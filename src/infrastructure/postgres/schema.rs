@@ -1,5 +1,14 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    adventurer_skills (id) {
+        id -> Int4,
+        adventurer_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+    }
+}
+
 diesel::table! {
     adventurers (id) {
         id -> Int4,
@@ -9,6 +18,8 @@ diesel::table! {
         password -> Varchar,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        gold -> Int8,
+        xp -> Int8,
     }
 }
 
@@ -24,10 +35,202 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    notifications (id) {
+        id -> Int4,
+        #[max_length = 255]
+        recipient_role -> Varchar,
+        recipient_id -> Int4,
+        quest_id -> Nullable<Int4>,
+        #[max_length = 255]
+        kind -> Varchar,
+        message -> Text,
+        read_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        #[max_length = 255]
+        idempotency_key -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    outbox_event_handlers (outbox_event_id, handler) {
+        outbox_event_id -> Int4,
+        #[max_length = 255]
+        handler -> Varchar,
+        handled_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    outbox_events (id) {
+        id -> Int4,
+        #[max_length = 255]
+        idempotency_key -> Varchar,
+        #[max_length = 255]
+        kind -> Varchar,
+        quest_id -> Int4,
+        payload -> Jsonb,
+        attempts -> Int4,
+        last_error -> Nullable<Text>,
+        processed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        #[max_length = 255]
+        status -> Varchar,
+        next_attempt_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     quest_adventurer_junction (quest_id, adventurer_id) {
         quest_id -> Int4,
         adventurer_id -> Int4,
+        joined_at -> Timestamp,
+        #[max_length = 255]
+        status -> Varchar,
+    }
+}
+
+diesel::table! {
+    quest_categories (id) {
+        id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        guild_commander_id -> Int4,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_comments (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        parent_id -> Nullable<Int4>,
+        #[max_length = 255]
+        author_role -> Varchar,
+        author_id -> Int4,
+        body -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    quest_dependencies (quest_id, prerequisite_id) {
+        quest_id -> Int4,
+        prerequisite_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_objectives (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        position -> Int4,
+        #[max_length = 255]
+        title -> Varchar,
+        description -> Nullable<Text>,
+        done -> Bool,
+        completed_by -> Nullable<Int4>,
+        completed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_requirements (quest_id) {
+        quest_id -> Int4,
+        min_level -> Nullable<Int4>,
+        min_completed_quests -> Nullable<Int4>,
+        required_skills -> Jsonb,
+        allowed_adventurer_ids -> Jsonb,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_series (id) {
+        id -> Int4,
+        guild_commander_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        approval_required -> Bool,
+        max_adventurers -> Int4,
+        #[max_length = 255]
+        difficulty -> Varchar,
+        category_id -> Nullable<Int4>,
+        tags -> Jsonb,
+        reward_gold -> Int4,
+        reward_xp -> Int4,
+        #[max_length = 255]
+        frequency -> Varchar,
+        weekdays -> Jsonb,
+        #[max_length = 255]
+        cron_expression -> Nullable<Varchar>,
+        starts_at -> Timestamp,
+        duration_minutes -> Nullable<Int4>,
+        ends_at -> Nullable<Timestamp>,
+        #[max_length = 255]
+        status -> Varchar,
+        materialized_until -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_status_histories (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        #[max_length = 255]
+        status -> Varchar,
+        #[max_length = 255]
+        actor -> Varchar,
+        guild_commander_id -> Nullable<Int4>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_tags (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+    }
+}
+
+diesel::table! {
+    quest_templates (id) {
+        id -> Int4,
+        guild_commander_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        approval_required -> Bool,
+        max_adventurers -> Int4,
+        #[max_length = 255]
+        difficulty -> Varchar,
+        category_id -> Nullable<Int4>,
+        tags -> Jsonb,
+        reward_gold -> Int4,
+        reward_xp -> Int4,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_waitlist (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        adventurer_id -> Int4,
+        created_at -> Timestamp,
     }
 }
 
@@ -43,16 +246,119 @@ diesel::table! {
         created_at -> Timestamp,
         updated_at -> Timestamp,
         deleted_at -> Nullable<Timestamp>,
+        approval_required -> Bool,
+        starts_at -> Nullable<Timestamp>,
+        due_at -> Nullable<Timestamp>,
+        join_deadline -> Nullable<Timestamp>,
+        reward_gold -> Int4,
+        reward_xp -> Int4,
+        #[max_length = 255]
+        difficulty -> Varchar,
+        category_id -> Nullable<Int4>,
+        max_adventurers -> Int4,
+        series_id -> Nullable<Int4>,
+        series_occurrence_at -> Nullable<Timestamp>,
+        objectives_required -> Bool,
+        version -> Int4,
+    }
+}
+
+diesel::table! {
+    reward_ledgers (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        adventurer_id -> Int4,
+        #[max_length = 255]
+        quest_status -> Varchar,
+        gold -> Int4,
+        xp -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    webhook_deliveries (id) {
+        id -> Int4,
+        webhook_id -> Int4,
+        #[max_length = 255]
+        event_kind -> Varchar,
+        payload -> Jsonb,
+        #[max_length = 255]
+        status -> Varchar,
+        attempts -> Int4,
+        next_attempt_at -> Timestamp,
+        last_status_code -> Nullable<Int4>,
+        last_error -> Nullable<Text>,
+        delivered_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        #[max_length = 255]
+        idempotency_key -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    webhooks (id) {
+        id -> Int4,
+        guild_commander_id -> Int4,
+        #[max_length = 255]
+        url -> Varchar,
+        #[max_length = 255]
+        secret -> Varchar,
+        events -> Jsonb,
+        active -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
+diesel::joinable!(adventurer_skills -> adventurers (adventurer_id));
+diesel::joinable!(notifications -> quests (quest_id));
+diesel::joinable!(outbox_event_handlers -> outbox_events (outbox_event_id));
 diesel::joinable!(quest_adventurer_junction -> adventurers (adventurer_id));
 diesel::joinable!(quest_adventurer_junction -> quests (quest_id));
+diesel::joinable!(quest_categories -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_comments -> quests (quest_id));
+diesel::joinable!(quest_objectives -> adventurers (completed_by));
+diesel::joinable!(quest_objectives -> quests (quest_id));
+diesel::joinable!(quest_requirements -> quests (quest_id));
+diesel::joinable!(quest_series -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_series -> quest_categories (category_id));
+diesel::joinable!(quest_status_histories -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_status_histories -> quests (quest_id));
+diesel::joinable!(quest_tags -> quests (quest_id));
+diesel::joinable!(quest_templates -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_templates -> quest_categories (category_id));
+diesel::joinable!(quest_waitlist -> adventurers (adventurer_id));
+diesel::joinable!(quest_waitlist -> quests (quest_id));
 diesel::joinable!(quests -> guild_commanders (guild_commander_id));
+diesel::joinable!(quests -> quest_categories (category_id));
+diesel::joinable!(quests -> quest_series (series_id));
+diesel::joinable!(reward_ledgers -> adventurers (adventurer_id));
+diesel::joinable!(reward_ledgers -> quests (quest_id));
+diesel::joinable!(webhook_deliveries -> webhooks (webhook_id));
+diesel::joinable!(webhooks -> guild_commanders (guild_commander_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    adventurer_skills,
     adventurers,
     guild_commanders,
+    notifications,
+    outbox_event_handlers,
+    outbox_events,
     quest_adventurer_junction,
+    quest_categories,
+    quest_comments,
+    quest_dependencies,
+    quest_objectives,
+    quest_requirements,
+    quest_series,
+    quest_status_histories,
+    quest_tags,
+    quest_templates,
+    quest_waitlist,
     quests,
+    reward_ledgers,
+    webhook_deliveries,
+    webhooks,
 );
@@ -0,0 +1,262 @@
+use std::{
+    hash::Hash,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use moka::sync::Cache;
+
+use crate::domain::{
+    entities::{
+        quest_dependencies::QuestDependencyEntity,
+        quest_objectives::QuestObjectiveEntity,
+        quest_requirements::QuestRequirementEntity,
+        quest_status_histories::QuestStatusHistoryEntity,
+        quests::{QuestEntity, QuestExportEntity},
+    },
+    repositories::quest_viewing::QuestViewingRepository,
+    value_objects::{
+        board_checking_filter::BoardCheckingFilter, crew_member_model::CrewMemberModel,
+        crew_membership_statuses::CrewMembershipStatuses,
+    },
+};
+
+pub const QUEST_CACHE_CAPACITY: u64 = 10_000;
+pub const BOARD_CACHE_CAPACITY: u64 = 500;
+
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+pub fn generation() -> u64 {
+    GENERATION.load(Ordering::SeqCst)
+}
+
+pub fn invalidate() {
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+struct GenerationalCache<K, V>
+where
+    K: Eq + Hash + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    entries: Cache<K, (u64, V)>,
+    enabled: bool,
+}
+
+impl<K, V> GenerationalCache<K, V>
+where
+    K: Eq + Hash + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    fn new(max_capacity: u64, ttl: Duration) -> Self {
+        Self {
+            entries: Cache::builder()
+                .max_capacity(max_capacity)
+                .time_to_live(ttl.max(Duration::from_millis(1)))
+                .build(),
+            enabled: !ttl.is_zero(),
+        }
+    }
+
+    fn get(&self, key: &K) -> Option<V> {
+        match self.entries.get(key) {
+            Some((cached_generation, value)) if cached_generation == generation() => Some(value),
+            _ => None,
+        }
+    }
+
+    fn put(&self, key: K, read_generation: u64, value: V) {
+        if self.enabled && read_generation == generation() {
+            self.entries.insert(key, (read_generation, value));
+        }
+    }
+}
+
+struct QuestViewingCache {
+    quests: GenerationalCache<i32, QuestEntity>,
+    boards: GenerationalCache<String, Vec<QuestEntity>>,
+    adventurers_counts: GenerationalCache<i32, i64>,
+    tags: GenerationalCache<i32, Vec<String>>,
+    objectives: GenerationalCache<i32, Vec<QuestObjectiveEntity>>,
+}
+
+impl QuestViewingCache {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            quests: GenerationalCache::new(QUEST_CACHE_CAPACITY, ttl),
+            boards: GenerationalCache::new(BOARD_CACHE_CAPACITY, ttl),
+            adventurers_counts: GenerationalCache::new(QUEST_CACHE_CAPACITY, ttl),
+            tags: GenerationalCache::new(QUEST_CACHE_CAPACITY, ttl),
+            objectives: GenerationalCache::new(QUEST_CACHE_CAPACITY, ttl),
+        }
+    }
+}
+
+pub struct CachedQuestViewing<T>
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    quest_viewing_repository: Arc<T>,
+    cache: QuestViewingCache,
+}
+
+impl<T> CachedQuestViewing<T>
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    pub fn new(quest_viewing_repository: Arc<T>, ttl: Duration) -> Self {
+        Self {
+            quest_viewing_repository,
+            cache: QuestViewingCache::new(ttl),
+        }
+    }
+}
+
+#[async_trait]
+impl<T> QuestViewingRepository for CachedQuestViewing<T>
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    async fn view_details(&self, quest_id: i32) -> Result<QuestEntity> {
+        if let Some(quest) = self.cache.quests.get(&quest_id) {
+            return Ok(quest);
+        }
+
+        let read_generation = generation();
+        let result = self.quest_viewing_repository.view_details(quest_id).await?;
+        self.cache
+            .quests
+            .put(quest_id, read_generation, result.clone());
+
+        Ok(result)
+    }
+    async fn board_checking(&self, filter: &BoardCheckingFilter) -> Result<Vec<QuestEntity>> {
+        let key = serde_json::to_string(filter)?;
+
+        if let Some(quests) = self.cache.boards.get(&key) {
+            return Ok(quests);
+        }
+
+        let read_generation = generation();
+        let results = self.quest_viewing_repository.board_checking(filter).await?;
+        self.cache.boards.put(key, read_generation, results.clone());
+
+        Ok(results)
+    }
+    async fn adventurers_counting_by_quest_id(&self, quest_id: i32) -> Result<i64> {
+        if let Some(count) = self.cache.adventurers_counts.get(&quest_id) {
+            return Ok(count);
+        }
+
+        let read_generation = generation();
+        let result = self
+            .quest_viewing_repository
+            .adventurers_counting_by_quest_id(quest_id)
+            .await?;
+        self.cache
+            .adventurers_counts
+            .put(quest_id, read_generation, result);
+
+        Ok(result)
+    }
+    async fn tags_by_quest_id(&self, quest_id: i32) -> Result<Vec<String>> {
+        if let Some(tags) = self.cache.tags.get(&quest_id) {
+            return Ok(tags);
+        }
+
+        let read_generation = generation();
+        let results = self
+            .quest_viewing_repository
+            .tags_by_quest_id(quest_id)
+            .await?;
+        self.cache
+            .tags
+            .put(quest_id, read_generation, results.clone());
+
+        Ok(results)
+    }
+    async fn objectives_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestObjectiveEntity>> {
+        if let Some(objectives) = self.cache.objectives.get(&quest_id) {
+            return Ok(objectives);
+        }
+
+        let read_generation = generation();
+        let results = self
+            .quest_viewing_repository
+            .objectives_by_quest_id(quest_id)
+            .await?;
+        self.cache
+            .objectives
+            .put(quest_id, read_generation, results.clone());
+
+        Ok(results)
+    }
+    async fn prerequisites_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestEntity>> {
+        self.quest_viewing_repository
+            .prerequisites_by_quest_id(quest_id)
+            .await
+    }
+    async fn chain_dependencies(&self, quest_id: i32) -> Result<Vec<QuestDependencyEntity>> {
+        self.quest_viewing_repository
+            .chain_dependencies(quest_id)
+            .await
+    }
+    async fn quests_by_ids(&self, quest_ids: Vec<i32>) -> Result<Vec<QuestEntity>> {
+        self.quest_viewing_repository.quests_by_ids(quest_ids).await
+    }
+    async fn requirements_by_quest_id(
+        &self,
+        quest_id: i32,
+    ) -> Result<Option<QuestRequirementEntity>> {
+        self.quest_viewing_repository
+            .requirements_by_quest_id(quest_id)
+            .await
+    }
+    async fn crew_by_quest_id(
+        &self,
+        quest_id: i32,
+        status: CrewMembershipStatuses,
+    ) -> Result<Vec<CrewMemberModel>> {
+        self.quest_viewing_repository
+            .crew_by_quest_id(quest_id, status)
+            .await
+    }
+    async fn status_histories_by_quest_id(
+        &self,
+        quest_id: i32,
+    ) -> Result<Vec<QuestStatusHistoryEntity>> {
+        self.quest_viewing_repository
+            .status_histories_by_quest_id(quest_id)
+            .await
+    }
+    async fn joined_quests_by_adventurer_id(&self, adventurer_id: i32) -> Result<Vec<QuestEntity>> {
+        self.quest_viewing_repository
+            .joined_quests_by_adventurer_id(adventurer_id)
+            .await
+    }
+    async fn owned_quests_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestEntity>> {
+        self.quest_viewing_repository
+            .owned_quests_by_guild_commander_id(guild_commander_id)
+            .await
+    }
+    async fn quest_exports_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestExportEntity>> {
+        self.quest_viewing_repository
+            .quest_exports_by_guild_commander_id(guild_commander_id)
+            .await
+    }
+    async fn overdue_quests(&self, now: NaiveDateTime) -> Result<Vec<QuestEntity>> {
+        self.quest_viewing_repository.overdue_quests(now).await
+    }
+}
@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use chrono::{TimeZone, Utc};
+    use tokio::sync::Mutex;
+
+    use crate::{
+        domain::{
+            entities::quests::QuestEntity,
+            repositories::quest_viewing::{MockQuestViewingRepository, QuestViewingRepository},
+            value_objects::{
+                quest_adventurer_junction::DEFAULT_CREW_SIZE,
+                quest_difficulties::QuestDifficulties, quest_statuses::QuestStatuses,
+            },
+        },
+        infrastructure::quest_viewing_cache::{self, CachedQuestViewing},
+    };
+
+    static GENERATION_LOCK: Mutex<()> = Mutex::const_new(());
+
+    fn quest(id: i32) -> QuestEntity {
+        QuestEntity {
+            id,
+            name: "test".to_string(),
+            description: Some("test".to_string()),
+            status: QuestStatuses::Open.to_string(),
+            guild_commander_id: 1,
+            approval_required: false,
+            starts_at: None,
+            due_at: None,
+            join_deadline: None,
+            reward_gold: 0,
+            reward_xp: 0,
+            difficulty: QuestDifficulties::Normal.to_string(),
+            category_id: None,
+            max_adventurers: DEFAULT_CREW_SIZE,
+            series_id: None,
+            objectives_required: false,
+            version: 1,
+            created_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+            updated_at: Utc
+                .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_view_details_is_served_from_cache_on_hit() {
+        let _guard = GENERATION_LOCK.lock().await;
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_view_details()
+            .times(1)
+            .returning(|quest_id| Box::pin(async move { Ok(quest(quest_id)) }));
+
+        let cached = CachedQuestViewing::new(Arc::new(mock_quest_repo), Duration::from_secs(60));
+
+        assert_eq!(cached.view_details(1).await.unwrap().id, 1);
+        assert_eq!(cached.view_details(1).await.unwrap().id, 1);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_drops_cached_entries() {
+        let _guard = GENERATION_LOCK.lock().await;
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_view_details()
+            .times(2)
+            .returning(|quest_id| Box::pin(async move { Ok(quest(quest_id)) }));
+
+        let cached = CachedQuestViewing::new(Arc::new(mock_quest_repo), Duration::from_secs(60));
+
+        cached.view_details(1).await.unwrap();
+        quest_viewing_cache::invalidate();
+        cached.view_details(1).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_read_that_races_a_write_is_not_cached() {
+        let _guard = GENERATION_LOCK.lock().await;
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_view_details()
+            .times(2)
+            .returning(|quest_id| {
+                Box::pin(async move {
+                    let quest = quest(quest_id);
+                    quest_viewing_cache::invalidate();
+                    Ok(quest)
+                })
+            });
+
+        let cached = CachedQuestViewing::new(Arc::new(mock_quest_repo), Duration::from_secs(60));
+
+        cached.view_details(1).await.unwrap();
+        cached.view_details(1).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_zero_ttl_disables_caching() {
+        let _guard = GENERATION_LOCK.lock().await;
+        let mut mock_quest_repo = MockQuestViewingRepository::new();
+
+        mock_quest_repo
+            .expect_tags_by_quest_id()
+            .times(2)
+            .returning(|_| Box::pin(async { Ok(vec!["dragon".to_string()]) }));
+
+        let cached = CachedQuestViewing::new(Arc::new(mock_quest_repo), Duration::ZERO);
+
+        cached.tags_by_quest_id(1).await.unwrap();
+        cached.tags_by_quest_id(1).await.unwrap();
+    }
+}
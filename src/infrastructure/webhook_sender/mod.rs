@@ -0,0 +1,93 @@
+use std::{net::SocketAddr, time::Duration};
+
+use anyhow::Result;
+use axum::async_trait;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    redirect, Client,
+};
+use url::Host;
+
+use crate::domain::{
+    repositories::webhook_sender::WebhookSender,
+    value_objects::webhook_model::{
+        ensure_public_host, is_public_ip, parse_webhook_url, WebhookRequestModel,
+    },
+};
+
+pub const WEBHOOK_SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct HttpWebhookSender {
+    allow_private_targets: bool,
+}
+
+impl HttpWebhookSender {
+    pub fn new(allow_private_targets: bool) -> Self {
+        Self {
+            allow_private_targets,
+        }
+    }
+
+    async fn resolve(&self, host: &str, port: u16) -> Result<SocketAddr> {
+        let addresses = tokio::net::lookup_host((host, port))
+            .await?
+            .collect::<Vec<_>>();
+
+        if !self.allow_private_targets
+            && addresses.iter().any(|address| !is_public_ip(address.ip()))
+        {
+            return Err(anyhow::anyhow!("Webhook URL resolves to a private address"));
+        }
+
+        addresses
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("Webhook URL host could not be resolved"))
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(&self, webhook_request: WebhookRequestModel) -> Result<u16> {
+        let url = parse_webhook_url(&webhook_request.url)?;
+        if !self.allow_private_targets {
+            ensure_public_host(&url)?;
+        }
+
+        let port = url
+            .port_or_known_default()
+            .ok_or_else(|| anyhow::anyhow!("Webhook URL must have a port"))?;
+
+        let mut client = Client::builder()
+            .redirect(redirect::Policy::none())
+            .timeout(WEBHOOK_SEND_TIMEOUT);
+
+        match url.host() {
+            Some(Host::Domain(domain)) => {
+                let address = self.resolve(domain, port).await?;
+                client = client.resolve(domain, address);
+            }
+            Some(Host::Ipv4(_)) | Some(Host::Ipv6(_)) => {}
+            None => return Err(anyhow::anyhow!("Webhook URL must have a host")),
+        }
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in webhook_request.headers.iter() {
+            headers.insert(
+                HeaderName::from_bytes(name.as_bytes())?,
+                HeaderValue::from_str(value)?,
+            );
+        }
+
+        let response = client
+            .build()?
+            .post(url)
+            .headers(headers)
+            .body(webhook_request.body)
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Webhook request failed: {}", e))?;
+
+        Ok(response.status().as_u16())
+    }
+}